@@ -1,5 +1,8 @@
 use listeners::{setup_listeners, AnalysisFinished, AnalysisStart, SiteRunIdSet};
-use seo_analyzer::{crawl_url, AnalysisProgress, CrawlConfig, CrawlResult};
+use seo_analyzer::{
+    crawl_url, AnalysisProgress, CrawlConfig, CrawlResult, CustomRulePlugin, PluginRegistry,
+    WasmPluginRuntime,
+};
 
 use seo_storage::SeoStorage;
 use specta_typescript::Typescript;
@@ -9,6 +12,7 @@ use tauri::{Emitter, Manager};
 use tauri_specta::{collect_commands, collect_events, Builder, Event};
 
 mod listeners;
+mod rules;
 mod sites;
 mod stores;
 
@@ -17,6 +21,8 @@ pub const STORE_FILE: &str = "store.json";
 struct AppData {
     storage: SeoStorage,
     site_run_id: Option<i32>,
+    custom_rules: CustomRulePlugin,
+    wasm_plugins: WasmPluginRuntime,
 }
 
 #[tauri::command]
@@ -35,15 +41,31 @@ async fn analyze_url_seo(app: tauri::AppHandle, url: String) -> Result<CrawlResu
         request_delay_ms: crawl_settings.request_delay_ms,
     };
 
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    let (rule_profile_id, rule_config) = storage
+        .get_rule_config_for_url(&base_url)
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut registry = plugin_registry(&app_handle).await?;
+    registry.set_config(rule_config);
+
     {
-        AnalysisStart { base_url }
-            .emit(&app_handle)
-            .map_err(|e| e.to_string())?;
+        AnalysisStart {
+            base_url,
+            rule_profile_id,
+        }
+        .emit(&app_handle)
+        .map_err(|e| e.to_string())?;
     }
     let progress_callback = Box::new(move |progress| {
         let _ = app_handle.emit("analysis-progress", progress);
     });
-    let res = crawl_url(&config, progress_callback)
+    let res = crawl_url(&config, registry, progress_callback)
         .await
         .map_err(|e| e.to_string())?;
     let res_clone = res.clone();
@@ -65,6 +87,25 @@ async fn analyze_url_seo(app: tauri::AppHandle, url: String) -> Result<CrawlResu
     Ok(res)
 }
 
+// The default plugins with the app's custom rule and WASM plugins
+async fn plugin_registry(app: &tauri::AppHandle) -> Result<PluginRegistry, String> {
+    let (custom_rules, wasm_plugins) = {
+        let state = app.state::<Mutex<AppData>>();
+        let app_data = state.lock().map_err(|e| e.to_string())?;
+        (app_data.custom_rules.clone(), app_data.wasm_plugins.clone())
+    };
+    let registry = PluginRegistry::default();
+    registry
+        .register(custom_rules)
+        .await
+        .map_err(|e| e.to_string())?;
+    registry
+        .register(wasm_plugins)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(registry)
+}
+
 fn builder() -> Builder<tauri::Wry> {
     Builder::<tauri::Wry>::new()
         // Then register them (separated by a comma)
@@ -75,8 +116,28 @@ fn builder() -> Builder<tauri::Wry> {
             sites::get_site_run_by_id,
             sites::get_site_by_id,
             sites::get_site_run_link_counts,
+            sites::get_site_run_pages,
+            sites::get_page_inlinks,
+            sites::get_page_outlinks,
+            sites::get_page_share_preview,
             sites::get_category_result_detail,
+            sites::get_rule_findings,
             sites::get_site_category_history,
+            rules::get_rules,
+            rules::set_rule_enabled,
+            rules::set_rule_severity,
+            rules::set_rule_weight,
+            rules::get_score_weights,
+            rules::set_score_weights,
+            rules::get_rule_definitions,
+            rules::set_rule_param,
+            rules::get_rule_profiles,
+            rules::save_current_rules_as_profile,
+            rules::delete_rule_profile,
+            rules::import_rule_profile,
+            rules::export_rule_profile,
+            rules::set_site_default_profile,
+            rules::reload_custom_rules,
         ])
         .events(collect_events![
             AnalysisProgress,
@@ -120,9 +181,36 @@ pub fn run() {
                     .await
                     .map_err(|e| e.to_string())
                     .expect("Failed to migrate storage");
+
+                #[allow(clippy::unwrap_used)]
+                let rules_dir = app
+                    .path()
+                    .app_data_dir()
+                    .unwrap()
+                    .join(rules::CUSTOM_RULES_DIR);
+                let custom_rules = rules::load_custom_rules(&storage, &rules_dir)
+                    .await
+                    .unwrap_or_else(|e| {
+                        eprintln!("Failed to load custom rules: {}", e);
+                        CustomRulePlugin::default()
+                    });
+                #[allow(clippy::unwrap_used)]
+                let plugins_dir = app
+                    .path()
+                    .app_data_dir()
+                    .unwrap()
+                    .join(rules::WASM_PLUGINS_DIR);
+                let wasm_plugins = rules::load_wasm_plugins(&storage, &plugins_dir)
+                    .await
+                    .unwrap_or_else(|e| {
+                        eprintln!("Failed to load WASM plugins: {}", e);
+                        WasmPluginRuntime::default()
+                    });
                 app.manage(Mutex::new(AppData {
                     storage,
                     site_run_id: None,
+                    custom_rules,
+                    wasm_plugins,
                 }));
                 setup_listeners(app.handle());
             });
@@ -13,6 +13,7 @@ use crate::AppData;
 #[derive(Debug, Serialize, Deserialize, Clone, Type, Event)]
 pub struct AnalysisStart {
     pub base_url: String,
+    pub rule_profile_id: Option<i32>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Type, Event)]
@@ -37,7 +38,7 @@ pub fn setup_listeners(app: &tauri::AppHandle) {
             .storage
             .clone();
         let site_run_id = storage_clone
-            .create_site_run(&payload.base_url)
+            .create_site_run(&payload.base_url, payload.rule_profile_id)
             .await
             .map_err(|e| anyhow::anyhow!("Error creating site run: {}", e))?;
         app_handle
@@ -69,7 +70,7 @@ pub fn setup_listeners(app: &tauri::AppHandle) {
         match payload.progress_type {
             AnalysisProgressType::AnalyzedPage(page_link) => {
                 storage_clone
-                    .insert_many_page_rule_results(site_run_id, page_link)
+                    .insert_many_page_rule_results(site_run_id, *page_link)
                     .await?;
             }
             AnalysisProgressType::AnalyzedSite(site_result) => {
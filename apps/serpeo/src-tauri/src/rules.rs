@@ -0,0 +1,333 @@
+use seo_analyzer::{
+    CustomRulePlugin, ProfileFormat, RuleDisplay, RuleParamValue, SeoPlugin, WasmLimits,
+    WasmPluginRuntime,
+};
+use seo_storage::entities::{plugin_rule, rule_profile, site};
+use seo_storage::enums::plugin_rule_enums::DbSeverity;
+use seo_storage::utils::health_score::ScoreWeights;
+use seo_storage::SeoStorage;
+use std::path::Path;
+use std::sync::Mutex;
+
+use tauri::Manager;
+
+use crate::AppData;
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_rules(app: tauri::AppHandle) -> Result<Vec<plugin_rule::Model>, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    let rules = storage
+        .get_plugin_rules()
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(rules)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_rule_enabled(
+    app: tauri::AppHandle,
+    rule_id: String,
+    enabled: bool,
+) -> Result<plugin_rule::Model, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    let rule = storage
+        .set_rule_enabled(&rule_id, enabled)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(rule)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_rule_severity(
+    app: tauri::AppHandle,
+    rule_id: String,
+    severity: Option<DbSeverity>,
+) -> Result<plugin_rule::Model, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    let rule = storage
+        .set_rule_severity(&rule_id, severity)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(rule)
+}
+
+// `None` resets the rule to the health score weight of its severity
+#[tauri::command]
+#[specta::specta]
+pub async fn set_rule_weight(
+    app: tauri::AppHandle,
+    rule_id: String,
+    weight: Option<i32>,
+) -> Result<plugin_rule::Model, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    let rule = storage
+        .set_rule_weight(&rule_id, weight)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(rule)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_score_weights(app: tauri::AppHandle) -> Result<ScoreWeights, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    storage.get_score_weights().await.map_err(|e| e.to_string())
+}
+
+// Severity weights for the health scores of later site runs
+#[tauri::command]
+#[specta::specta]
+pub async fn set_score_weights(
+    app: tauri::AppHandle,
+    score_weights: ScoreWeights,
+) -> Result<ScoreWeights, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    storage
+        .set_score_weights(score_weights)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// Rule metadata from the plugins, including the parameters each rule accepts
+#[tauri::command]
+#[specta::specta]
+pub async fn get_rule_definitions(app: tauri::AppHandle) -> Result<Vec<RuleDisplay>, String> {
+    Ok(crate::plugin_registry(&app).await?.get_available_rules())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_rule_param(
+    app: tauri::AppHandle,
+    rule_id: String,
+    name: String,
+    value: Option<RuleParamValue>,
+) -> Result<plugin_rule::Model, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    let registry = crate::plugin_registry(&app).await?;
+    let rule = storage
+        .set_rule_param(&registry, &rule_id, &name, value)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(rule)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_rule_profiles(app: tauri::AppHandle) -> Result<Vec<rule_profile::Model>, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    let profiles = storage
+        .get_rule_profiles()
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(profiles)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn save_current_rules_as_profile(
+    app: tauri::AppHandle,
+    name: String,
+    description: String,
+) -> Result<rule_profile::Model, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    let profile = storage
+        .save_current_rules_as_profile(&name, &description)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(profile)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_rule_profile(app: tauri::AppHandle, id: i32) -> Result<(), String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    storage
+        .delete_rule_profile(id)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn import_rule_profile(
+    app: tauri::AppHandle,
+    contents: String,
+    format: ProfileFormat,
+) -> Result<rule_profile::Model, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    let profile = storage
+        .import_rule_profile(&contents, format)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(profile)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn export_rule_profile(
+    app: tauri::AppHandle,
+    id: i32,
+    format: ProfileFormat,
+) -> Result<String, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    let contents = storage
+        .export_rule_profile(id, format)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(contents)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_site_default_profile(
+    app: tauri::AppHandle,
+    site_id: i32,
+    profile_id: Option<i32>,
+) -> Result<site::Model, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    let site = storage
+        .set_site_default_profile(site_id, profile_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(site)
+}
+
+// Custom rule files (.toml or .json) are read from this folder in the app data dir
+pub const CUSTOM_RULES_DIR: &str = "rules";
+
+pub async fn load_custom_rules(
+    storage: &SeoStorage,
+    rules_dir: &Path,
+) -> Result<CustomRulePlugin, String> {
+    let custom_rules = CustomRulePlugin::load_dir(rules_dir).map_err(|e| e.to_string())?;
+    storage
+        .seed_plugin_rules(custom_rules.rule_displays())
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(custom_rules)
+}
+
+// Plugins compiled to WebAssembly (.wasm) are read from this folder in the app data dir
+pub const WASM_PLUGINS_DIR: &str = "plugins";
+
+pub async fn load_wasm_plugins(
+    storage: &SeoStorage,
+    plugins_dir: &Path,
+) -> Result<WasmPluginRuntime, String> {
+    let wasm_plugins = WasmPluginRuntime::load_dir(plugins_dir, WasmLimits::default())
+        .map_err(|e| e.to_string())?;
+    storage
+        .seed_plugin_rules(wasm_plugins.rule_displays())
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(wasm_plugins)
+}
+
+// Re-reads the custom rule files so edits apply without restarting the app
+#[tauri::command]
+#[specta::specta]
+pub async fn reload_custom_rules(app: tauri::AppHandle) -> Result<Vec<RuleDisplay>, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    let rules_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join(CUSTOM_RULES_DIR);
+    let custom_rules = load_custom_rules(&storage, &rules_dir).await?;
+    let rules = custom_rules.rule_displays();
+    app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .custom_rules = custom_rules;
+    Ok(rules)
+}
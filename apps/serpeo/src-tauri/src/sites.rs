@@ -1,9 +1,10 @@
-use seo_storage::entities::site_run;
+use seo_analyzer::SharePreview;
+use seo_storage::entities::{rule_finding, site_page, site_run};
 use seo_storage::utils::category_counts::CategoryResultHistory;
 use seo_storage::utils::category_detail::CategoryDetailResponse;
 use seo_storage::utils::sites_with_site_runs::SiteWithSiteRuns;
-use seo_storage::SitePageLinkCount;
 use seo_storage::{entities::site, utils::category_counts::CategoryResultDisplay};
+use seo_storage::{LinkedPage, SitePageLinkCount};
 use std::sync::Mutex;
 
 use tauri::Manager;
@@ -122,6 +123,26 @@ pub async fn get_category_result_detail(
     Ok(category_result)
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn get_rule_findings(
+    app: tauri::AppHandle,
+    page_rule_result_id: i32,
+) -> Result<Vec<rule_finding::Model>, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    let rule_findings = storage
+        .get_rule_findings(page_rule_result_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(rule_findings)
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn get_site_run_link_counts(
@@ -142,3 +163,90 @@ pub async fn get_site_run_link_counts(
 
     Ok(site_run_link_counts)
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_site_run_pages(
+    app: tauri::AppHandle,
+    site_run_id: i32,
+) -> Result<Vec<site_page::Model>, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    let pages = storage
+        .get_site_run_pages(site_run_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(pages)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_page_inlinks(
+    app: tauri::AppHandle,
+    site_run_id: i32,
+    url: String,
+) -> Result<Vec<LinkedPage>, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    let inlinks = storage
+        .get_page_inlinks(site_run_id, &url)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(inlinks)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_page_outlinks(
+    app: tauri::AppHandle,
+    site_run_id: i32,
+    url: String,
+) -> Result<Vec<LinkedPage>, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    let outlinks = storage
+        .get_page_outlinks(site_run_id, &url)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(outlinks)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_page_share_preview(
+    app: tauri::AppHandle,
+    site_run_id: i32,
+    url: String,
+) -> Result<Option<SharePreview>, String> {
+    let app_handle = app.clone();
+    let storage = app_handle
+        .state::<Mutex<AppData>>()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .storage
+        .clone();
+    let share_preview = storage
+        .get_page_share_preview(site_run_id, &url)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(share_preview)
+}
@@ -8,7 +8,10 @@ import { Badge } from "@repo/ui/components/badge";
 import { useAtomValue } from "jotai";
 import { groupBy, prop } from "remeda";
 import { issueCategoriesAtom } from "../../../atoms/crawl-result";
-import type { FlatRuleResult } from "../../../generated/bindings";
+import type {
+  FlatRuleResult,
+  Remediation,
+} from "../../../generated/bindings";
 import { useSiteRunCategoryResultDetailQuery } from "../../../queries/sites";
 import { SeverityBadge } from "../severity-badge";
 export const IssueCategoryDetailOld = () => {
@@ -60,7 +63,7 @@ const IssueCategoryDetailItem = ({
   category,
   issues,
 }: { category: string; issues: FlatRuleResult[] }) => {
-  const failedTests = issues.filter((i) => !i.passed);
+  const failedTests = issues.filter((i) => i.status === "Failed");
 
   if (failedTests.length === 0) {
     return null;
@@ -84,15 +87,26 @@ const IssueCategoryDetailItem = ({
               <div className="grid grid-cols-[45px_80px_1fr] items-center gap-2">
                 <Badge variant="outline">{i.tests.length}</Badge>
                 <SeverityBadge severity={i.tests[0].severity} />
-                {i.tests[0].message}
+                {/* Messages are per page, e.g. how many images failed */}
+                {i.name}
               </div>
             </AccordionTrigger>
             <AccordionContent>
+              {i.tests[0].remediation && (
+                <RemediationDetail remediation={i.tests[0].remediation} />
+              )}
               {i.tests.map((t, idx) => {
                 return (
                   <div key={`${t.rule_id}-${idx}`}>
                     <p>{t.message}</p>
                     <p>{t.page_url}</p>
+                    {t.context?.values.map((c) => (
+                      <p key={c.value} className="text-muted-foreground text-sm">
+                        "{c.value}"
+                        {c.related_urls.length > 0 &&
+                          ` also on ${c.related_urls.join(", ")}`}
+                      </p>
+                    ))}
                   </div>
                 );
               })}
@@ -103,3 +117,37 @@ const IssueCategoryDetailItem = ({
     </div>
   );
 };
+
+const RemediationDetail = ({ remediation }: { remediation: Remediation }) => {
+  return (
+    <div className="mb-4 flex flex-col gap-2 rounded-md border p-3">
+      <div className="flex items-center gap-2">
+        <h3 className="font-semibold">How to fix</h3>
+        <Badge variant="outline">{remediation.effort} effort</Badge>
+      </div>
+      <p className="text-muted-foreground">{remediation.why_it_matters}</p>
+      <p>{remediation.how_to_fix}</p>
+      {remediation.example && (
+        <pre className="overflow-x-auto rounded bg-muted p-2 text-xs">
+          <code>{remediation.example}</code>
+        </pre>
+      )}
+      {remediation.references.length > 0 && (
+        <ul className="list-disc pl-4">
+          {remediation.references.map((url) => (
+            <li key={url}>
+              <a
+                href={url}
+                target="_blank"
+                rel="noreferrer"
+                className="underline"
+              >
+                {url}
+              </a>
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  );
+};
@@ -35,7 +35,7 @@ export const IssueCategoryOverview = ({ siteRunId }: Props) => {
         <IssueCategoryOverviewItem
           key={category}
           category={category}
-          total={result.total}
+          total={result.passed + result.failed}
           passed={result.passed}
         />
       ))}
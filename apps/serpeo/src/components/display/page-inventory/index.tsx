@@ -0,0 +1,224 @@
+import { Badge } from "@repo/ui/components/badge";
+import {
+  Card,
+  CardContent,
+  CardDescription,
+  CardHeader,
+  CardTitle,
+} from "@repo/ui/components/card";
+import { ScrollArea } from "@repo/ui/components/scroll-area";
+import { ArrowDown, ArrowUp } from "lucide-react";
+import { useMemo, useState } from "react";
+import type { SitePageModel } from "../../../generated/bindings";
+import { useSiteRunPagesQuery } from "../../../queries/sites";
+import { SharePreview } from "../share-preview";
+
+type PageInventoryProps = {
+  siteRunId: number;
+};
+
+const formatBytes = (bytes: number | null) => {
+  if (bytes === null) {
+    return "-";
+  }
+  if (bytes < 1024) {
+    return `${bytes} B`;
+  }
+  return `${(bytes / 1024).toFixed(1)} KB`;
+};
+
+type SortKey =
+  | "depth"
+  | "inlinks"
+  | "link_score"
+  | "response_time_ms"
+  | "content_length"
+  | "word_count"
+  | "paragraph_count"
+  | "text_html_ratio"
+  | "reading_ease"
+  | "grade_level";
+
+type Sort = { key: SortKey; descending: boolean };
+
+// Pages without the value go last, whatever the direction
+const sortPages = (pages: SitePageModel[], sort: Sort | null) => {
+  if (sort === null) {
+    return pages;
+  }
+  return [...pages].sort((a, b) => {
+    const left = a[sort.key];
+    const right = b[sort.key];
+    if (left === null || right === null) {
+      return (left === null ? 1 : 0) - (right === null ? 1 : 0);
+    }
+    return sort.descending ? right - left : left - right;
+  });
+};
+
+const orDash = (value: number | null, suffix = "") =>
+  value === null ? "-" : `${value}${suffix}`;
+
+const orDashFixed = (value: number | null, suffix = "") =>
+  value === null ? "-" : `${value.toFixed(1)}${suffix}`;
+
+type SortableHeaderProps = {
+  label: string;
+  sortKey: SortKey;
+  sort: Sort | null;
+  onSort: (key: SortKey) => void;
+};
+
+const SortableHeader = ({
+  label,
+  sortKey,
+  sort,
+  onSort,
+}: SortableHeaderProps) => {
+  const active = sort?.key === sortKey;
+  const Arrow = sort?.descending ? ArrowDown : ArrowUp;
+  return (
+    <th className="p-2 text-right">
+      <button
+        type="button"
+        className="inline-flex items-center gap-1 hover:text-foreground"
+        onClick={() => onSort(sortKey)}
+      >
+        {label}
+        {active && <Arrow className="size-3" />}
+      </button>
+    </th>
+  );
+};
+
+const StatusBadge = ({ page }: { page: SitePageModel }) => {
+  if (page.status_code === null) {
+    return <span className="text-muted-foreground">-</span>;
+  }
+  const variant = page.status_code < 400 ? "outline" : "destructive";
+  return (
+    <Badge variant={variant}>
+      {page.status_code}
+      {page.redirected && " (redirected)"}
+    </Badge>
+  );
+};
+
+export const PageInventory = ({ siteRunId }: PageInventoryProps) => {
+  const { data: pages } = useSiteRunPagesQuery(siteRunId);
+  const [selectedUrl, setSelectedUrl] = useState<string | null>(null);
+  const [sort, setSort] = useState<Sort | null>(null);
+  const sortedPages = useMemo(
+    () => sortPages(pages ?? [], sort),
+    [pages, sort],
+  );
+
+  // A new column sorts descending first, a second click flips the direction
+  const toggleSort = (key: SortKey) =>
+    setSort((current) =>
+      current?.key === key
+        ? { key, descending: !current.descending }
+        : { key, descending: true },
+    );
+  const header = (label: string, key: SortKey) => (
+    <SortableHeader
+      label={label}
+      sortKey={key}
+      sort={sort}
+      onSort={toggleSort}
+    />
+  );
+
+  return (
+    <div className="flex flex-col gap-4">
+      <Card>
+        <CardHeader>
+          <CardTitle>Pages</CardTitle>
+          <CardDescription>
+            Every page found in the crawl, by depth from the start page. Click
+            a numeric column to sort by it, and select a page to see its share
+            preview.
+          </CardDescription>
+        </CardHeader>
+        <CardContent>
+          <ScrollArea className="h-96">
+            <table className="w-full text-sm">
+              <thead className="text-left text-muted-foreground">
+                <tr>
+                  <th className="p-2">URL</th>
+                  <th className="p-2">Status</th>
+                  <th className="p-2">Title</th>
+                  {header("Depth", "depth")}
+                  <th className="p-2">Found in</th>
+                  {header("Inlinks", "inlinks")}
+                  {header("Link score", "link_score")}
+                  {header("Time", "response_time_ms")}
+                  {header("Size", "content_length")}
+                  {header("Words", "word_count")}
+                  {header("Paragraphs", "paragraph_count")}
+                  {header("Text ratio", "text_html_ratio")}
+                  {header("Reading ease", "reading_ease")}
+                  {header("Grade", "grade_level")}
+                </tr>
+              </thead>
+              <tbody>
+                {sortedPages.map((page) => (
+                  <tr
+                    key={page.id}
+                    className={`cursor-pointer border-t hover:bg-muted/50 ${
+                      selectedUrl === page.url ? "bg-muted" : ""
+                    }`}
+                    onClick={() => setSelectedUrl(page.url)}
+                  >
+                    <td className="max-w-xs truncate p-2" title={page.url}>
+                      {page.url}
+                    </td>
+                    <td className="p-2">
+                      <StatusBadge page={page} />
+                    </td>
+                    <td
+                      className="max-w-xs truncate p-2"
+                      title={page.title ?? ""}
+                    >
+                      {page.title ?? "-"}
+                    </td>
+                    <td className="p-2 text-right">{orDash(page.depth)}</td>
+                    <td className="p-2">{page.discovery_source ?? "-"}</td>
+                    <td className="p-2 text-right">{orDash(page.inlinks)}</td>
+                    <td className="p-2 text-right">
+                      {orDashFixed(page.link_score)}
+                    </td>
+                    <td className="p-2 text-right">
+                      {orDash(page.response_time_ms, " ms")}
+                    </td>
+                    <td className="p-2 text-right">
+                      {formatBytes(page.content_length)}
+                    </td>
+                    <td className="p-2 text-right">
+                      {orDash(page.word_count)}
+                    </td>
+                    <td className="p-2 text-right">
+                      {orDash(page.paragraph_count)}
+                    </td>
+                    <td className="p-2 text-right">
+                      {orDashFixed(page.text_html_ratio, "%")}
+                    </td>
+                    <td className="p-2 text-right">
+                      {orDashFixed(page.reading_ease)}
+                    </td>
+                    <td className="p-2 text-right">
+                      {orDashFixed(page.grade_level)}
+                    </td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </ScrollArea>
+        </CardContent>
+      </Card>
+      {selectedUrl !== null && (
+        <SharePreview siteRunId={siteRunId} url={selectedUrl} />
+      )}
+    </div>
+  );
+};
@@ -0,0 +1,126 @@
+import { Badge } from "@repo/ui/components/badge";
+import {
+  Card,
+  CardContent,
+  CardDescription,
+  CardHeader,
+  CardTitle,
+} from "@repo/ui/components/card";
+import type {
+  SharePreview as SharePreviewData,
+} from "../../../generated/bindings";
+import { useSharePreviewQuery } from "../../../queries/sites";
+
+type SharePreviewProps = {
+  siteRunId: number;
+  url: string;
+};
+
+type PreviewCardProps = {
+  label: string;
+  siteName: string;
+  title: string | null;
+  description: string | null;
+  image: string | null;
+  largeImage: boolean;
+};
+
+const PreviewCard = ({
+  label,
+  siteName,
+  title,
+  description,
+  image,
+  largeImage,
+}: PreviewCardProps) => (
+  <div className="flex flex-col gap-2">
+    <span className="text-muted-foreground text-sm">{label}</span>
+    <div
+      className={`overflow-hidden rounded-lg border ${largeImage ? "" : "flex"}`}
+    >
+      {image ? (
+        <img
+          src={image}
+          alt=""
+          className={
+            largeImage
+              ? "aspect-[1.91/1] w-full object-cover"
+              : "size-28 object-cover"
+          }
+        />
+      ) : (
+        <div
+          className={`flex items-center justify-center bg-muted text-muted-foreground text-xs ${largeImage ? "aspect-[1.91/1] w-full" : "size-28"}`}
+        >
+          No image
+        </div>
+      )}
+      <div className="flex min-w-0 flex-col gap-1 p-3">
+        <span className="truncate text-muted-foreground text-xs uppercase">
+          {siteName}
+        </span>
+        <span className="line-clamp-2 font-medium">
+          {title ?? <span className="text-destructive">No title</span>}
+        </span>
+        {description && (
+          <span className="line-clamp-2 text-muted-foreground text-sm">
+            {description}
+          </span>
+        )}
+      </div>
+    </div>
+  </div>
+);
+
+const TwitterCardBadge = ({ preview }: { preview: SharePreviewData }) =>
+  preview.twitter_card ? (
+    <Badge variant="outline">{preview.twitter_card}</Badge>
+  ) : (
+    <Badge variant="destructive">No twitter:card</Badge>
+  );
+
+export const SharePreview = ({ siteRunId, url }: SharePreviewProps) => {
+  const { data: preview } = useSharePreviewQuery(siteRunId, url);
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>Share preview</CardTitle>
+        <CardDescription className="truncate">
+          How a shared link to {url} looks
+        </CardDescription>
+      </CardHeader>
+      <CardContent>
+        {preview ? (
+          <div className="grid gap-6 md:grid-cols-2">
+            <PreviewCard
+              label="Facebook, LinkedIn and chat apps"
+              siteName={preview.site_name}
+              title={preview.title}
+              description={preview.description}
+              image={preview.image}
+              largeImage
+            />
+            <div className="flex flex-col gap-2">
+              <PreviewCard
+                label="X"
+                siteName={preview.site_name}
+                title={preview.twitter_title}
+                description={preview.twitter_description}
+                image={preview.twitter_image}
+                largeImage={preview.twitter_card === "summary_large_image"}
+              />
+              <div>
+                <TwitterCardBadge preview={preview} />
+              </div>
+            </div>
+          </div>
+        ) : (
+          <span className="text-muted-foreground text-sm">
+            No preview for this page
+          </span>
+        )}
+      </CardContent>
+    </Card>
+  );
+};
@@ -0,0 +1,201 @@
+import { Button } from "@repo/ui/components/button";
+import {
+  Card,
+  CardContent,
+  CardDescription,
+  CardHeader,
+  CardTitle,
+} from "@repo/ui/components/card";
+import { Input } from "@repo/ui/components/input";
+import { Separator } from "@repo/ui/components/separator";
+import type { ReactNode } from "react";
+import { toast } from "sonner";
+import type {
+  DbSeverity,
+  PluginRuleModel,
+  RuleDisplay,
+  RuleParam,
+  RuleParamValue,
+} from "../../../generated/bindings";
+import {
+  useReloadCustomRulesMutation,
+  useRuleDefinitionsQuery,
+  useRulesQuery,
+  useSetRuleEnabledMutation,
+  useSetRuleParamMutation,
+  useSetRuleSeverityMutation,
+} from "../../../queries/rules";
+
+const severities: DbSeverity[] = ["Info", "Warning", "Error", "Critical"];
+
+const selectClassName =
+  "h-9 rounded-md border border-input bg-transparent px-2 text-sm shadow-xs";
+
+const parseOverrides = (rule: PluginRuleModel) => {
+  if (!rule.params) {
+    return {};
+  }
+  return JSON.parse(rule.params) as Record<string, RuleParamValue>;
+};
+
+export const RulesCard = () => {
+  const { data: rules } = useRulesQuery();
+  const { data: definitions } = useRuleDefinitionsQuery();
+  const reloadCustomRules = useReloadCustomRulesMutation();
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>Rules</CardTitle>
+        <CardDescription>
+          Choose which rules run, how severe their issues are and the limits
+          they check against
+        </CardDescription>
+        <Button
+          variant="outline"
+          className="w-fit"
+          disabled={reloadCustomRules.isPending}
+          onClick={() =>
+            reloadCustomRules.mutate(undefined, {
+              onSuccess: (rules) =>
+                toast.success(`Loaded ${rules.length} custom rules`),
+              onError: (error) => toast.error(error.message),
+            })
+          }
+        >
+          Reload custom rules
+        </Button>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        {rules?.map((rule) => (
+          <div key={rule.id} className="space-y-4">
+            <Separator />
+            <RuleRow
+              rule={rule}
+              definition={definitions?.find((d) => d.id === rule.id)}
+            />
+          </div>
+        ))}
+      </CardContent>
+    </Card>
+  );
+};
+
+const RuleRow = ({
+  rule,
+  definition,
+}: { rule: PluginRuleModel; definition?: RuleDisplay }) => {
+  const setEnabled = useSetRuleEnabledMutation();
+  const setSeverity = useSetRuleSeverityMutation();
+  const overrides = parseOverrides(rule);
+
+  return (
+    <div className="space-y-2">
+      <div className="flex items-center justify-between gap-4">
+        <label className="flex items-center gap-2">
+          <input
+            type="checkbox"
+            checked={rule.enabled}
+            onChange={(e) =>
+              setEnabled.mutate({ ruleId: rule.id, enabled: e.target.checked })
+            }
+          />
+          <span className="font-medium">{rule.name}</span>
+          <span className="text-muted-foreground text-sm">
+            {rule.plugin_name}
+          </span>
+        </label>
+        <select
+          className={selectClassName}
+          value={rule.severity_override ?? ""}
+          onChange={(e) =>
+            setSeverity.mutate({
+              ruleId: rule.id,
+              severity: (e.target.value || null) as DbSeverity | null,
+            })
+          }
+        >
+          <option value="">Default ({rule.severity})</option>
+          {severities.map((severity) => (
+            <option key={severity} value={severity}>
+              {severity}
+            </option>
+          ))}
+        </select>
+      </div>
+      {definition && definition.params.length > 0 && (
+        <div className="grid grid-cols-3 gap-4 pl-6">
+          {definition.params.map((param) => (
+            <RuleParamInput
+              key={param.name}
+              ruleId={rule.id}
+              param={param}
+              value={overrides[param.name] ?? param.default}
+            />
+          ))}
+        </div>
+      )}
+    </div>
+  );
+};
+
+const RuleParamInput = ({
+  ruleId,
+  param,
+  value,
+}: { ruleId: string; param: RuleParam; value: RuleParamValue }) => {
+  const setParam = useSetRuleParamMutation();
+  const save = (value: RuleParamValue) =>
+    setParam.mutate(
+      { ruleId, name: param.name, value },
+      { onError: (error) => toast.error(error.message) }
+    );
+
+  const kind = param.kind;
+  let input: ReactNode;
+  if (kind === "Boolean") {
+    input = (
+      <input
+        type="checkbox"
+        checked={value === true}
+        onChange={(e) => save(e.target.checked)}
+      />
+    );
+  } else if ("Choice" in kind) {
+    input = (
+      <select
+        className={selectClassName}
+        value={String(value)}
+        onChange={(e) => save(e.target.value)}
+      >
+        {kind.Choice.options.map((option) => (
+          <option key={option} value={option}>
+            {option}
+          </option>
+        ))}
+      </select>
+    );
+  } else {
+    input = (
+      <Input
+        type="number"
+        min={kind.Number.min}
+        max={kind.Number.max}
+        defaultValue={Number(value)}
+        onBlur={(e) => {
+          const next = e.target.valueAsNumber;
+          if (!Number.isNaN(next) && next !== value) {
+            save(next);
+          }
+        }}
+      />
+    );
+  }
+
+  return (
+    <label className="space-y-1 text-sm" title={param.description}>
+      <span className="text-muted-foreground">{param.label}</span>
+      {input}
+    </label>
+  );
+};
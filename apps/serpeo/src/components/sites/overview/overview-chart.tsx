@@ -46,14 +46,10 @@ export const ChartWrapper = ({ siteId }: ChartWrapperProps) => {
     const data: ChartData = {
       created_at: dayjs(item.created_at).format("M/D"),
     };
+    // Weighted health scores, categories without checked rules have none
     // biome-ignore lint/nursery/useGuardForIn: <explanation>
-    for (const key in item.data) {
-      const cat = item.data[key as RuleCategory];
-      if (cat && cat.total > 0) {
-        data[key as RuleCategory] = (cat.passed / cat.total) * 100;
-      } else if (cat) {
-        data[key as RuleCategory] = 0; // Default to 0% when total is 0
-      }
+    for (const key in item.scores) {
+      data[key as RuleCategory] = item.scores[key as RuleCategory];
     }
     return data;
   });
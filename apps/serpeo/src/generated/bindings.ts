@@ -53,6 +53,38 @@ async getSiteRunLinkCounts(siteRunId: number) : Promise<Result<SitePageLinkCount
     else return { status: "error", error: e  as any };
 }
 },
+async getSiteRunPages(siteRunId: number) : Promise<Result<SitePageModel[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_site_run_pages", { siteRunId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getPageInlinks(siteRunId: number, url: string) : Promise<Result<LinkedPage[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_page_inlinks", { siteRunId, url }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getPageOutlinks(siteRunId: number, url: string) : Promise<Result<LinkedPage[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_page_outlinks", { siteRunId, url }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getPageSharePreview(siteRunId: number, url: string) : Promise<Result<SharePreview | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_page_share_preview", { siteRunId, url }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async getCategoryResultDetail(siteRunId: number, passed: boolean | null) : Promise<Result<CategoryDetailResponse, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("get_category_result_detail", { siteRunId, passed }) };
@@ -61,6 +93,14 @@ async getCategoryResultDetail(siteRunId: number, passed: boolean | null) : Promi
     else return { status: "error", error: e  as any };
 }
 },
+async getRuleFindings(pageRuleResultId: number) : Promise<Result<RuleFindingModel[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_rule_findings", { pageRuleResultId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async getSiteCategoryHistory(siteId: number) : Promise<Result<CategoryResultHistory[], string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("get_site_category_history", { siteId }) };
@@ -68,6 +108,126 @@ async getSiteCategoryHistory(siteId: number) : Promise<Result<CategoryResultHist
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async getRules() : Promise<Result<PluginRuleModel[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_rules") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setRuleEnabled(ruleId: string, enabled: boolean) : Promise<Result<PluginRuleModel, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_rule_enabled", { ruleId, enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setRuleSeverity(ruleId: string, severity: DbSeverity | null) : Promise<Result<PluginRuleModel, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_rule_severity", { ruleId, severity }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setRuleWeight(ruleId: string, weight: number | null) : Promise<Result<PluginRuleModel, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_rule_weight", { ruleId, weight }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getScoreWeights() : Promise<Result<ScoreWeights, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_score_weights") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setScoreWeights(scoreWeights: ScoreWeights) : Promise<Result<ScoreWeights, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_score_weights", { scoreWeights }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getRuleDefinitions() : Promise<Result<RuleDisplay[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_rule_definitions") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setRuleParam(ruleId: string, name: string, value: RuleParamValue | null) : Promise<Result<PluginRuleModel, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_rule_param", { ruleId, name, value }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getRuleProfiles() : Promise<Result<RuleProfileModel[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_rule_profiles") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async saveCurrentRulesAsProfile(name: string, description: string) : Promise<Result<RuleProfileModel, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_current_rules_as_profile", { name, description }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteRuleProfile(id: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_rule_profile", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async importRuleProfile(contents: string, format: ProfileFormat) : Promise<Result<RuleProfileModel, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("import_rule_profile", { contents, format }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async exportRuleProfile(id: number, format: ProfileFormat) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_rule_profile", { id, format }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setSiteDefaultProfile(siteId: number, profileId: number | null) : Promise<Result<SiteModel, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_site_default_profile", { siteId, profileId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async reloadCustomRules() : Promise<Result<RuleDisplay[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("reload_custom_rules") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -96,30 +256,59 @@ export const CRAWL_SETTINGS_KEY = "crawl_settings" as const;
 export type AnalysisFinished = { site_run_id: number; result: CrawlResult }
 export type AnalysisProgress = { progress_type: AnalysisProgressType; url: string | null; total_pages: number; completed_pages: number }
 export type AnalysisProgressType = "FoundLink" | { AnalyzedPage: PageLink } | { AnalyzedSite: RuleResult[] }
-export type AnalysisStart = { base_url: string }
+export type AnalysisStart = { base_url: string; rule_profile_id: number | null }
 export type CategoryDetailResponse = { data: Partial<{ [key in DbRuleCategory]: FlatRuleResult[] }> }
-export type CategoryResult = { total: number; passed: number; failed: number }
-export type CategoryResultDisplay = { data: Partial<{ [key in DbRuleCategory]: CategoryResult }>; total: number; passed: number; failed: number }
-export type CategoryResultHistory = { data: Partial<{ [key in DbRuleCategory]: CategoryResult }>; created_at: string }
+export type CategoryResult = { total: number; passed: number; failed: number; not_applicable: number; errored: number }
+export type CategoryResultDisplay = { data: Partial<{ [key in DbRuleCategory]: CategoryResult }>; total: number; passed: number; failed: number; not_applicable: number; errored: number }
+export type CategoryResultHistory = { data: Partial<{ [key in DbRuleCategory]: CategoryResult }>; created_at: string; score: number | null; scores: Partial<{ [key in DbRuleCategory]: number }> }
+export type ContentMetrics = { word_count: number; sentence_count: number; paragraph_count: number; text_html_ratio: number; readability: Readability | null }
+export type ContextValue = { value: string; related_urls: string[] }
 export type CrawlResult = { page_results: PageLink[]; site_result: RuleResult[]; total_pages: number }
 export type CrawlSettingsStore = { max_concurrent_requests: number; request_delay_ms: number }
+export type DbLinkPosition = "Header" | "Nav" | "Main" | "Aside" | "Footer" | "Body"
+export type DbLinkSourceType = "Sitemap" | "Root" | "Link"
 export type DbLinkType = "Internal" | "External" | "Mailto" | "Tel" | "Unknown"
 export type DbRuleCategory = "Accessibility" | "Performance" | "BestPractices" | "SEO"
+export type DbRuleStatus = "Passed" | "Failed" | "NotApplicable" | "Errored"
+export type DbRuleType = "Page" | "Site"
 export type DbSeverity = "Info" | "Warning" | "Error" | "Critical"
-export type FlatRuleResult = { rule_id: string; name: string; plugin_name: string; passed: boolean; message: string; severity: DbSeverity; category: DbRuleCategory; page_url: string }
+export type Effort = "Low" | "Medium" | "High"
+export type Finding = { selector: string; html: string; line: number | null; column: number | null; value: string | null }
+export type FlatRuleResult = { page_rule_result_id: number; rule_id: string; name: string; plugin_name: string; passed: boolean; status: DbRuleStatus; message: string; severity: DbSeverity; category: DbRuleCategory; page_url: string; remediation: Remediation | null; context: ResultContext | null }
+export type Link = { href: string; path: string; link_type: LinkType; anchor_text: string; rel: string[]; position: LinkPosition; title: string | null; image_alt: string | null }
+export type LinkPosition = "Header" | "Nav" | "Main" | "Aside" | "Footer" | "Body"
 export type LinkSourceType = "Sitemap" | "Root" | "Link"
 export type LinkType = "Internal" | "External" | "Mailto" | "Tel" | "Unknown"
-export type PageLink = { url: string; link_type: LinkType; found_in: PageLinkSource[]; result: PageResult | null }
+export type LinkedPage = { url: string; anchor_text: string; rel: string | null; position: DbLinkPosition }
+export type PageLink = { url: string; link_type: LinkType; found_in: PageLinkSource[]; result: PageResult | null; depth: number | null; discovered_by: LinkSourceType; metadata: PageMetadata | null; outlinks: Link[]; link_metrics: PageLinkMetrics | null }
+export type PageLinkMetrics = { inlinks: number; outlinks: number; link_score: number }
 export type PageLinkSource = { link_source_type: LinkSourceType; url: string }
+export type PageMetadata = { status_code: number | null; response_time_ms: number | null; content_length: number | null; redirected: boolean; title: string | null; description: string | null; canonical: string | null; share_preview: SharePreview; content_metrics: ContentMetrics | null }
 export type PageResult = { error: boolean; results: RuleResult[] }
+export type PluginRuleModel = { id: string; name: string; plugin_name: string; description: string; severity: DbSeverity; category: DbRuleCategory; rule_type: DbRuleType; passed_message: string; failed_message: string; enabled: boolean; created_at: string; updated_at: string; severity_override: DbSeverity | null; params: string | null; weight: number | null; remediation: string | null }
+export type ProfileFormat = "Toml" | "Json"
+export type Readability = { language: string; formula: string; reading_ease: number; grade_level: number }
+export type Remediation = { why_it_matters: string; how_to_fix: string; example: string | null; references: string[]; effort: Effort }
+export type ResultContext = { values: ContextValue[] }
 export type RuleCategory = "Accessibility" | "Performance" | "BestPractices" | "SEO"
-export type RuleResult = { rule_id: string; name: string; plugin_name: string; passed: boolean; message: string; severity: Severity; category: RuleCategory; context: SiteCheckContext }
+export type RuleDisplay = { id: string; name: string; plugin_name: string; description: string; severity: Severity; category: RuleCategory; rule_type: RuleType; passed_message: string; failed_message: string; params: RuleParam[]; remediation: Remediation | null }
+export type RuleFindingModel = { id: number; page_rule_result_id: number; site_run_id: number; selector: string; html: string; source_line: number | null; source_column: number | null; value: string | null; created_at: string }
+export type RuleParam = { name: string; label: string; description: string; kind: RuleParamKind; default: RuleParamValue }
+export type RuleParamKind = { Number: { min: number; max: number } } | { Choice: { options: string[] } } | "Boolean"
+export type RuleParamValue = boolean | number | string
+export type RuleProfileModel = { id: number; name: string; description: string; rules: string; enable_unlisted: boolean; created_at: string; updated_at: string }
+export type RuleResult = { rule_id: string; name: string; plugin_name: string; passed: boolean; status: RuleStatus; message: string; severity: Severity; category: RuleCategory; context: SiteCheckContext; findings: Finding[] }
+export type RuleStatus = "Passed" | "Failed" | "NotApplicable" | "Errored"
+export type RuleType = "Page" | "Site"
 export type Severity = "Info" | "Warning" | "Error" | "Critical"
+export type ScoreWeights = { info: number; warning: number; error: number; critical: number }
+export type SharePreview = { url: string; site_name: string; title: string | null; description: string | null; image: string | null; twitter_card: string | null; twitter_title: string | null; twitter_description: string | null; twitter_image: string | null }
 export type SiteCheckContext = { Urls: string[] } | { Values: Partial<{ [key in string]: string[] }> } | "Empty"
-export type SiteModel = { id: number; name: string; url: string; created_at: string }
+export type SiteModel = { id: number; name: string; url: string; created_at: string; default_profile_id: number | null }
 export type SitePageLinkCount = { db_link_type: DbLinkType; count: number }
+export type SitePageModel = { id: number; site_id: number; site_run_id: number; url: string; db_link_type: DbLinkType; created_at: string; status_code: number | null; response_time_ms: number | null; content_length: number | null; redirected: boolean | null; title: string | null; description: string | null; canonical: string | null; depth: number | null; discovery_source: DbLinkSourceType | null; inlinks: number | null; outlinks: number | null; link_score: number | null; share_preview: string | null; word_count: number | null; paragraph_count: number | null; text_html_ratio: number | null; reading_ease: number | null; grade_level: number | null }
 export type SiteRunIdSet = { site_run_id: number }
-export type SiteRunModel = { id: number; site_id: number; created_at: string; status: SiteRunStatus }
+export type SiteRunModel = { id: number; site_id: number; created_at: string; status: SiteRunStatus; rule_profile_id: number | null; score: number | null; category_scores: string | null }
 export type SiteRunStatus = "Pending" | "Running" | "Finished" | "Error"
 export type SiteWithSiteRuns = { site: SiteModel; last_site_run_at: string; site_runs: SiteRunModel[] }
 
@@ -0,0 +1,106 @@
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import {
+  type DbSeverity,
+  type RuleParamValue,
+  commands,
+} from "../generated/bindings";
+
+export const useRulesQuery = () => {
+  return useQuery({
+    queryKey: ["rules", "all"],
+    queryFn: async () => {
+      const rules = await commands.getRules();
+      if (rules.status === "ok") {
+        return rules.data;
+      }
+      return [];
+    },
+  });
+};
+
+export const useRuleDefinitionsQuery = () => {
+  return useQuery({
+    queryKey: ["rules", "definitions"],
+    queryFn: async () => {
+      const definitions = await commands.getRuleDefinitions();
+      if (definitions.status === "ok") {
+        return definitions.data;
+      }
+      return [];
+    },
+  });
+};
+
+const useInvalidateRules = () => {
+  const queryClient = useQueryClient();
+  return () =>
+    queryClient.invalidateQueries({
+      predicate: (query) => query.queryKey[0] === "rules",
+    });
+};
+
+export const useSetRuleEnabledMutation = () => {
+  const invalidate = useInvalidateRules();
+  return useMutation({
+    mutationFn: async ({
+      ruleId,
+      enabled,
+    }: { ruleId: string; enabled: boolean }) => {
+      const rule = await commands.setRuleEnabled(ruleId, enabled);
+      if (rule.status === "error") {
+        throw new Error(rule.error);
+      }
+      return rule.data;
+    },
+    onSuccess: invalidate,
+  });
+};
+
+export const useSetRuleSeverityMutation = () => {
+  const invalidate = useInvalidateRules();
+  return useMutation({
+    mutationFn: async ({
+      ruleId,
+      severity,
+    }: { ruleId: string; severity: DbSeverity | null }) => {
+      const rule = await commands.setRuleSeverity(ruleId, severity);
+      if (rule.status === "error") {
+        throw new Error(rule.error);
+      }
+      return rule.data;
+    },
+    onSuccess: invalidate,
+  });
+};
+
+export const useSetRuleParamMutation = () => {
+  const invalidate = useInvalidateRules();
+  return useMutation({
+    mutationFn: async ({
+      ruleId,
+      name,
+      value,
+    }: { ruleId: string; name: string; value: RuleParamValue | null }) => {
+      const rule = await commands.setRuleParam(ruleId, name, value);
+      if (rule.status === "error") {
+        throw new Error(rule.error);
+      }
+      return rule.data;
+    },
+    onSuccess: invalidate,
+  });
+};
+
+export const useReloadCustomRulesMutation = () => {
+  const invalidate = useInvalidateRules();
+  return useMutation({
+    mutationFn: async () => {
+      const rules = await commands.reloadCustomRules();
+      if (rules.status === "error") {
+        throw new Error(rules.error);
+      }
+      return rules.data;
+    },
+    onSuccess: invalidate,
+  });
+};
@@ -104,3 +104,34 @@ export const useSiteRunCategoryResultDetailQuery = (
     },
   });
 };
+
+export const useSiteRunPagesQuery = (siteRunId: number) => {
+  return useQuery({
+    refetchInterval: 1000,
+    queryKey: ["siteRun", "pages", siteRunId],
+    queryFn: async () => {
+      const siteRunPages = await commands.getSiteRunPages(siteRunId);
+      if (siteRunPages.status === "ok") {
+        return siteRunPages.data;
+      }
+      return [];
+    },
+  });
+};
+
+export const useSharePreviewQuery = (siteRunId: number, url: string | null) => {
+  return useQuery({
+    queryKey: ["siteRun", "sharePreview", siteRunId, url],
+    enabled: url !== null,
+    queryFn: async () => {
+      if (url === null) {
+        return null;
+      }
+      const sharePreview = await commands.getPageSharePreview(siteRunId, url);
+      if (sharePreview.status === "ok") {
+        return sharePreview.data;
+      }
+      return null;
+    },
+  });
+};
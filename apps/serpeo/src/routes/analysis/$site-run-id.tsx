@@ -25,6 +25,7 @@ import { AnalysisStatus } from "../../components/analysis-status";
 import { IssueCategoryDetail } from "../../components/display/issue-category-detail";
 import { IssueCategoryOverview } from "../../components/display/issue-category-overview";
 import { LinkDisplay } from "../../components/display/link-display";
+import { PageInventory } from "../../components/display/page-inventory";
 import { events, commands } from "../../generated/bindings";
 
 dayjs.extend(relativeTime);
@@ -117,6 +118,7 @@ function SiteRun() {
           <LinkDisplay siteRunId={siteRun.id} />
           <IssueCategoryOverview siteRunId={siteRun.id} />
           <IssueCategoryDetail siteRunId={siteRun.id} />
+          <PageInventory siteRunId={siteRun.id} />
         </div>
       </CardContent>
     </Card>
@@ -1,5 +1,6 @@
 import { createFileRoute } from "@tanstack/react-router";
 import { SettingsCard } from "../../components/settings/card";
+import { RulesCard } from "../../components/settings/rules";
 
 export const Route = createFileRoute("/settings/")({
   component: SettingsPage,
@@ -7,8 +8,9 @@ export const Route = createFileRoute("/settings/")({
 
 function SettingsPage() {
   return (
-    <div className="container mx-auto py-8 ">
+    <div className="container mx-auto space-y-4 py-8">
       <SettingsCard />
+      <RulesCard />
     </div>
   );
 }
@@ -1,9 +1,24 @@
 // mod lighthouse;
 
 pub use seo_plugins::{
+    plugins::{
+        custom_rule::CustomRulePlugin,
+        wasm::{WasmLimits, WasmPluginRuntime},
+    },
     site_analyzer::{AnalysisProgress, AnalysisProgressType, CrawlResult, SiteAnalyzer},
-    utils::{crawl_config::CrawlConfig, page::Page},
+    utils::{
+        config::{RuleConfig, RuleDisplay},
+        crawl_config::CrawlConfig,
+        fetcher::Fetcher,
+        page::Page,
+        page_plugin::SeoPlugin,
+        profile::ProfileFormat,
+        registry::PluginRegistry,
+        rule_param::RuleParamValue,
+        share_preview::SharePreview,
+    },
 };
+use std::time::Duration;
 use thiserror::Error;
 
 // pub use lighthouse::{run_lighthouse_analysis, CommandOutput, LighthouseMetrics, ShellCommand};
@@ -22,13 +37,19 @@ pub enum SeoError {
 
 pub async fn crawl_url(
     config: &CrawlConfig,
+    mut registry: PluginRegistry,
     progress_callback: Box<dyn Fn(AnalysisProgress) + Send + Sync + 'static>,
 ) -> Result<CrawlResult, SeoError> {
     let url = config
         .get_url()
         .map_err(|e| SeoError::UrlParseError(e.to_string()))?;
+    // Async rules fetch linked resources under the same limits as the crawl
+    registry.set_fetcher(Fetcher::new(
+        config.max_concurrent_requests as usize,
+        Duration::from_millis(config.request_delay_ms as u64),
+    ));
     let site =
-        SiteAnalyzer::new_with_default(url).map_err(|e| SeoError::AnalysisError(e.to_string()))?;
+        SiteAnalyzer::new(url, registry).map_err(|e| SeoError::AnalysisError(e.to_string()))?;
     site.with_progress_callback(move |progress| {
         progress_callback(progress);
     })
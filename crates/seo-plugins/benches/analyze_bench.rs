@@ -1,6 +1,49 @@
 use criterion::async_executor::FuturesExecutor;
-use criterion::{Criterion, black_box, criterion_group, criterion_main};
-use seo_plugins::utils::{page::Page, registry::PluginRegistry};
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use seo_plugins::plugins::{axe::AxePlugin, image::ImagePlugin, seo_basic::SeoBasicPlugin};
+use seo_plugins::utils::{
+    config::{Rule, RuleConfig},
+    page::Page,
+    page_plugin::SeoPlugin,
+    registry::PluginRegistry,
+};
+
+// Builds a page with `sections` blocks of mixed content, roughly 1KB each
+fn large_page(sections: usize) -> String {
+    let mut body = String::new();
+    for i in 0..sections {
+        body.push_str(&format!(
+            r#"
+            <section id="section-{i}">
+                <h2>Section {i}</h2>
+                <p>Lorem ipsum dolor sit amet, <a href="/page-{i}">consectetur</a> adipiscing elit.</p>
+                <img src="/images/{i}.jpg" alt="Image {i}" srcset="/images/{i}.jpg 1x, /images/{i}-2x.jpg 2x">
+                <form>
+                    <label for="field-{i}">Field</label>
+                    <input id="field-{i}" type="text">
+                    <button aria-label="Submit {i}"></button>
+                </form>
+                <div role="button" tabindex="0" aria-pressed="false">Toggle</div>
+                <iframe src="/embed/{i}" title="Embed {i}"></iframe>
+            </section>
+            "#
+        ));
+    }
+
+    format!(
+        r#"<!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <title>Large Test Page</title>
+                <meta name="description" content="Large test description">
+                <meta name="viewport" content="width=device-width, initial-scale=1">
+                <meta charset="utf-8">
+                <link rel="canonical" href="https://example.com/">
+            </head>
+            <body>{body}</body>
+        </html>"#
+    )
+}
 
 fn analyze_benchmark(c: &mut Criterion) {
     let mut group = c.benchmark_group("analyze");
@@ -33,19 +76,70 @@ fn analyze_benchmark(c: &mut Criterion) {
     // Benchmark sync analyze
     group.bench_function("sync_analyze", |b| {
         b.iter(|| {
-            black_box(registry.analyze(&page));
+            let _ = black_box(registry.analyze(&page));
         })
     });
 
     // Benchmark async analyze
     group.bench_function("async_analyze", |b| {
         b.to_async(FuturesExecutor).iter(|| async {
-            black_box(registry.analyze_async(&page).await);
+            let _ = black_box(registry.analyze_async(&page).await);
         })
     });
 
     group.finish();
 }
 
-criterion_group!(benches, analyze_benchmark);
+fn large_page_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("large_page");
+    group.sample_size(20);
+
+    let plugins: Vec<Box<dyn SeoPlugin>> = vec![
+        Box::new(AxePlugin::new()),
+        Box::new(ImagePlugin::new()),
+        Box::new(SeoBasicPlugin::new()),
+    ];
+    let rules: Vec<Rule> = plugins
+        .iter()
+        .flat_map(|plugin| plugin.available_rules())
+        .collect();
+    let config = RuleConfig::new();
+
+    for sections in [100, 1_000] {
+        let html = large_page(sections);
+
+        // Every rule gets a fresh page, so the document is parsed once per rule.
+        // This is what each rule paid before the parsed document was shared.
+        group.bench_with_input(
+            BenchmarkId::new("parse_per_rule", sections),
+            &html,
+            |b, html| {
+                b.iter(|| {
+                    for rule in &rules {
+                        let page = Page::from_html(html.clone());
+                        let _ = black_box(rule.run(&page, &config));
+                    }
+                })
+            },
+        );
+
+        // One page per iteration, parsed once and shared by all rules
+        group.bench_with_input(
+            BenchmarkId::new("shared_document", sections),
+            &html,
+            |b, html| {
+                b.iter(|| {
+                    let page = Page::from_html(html.clone());
+                    for rule in &rules {
+                        let _ = black_box(rule.run(&page, &config));
+                    }
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, analyze_benchmark, large_page_benchmark);
 criterion_main!(benches);
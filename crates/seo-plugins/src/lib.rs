@@ -2,3 +2,6 @@ pub mod plugins;
 pub mod site_analyzer;
 pub mod site_plugins;
 pub mod utils;
+
+#[cfg(test)]
+pub(crate) mod test_utils;
@@ -1,8 +1,7 @@
-#![allow(clippy::unwrap_used)]
 use std::any::Any;
 
 use crate::utils::{
-    config::{CheckResult, Rule, RuleCategory, Severity},
+    config::{CheckError, CheckResult, Effort, Remediation, Rule, RuleCategory, Severity},
     page_plugin::SeoPlugin,
 };
 use scraper::Selector;
@@ -46,12 +45,28 @@ impl SeoPlugin for AxePlugin {
                 category: RuleCategory::Accessibility,
                 passed_message: "HTML has lang attribute",
                 failed_message: "HTML is missing lang attribute",
-                check: |page| {
-                    let page = page.clone();
-                    let lang = page.get_element("html").unwrap();
-                    let has_lang = lang.attr("lang").is_some();
-
-                    CheckResult {
+                remediation: Some(
+                    Remediation::new(
+                        "Screen readers use the lang attribute to pick the pronunciation rules for the page.",
+                        "Set the lang attribute on the <html> element to the page's language.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<html lang="en">"#)
+                    .with_reference("https://dequeuniversity.com/rules/axe/4.10/html-has-lang")
+                ),
+                check: |page, _params| {
+                    let selector = Selector::parse("html")?;
+                    let document = page.get_document()?;
+                    let html =
+                        document
+                            .select(&selector)
+                            .next()
+                            .ok_or(CheckError::NotApplicable(
+                                "Page has no html element".to_string(),
+                            ))?;
+                    let has_lang = html.attr("lang").is_some();
+
+                    Ok(CheckResult {
                         rule_id: "axe.html_has_lang".to_string(),
                         passed: has_lang,
                         message: if has_lang {
@@ -59,8 +74,14 @@ impl SeoPlugin for AxePlugin {
                         } else {
                             "HTML is missing lang attribute".to_string()
                         },
-                    }
+                        findings: if has_lang {
+                            vec![]
+                        } else {
+                            vec![page.finding(&html)]
+                        },
+                    })
                 },
+                params: vec![],
             },
             Rule {
                 id: "axe.image_alt",
@@ -71,15 +92,24 @@ impl SeoPlugin for AxePlugin {
                 category: RuleCategory::Accessibility,
                 passed_message: "All images have alt text",
                 failed_message: "Images missing alt text",
-                check: |page| {
-                    let page = page.clone();
-                    let images = page.extract_images();
-                    let images_without_alt = images
-                        .iter()
-                        .filter(|img| img.alt.is_none())
+                remediation: Some(
+                    Remediation::new(
+                        "Screen reader users hear nothing, or the file name, for images without alternative text, and search engines can't tell what the image shows.",
+                        "Describe each meaningful image in its alt attribute. Use an empty alt for purely decorative images.",
+                        Effort::Medium,
+                    )
+                    .with_example(r#"<img src="trail.jpg" alt="Runner crossing a stream on a forest trail">"#)
+                    .with_reference("https://dequeuniversity.com/rules/axe/4.10/image-alt")
+                ),
+                check: |page, _params| {
+                    let selector = Selector::parse("img")?;
+                    let document = page.get_document()?;
+                    let images_without_alt = document
+                        .select(&selector)
+                        .filter(|img| img.attr("alt").is_none())
                         .collect::<Vec<_>>();
 
-                    CheckResult {
+                    Ok(CheckResult {
                         rule_id: "axe.image_alt".to_string(),
                         passed: images_without_alt.is_empty(),
                         message: if images_without_alt.is_empty() {
@@ -87,8 +117,16 @@ impl SeoPlugin for AxePlugin {
                         } else {
                             format!("{} images missing alt text", images_without_alt.len())
                         },
-                    }
+                        findings: images_without_alt
+                            .iter()
+                            .map(|img| {
+                                page.finding(img)
+                                    .with_value(img.attr("src").unwrap_or_default())
+                            })
+                            .collect(),
+                    })
                 },
+                params: vec![],
             },
             Rule {
                 id: "axe.meta_viewport",
@@ -99,8 +137,16 @@ impl SeoPlugin for AxePlugin {
                 category: RuleCategory::Accessibility,
                 passed_message: "Meta viewport allows zooming",
                 failed_message: "Meta viewport disables zooming",
-                check: |page| {
-                    let page = page.clone();
+                remediation: Some(
+                    Remediation::new(
+                        "Disabling zoom stops people with low vision from enlarging text.",
+                        "Remove user-scalable=no and any maximum-scale below 5 from the viewport meta tag.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<meta name="viewport" content="width=device-width, initial-scale=1">"#)
+                    .with_reference("https://dequeuniversity.com/rules/axe/4.10/meta-viewport")
+                ),
+                check: |page, _params| {
                     let meta_tags = page.extract_meta_tags();
 
                     let viewport = meta_tags.viewport.unwrap_or_default();
@@ -109,7 +155,7 @@ impl SeoPlugin for AxePlugin {
                         || viewport.contains("maximum-scale=1")
                         || viewport.is_empty();
 
-                    CheckResult {
+                    Ok(CheckResult {
                         rule_id: "axe.meta_viewport".to_string(),
                         passed: !disables_zoom,
                         message: if !disables_zoom {
@@ -117,8 +163,10 @@ impl SeoPlugin for AxePlugin {
                         } else {
                             "Meta viewport disables zooming".to_string()
                         },
-                    }
+                        findings: vec![],
+                    })
                 },
+                params: vec![],
             },
             Rule {
                 id: "axe.document_title",
@@ -129,11 +177,19 @@ impl SeoPlugin for AxePlugin {
                 category: RuleCategory::Accessibility,
                 passed_message: "Document has title",
                 failed_message: "Document is missing title",
-                check: |page| {
-                    let page = page.clone();
+                remediation: Some(
+                    Remediation::new(
+                        "The title is the first thing screen readers announce and helps users tell tabs apart.",
+                        "Add a <title> element to the <head> that describes the page.",
+                        Effort::Low,
+                    )
+                    .with_example("<title>Trail Running Shoes | Example Store</title>")
+                    .with_reference("https://dequeuniversity.com/rules/axe/4.10/document-title")
+                ),
+                check: |page, _params| {
                     let has_title = page.get_element("title").is_ok();
 
-                    CheckResult {
+                    Ok(CheckResult {
                         rule_id: "axe.document_title".to_string(),
                         passed: has_title,
                         message: if has_title {
@@ -141,8 +197,10 @@ impl SeoPlugin for AxePlugin {
                         } else {
                             "Document is missing title".to_string()
                         },
-                    }
+                        findings: vec![],
+                    })
                 },
+                params: vec![],
             },
             Rule {
                 id: "axe.button_name",
@@ -153,10 +211,18 @@ impl SeoPlugin for AxePlugin {
                 category: RuleCategory::Accessibility,
                 passed_message: "All buttons have accessible names",
                 failed_message: "Buttons missing accessible names",
-                check: |page| {
-                    let page = page.clone();
-                    let selector = Selector::parse("button").unwrap();
-                    let document = page.get_document().unwrap();
+                remediation: Some(
+                    Remediation::new(
+                        r#"Screen readers announce a button without a name as just "button", so users can't tell what it does."#,
+                        "Give each button visible text, or an aria-label when it only contains an icon.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<button type="button" aria-label="Close menu"><svg aria-hidden="true">...</svg></button>"#)
+                    .with_reference("https://dequeuniversity.com/rules/axe/4.10/button-name")
+                ),
+                check: |page, _params| {
+                    let selector = Selector::parse("button")?;
+                    let document = page.get_document()?;
                     let buttons = document.select(&selector);
                     let buttons_without_name = buttons
                         .filter(|button| {
@@ -166,7 +232,7 @@ impl SeoPlugin for AxePlugin {
                         })
                         .collect::<Vec<_>>();
 
-                    CheckResult {
+                    Ok(CheckResult {
                         rule_id: "axe.button_name".to_string(),
                         passed: buttons_without_name.is_empty(),
                         message: if buttons_without_name.is_empty() {
@@ -177,8 +243,13 @@ impl SeoPlugin for AxePlugin {
                                 buttons_without_name.len()
                             )
                         },
-                    }
+                        findings: buttons_without_name
+                            .iter()
+                            .map(|button| page.finding(button))
+                            .collect(),
+                    })
                 },
+                params: vec![],
             },
             Rule {
                 id: "axe.link_name",
@@ -189,10 +260,18 @@ impl SeoPlugin for AxePlugin {
                 category: RuleCategory::Accessibility,
                 passed_message: "All links have accessible names",
                 failed_message: "Links missing accessible names",
-                check: |page| {
-                    let page = page.clone();
-                    let selector = Selector::parse("a").unwrap();
-                    let document = page.get_document().unwrap();
+                remediation: Some(
+                    Remediation::new(
+                        r#"Links without a name are announced as just "link" or by their URL, and give search engines no anchor text."#,
+                        "Give each link descriptive text, or an aria-label when it only contains an icon or image.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<a href="/cart" aria-label="Shopping cart"><svg aria-hidden="true">...</svg></a>"#)
+                    .with_reference("https://dequeuniversity.com/rules/axe/4.10/link-name")
+                ),
+                check: |page, _params| {
+                    let selector = Selector::parse("a")?;
+                    let document = page.get_document()?;
                     let links = document.select(&selector);
                     // let links_length = links.count();
                     let links_without_name = links
@@ -203,7 +282,7 @@ impl SeoPlugin for AxePlugin {
                         })
                         .collect::<Vec<_>>();
 
-                    CheckResult {
+                    Ok(CheckResult {
                         rule_id: "axe.link_name".to_string(),
                         passed: links_without_name.is_empty(),
                         message: if links_without_name.is_empty() {
@@ -214,8 +293,16 @@ impl SeoPlugin for AxePlugin {
                                 links_without_name.len()
                             )
                         },
-                    }
+                        findings: links_without_name
+                            .iter()
+                            .map(|link| {
+                                page.finding(link)
+                                    .with_value(link.attr("href").unwrap_or_default())
+                            })
+                            .collect(),
+                    })
                 },
+                params: vec![],
             },
             Rule {
                 id: "axe.form_field_labels",
@@ -226,10 +313,18 @@ impl SeoPlugin for AxePlugin {
                 category: RuleCategory::Accessibility,
                 passed_message: "All form fields have labels",
                 failed_message: "Form fields missing labels",
-                check: |page| {
-                    let page = page.clone();
-                    let selector = Selector::parse("input, select, textarea").unwrap();
-                    let document = page.get_document().unwrap();
+                remediation: Some(
+                    Remediation::new(
+                        "Without a label, screen reader users don't know what to enter in a field.",
+                        "Associate a <label> with each field through its for attribute, or wrap the field in the label.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<label for="email">Email address</label> <input id="email" type="email" name="email">"#)
+                    .with_reference("https://dequeuniversity.com/rules/axe/4.10/label")
+                ),
+                check: |page, _params| {
+                    let selector = Selector::parse("input, select, textarea")?;
+                    let document = page.get_document()?;
                     let form_fields = document.select(&selector);
                     let fields_without_labels = form_fields
                         .filter(|field| {
@@ -242,7 +337,7 @@ impl SeoPlugin for AxePlugin {
                         })
                         .collect::<Vec<_>>();
 
-                    CheckResult {
+                    Ok(CheckResult {
                         rule_id: "axe.form_field_labels".to_string(),
                         passed: fields_without_labels.is_empty(),
                         message: if fields_without_labels.is_empty() {
@@ -250,8 +345,13 @@ impl SeoPlugin for AxePlugin {
                         } else {
                             format!("{} form fields missing labels", fields_without_labels.len())
                         },
-                    }
+                        findings: fields_without_labels
+                            .iter()
+                            .map(|field| page.finding(field))
+                            .collect(),
+                    })
                 },
+                params: vec![],
             },
             Rule {
                 id: "axe.aria_valid_attr",
@@ -262,10 +362,18 @@ impl SeoPlugin for AxePlugin {
                 category: RuleCategory::Accessibility,
                 passed_message: "All ARIA attributes are valid",
                 failed_message: "Found elements with invalid ARIA attributes",
-                check: |page| {
-                    let page = page.clone();
-                    let selector = Selector::parse("[aria-]").unwrap();
-                    let document = page.get_document().unwrap();
+                remediation: Some(
+                    Remediation::new(
+                        "Assistive technologies ignore misspelled or made-up ARIA attributes, so the information they were meant to convey is lost.",
+                        "Fix the attribute names to valid aria-* attributes, or remove them.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<div role="tab" aria-selected="true">Details</div>"#)
+                    .with_reference("https://dequeuniversity.com/rules/axe/4.10/aria-valid-attr")
+                ),
+                check: |page, _params| {
+                    let selector = Selector::parse("[aria-]")?;
+                    let document = page.get_document()?;
                     let elements = document.select(&selector);
                     let invalid_attrs = elements
                         .filter_map(|element| {
@@ -279,12 +387,12 @@ impl SeoPlugin for AxePlugin {
                             if invalid.is_empty() {
                                 None
                             } else {
-                                Some(invalid)
+                                Some((element, invalid))
                             }
                         })
                         .collect::<Vec<_>>();
 
-                    CheckResult {
+                    Ok(CheckResult {
                         rule_id: "axe.aria_valid_attr".to_string(),
                         passed: invalid_attrs.is_empty(),
                         message: if invalid_attrs.is_empty() {
@@ -295,8 +403,20 @@ impl SeoPlugin for AxePlugin {
                                 invalid_attrs.len()
                             )
                         },
-                    }
+                        findings: invalid_attrs
+                            .iter()
+                            .map(|(element, invalid)| {
+                                let value = invalid
+                                    .iter()
+                                    .map(|(name, value)| format!("{}=\"{}\"", name.local, value))
+                                    .collect::<Vec<_>>()
+                                    .join(" ");
+                                page.finding(element).with_value(value)
+                            })
+                            .collect(),
+                    })
                 },
+                params: vec![],
             },
             Rule {
                 id: "axe.aria_required_attr",
@@ -307,10 +427,18 @@ impl SeoPlugin for AxePlugin {
                 category: RuleCategory::Accessibility,
                 passed_message: "All ARIA roles have required attributes",
                 failed_message: "Found elements missing required ARIA attributes",
-                check: |page| {
-                    let page = page.clone();
-                    let selector = Selector::parse("[role]").unwrap();
-                    let document = page.get_document().unwrap();
+                remediation: Some(
+                    Remediation::new(
+                        "Elements with an ARIA role but without its required attributes expose an incomplete state to assistive technologies.",
+                        r#"Add the attributes the role requires, e.g. aria-checked for role="checkbox"."#,
+                        Effort::Low,
+                    )
+                    .with_example(r#"<div role="checkbox" aria-checked="false" tabindex="0">Subscribe</div>"#)
+                    .with_reference("https://dequeuniversity.com/rules/axe/4.10/aria-required-attr")
+                ),
+                check: |page, _params| {
+                    let selector = Selector::parse("[role]")?;
+                    let document = page.get_document()?;
                     let elements = document.select(&selector);
                     let missing_attrs = elements
                         .filter_map(|element| {
@@ -324,7 +452,7 @@ impl SeoPlugin for AxePlugin {
                                 if missing.is_empty() {
                                     None
                                 } else {
-                                    Some(missing)
+                                    Some((element, role))
                                 }
                             } else {
                                 None
@@ -332,7 +460,7 @@ impl SeoPlugin for AxePlugin {
                         })
                         .collect::<Vec<_>>();
 
-                    CheckResult {
+                    Ok(CheckResult {
                         rule_id: "axe.aria_required_attr".to_string(),
                         passed: missing_attrs.is_empty(),
                         message: if missing_attrs.is_empty() {
@@ -343,8 +471,13 @@ impl SeoPlugin for AxePlugin {
                                 missing_attrs.len()
                             )
                         },
-                    }
+                        findings: missing_attrs
+                            .iter()
+                            .map(|(element, role)| page.finding(element).with_value(*role))
+                            .collect(),
+                    })
                 },
+                params: vec![],
             },
             Rule {
                 id: "axe.duplicate_id",
@@ -355,23 +488,33 @@ impl SeoPlugin for AxePlugin {
                 category: RuleCategory::Accessibility,
                 passed_message: "No duplicate IDs found",
                 failed_message: "Found duplicate IDs",
-                check: |page| {
-                    let page = page.clone();
-                    let selector = Selector::parse("[id]").unwrap();
-                    let document = page.get_document().unwrap();
+                remediation: Some(
+                    Remediation::new(
+                        "Duplicate ids break label and ARIA references and in-page links, which all point at the first element with the id.",
+                        "Make every id unique within the page.",
+                        Effort::Medium,
+                    )
+                    .with_reference("https://dequeuniversity.com/rules/axe/4.10/duplicate-id")
+                ),
+                check: |page, _params| {
+                    let selector = Selector::parse("[id]")?;
+                    let document = page.get_document()?;
                     let elements = document.select(&selector);
                     let mut id_counts = std::collections::HashMap::new();
                     for element in elements {
                         if let Some(id) = element.attr("id") {
-                            *id_counts.entry(id.to_string()).or_insert(0) += 1;
+                            id_counts
+                                .entry(id.to_string())
+                                .or_insert_with(Vec::new)
+                                .push(element);
                         }
                     }
                     let duplicate_ids = id_counts
                         .iter()
-                        .filter(|(_, count)| **count > 1)
+                        .filter(|(_, elements)| elements.len() > 1)
                         .collect::<Vec<_>>();
 
-                    CheckResult {
+                    Ok(CheckResult {
                         rule_id: "axe.duplicate_id".to_string(),
                         passed: duplicate_ids.is_empty(),
                         message: if duplicate_ids.is_empty() {
@@ -379,8 +522,17 @@ impl SeoPlugin for AxePlugin {
                         } else {
                             format!("Found {} duplicate IDs", duplicate_ids.len())
                         },
-                    }
+                        findings: duplicate_ids
+                            .iter()
+                            .flat_map(|(id, elements)| {
+                                elements
+                                    .iter()
+                                    .map(|element| page.finding(element).with_value(id.as_str()))
+                            })
+                            .collect(),
+                    })
                 },
+                params: vec![],
             },
             Rule {
                 id: "axe.frame_title",
@@ -391,16 +543,24 @@ impl SeoPlugin for AxePlugin {
                 category: RuleCategory::Accessibility,
                 passed_message: "All frames have title attributes",
                 failed_message: "Frames missing title attributes",
-                check: |page| {
-                    let page = page.clone();
-                    let selector = Selector::parse("frame, iframe").unwrap();
-                    let document = page.get_document().unwrap();
+                remediation: Some(
+                    Remediation::new(
+                        "Screen reader users rely on frame titles to decide whether to enter a frame.",
+                        "Add a title attribute describing the frame's content to each <iframe>.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<iframe src="https://www.youtube.com/embed/..." title="Trail running shoe review video"></iframe>"#)
+                    .with_reference("https://dequeuniversity.com/rules/axe/4.10/frame-title")
+                ),
+                check: |page, _params| {
+                    let selector = Selector::parse("frame, iframe")?;
+                    let document = page.get_document()?;
                     let frames = document.select(&selector);
                     let frames_without_title = frames
                         .filter(|frame| frame.attr("title").is_none())
                         .collect::<Vec<_>>();
 
-                    CheckResult {
+                    Ok(CheckResult {
                         rule_id: "axe.frame_title".to_string(),
                         passed: frames_without_title.is_empty(),
                         message: if frames_without_title.is_empty() {
@@ -411,8 +571,16 @@ impl SeoPlugin for AxePlugin {
                                 frames_without_title.len()
                             )
                         },
-                    }
+                        findings: frames_without_title
+                            .iter()
+                            .map(|frame| {
+                                page.finding(frame)
+                                    .with_value(frame.attr("src").unwrap_or_default())
+                            })
+                            .collect(),
+                    })
                 },
+                params: vec![],
             },
             // Rule {
             //     id: "axe.skip_link",
@@ -420,10 +588,9 @@ impl SeoPlugin for AxePlugin {
             //     description: "Ensures skip link is present for keyboard navigation",
             //     default_severity: Severity::Warning,
             //     category: RuleCategory::Accessibility,
-            //     check: |page| {
-            //         let page = page.clone();
-            //         let selector = Selector::parse("a[href^='#']").unwrap();
-            //         let document = page.get_document().unwrap();
+            //     check: |page, _params| {
+            //         let selector = Selector::parse("a[href^='#']")?;
+            //         let document = page.get_document()?;
             //         let mut links = document.select(&selector);
             //         let has_skip_link = links.any(|link| {
             //             link.text()
@@ -440,8 +607,10 @@ impl SeoPlugin for AxePlugin {
             //             } else {
             //                 "Skip link not found".to_string()
             //             },
+            //             findings: vec![],
             //         }
             //     },
+            //     params: vec![],
             // },
             Rule {
                 id: "axe.tabindex",
@@ -452,10 +621,18 @@ impl SeoPlugin for AxePlugin {
                 category: RuleCategory::Accessibility,
                 passed_message: "No invalid tabindex values found",
                 failed_message: "Found elements with invalid tabindex values",
-                check: |page| {
-                    let page = page.clone();
-                    let selector = Selector::parse("[tabindex]").unwrap();
-                    let document = page.get_document().unwrap();
+                remediation: Some(
+                    Remediation::new(
+                        "A positive tabindex changes the keyboard focus order, which confuses keyboard and screen reader users.",
+                        r#"Use tabindex="0" or "-1" only and change the focus order by reordering the markup instead."#,
+                        Effort::Medium,
+                    )
+                    .with_example(r#"<div role="button" tabindex="0">Open filters</div>"#)
+                    .with_reference("https://dequeuniversity.com/rules/axe/4.10/tabindex")
+                ),
+                check: |page, _params| {
+                    let selector = Selector::parse("[tabindex]")?;
+                    let document = page.get_document()?;
                     let elements = document.select(&selector);
                     let invalid_tabindex = elements
                         .filter(|element| {
@@ -467,7 +644,7 @@ impl SeoPlugin for AxePlugin {
                         })
                         .collect::<Vec<_>>();
 
-                    CheckResult {
+                    Ok(CheckResult {
                         rule_id: "axe.tabindex".to_string(),
                         passed: invalid_tabindex.is_empty(),
                         message: if invalid_tabindex.is_empty() {
@@ -478,8 +655,16 @@ impl SeoPlugin for AxePlugin {
                                 invalid_tabindex.len()
                             )
                         },
-                    }
+                        findings: invalid_tabindex
+                            .iter()
+                            .map(|element| {
+                                page.finding(element)
+                                    .with_value(element.attr("tabindex").unwrap_or_default())
+                            })
+                            .collect(),
+                    })
                 },
+                params: vec![],
             },
         ]
     }
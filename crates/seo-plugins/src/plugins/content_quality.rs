@@ -0,0 +1,230 @@
+use std::any::{Any, TypeId};
+
+use crate::plugins::main_text::MainTextPlugin;
+
+use crate::utils::{
+    config::{CheckError, CheckResult, Effort, Remediation, Rule, RuleCategory, Severity},
+    content::ContentMetrics,
+    page::{Page, PageError},
+    page_plugin::SeoPlugin,
+    rule_param::RuleParam,
+};
+
+// Fewer words than this make readability scores meaningless
+const MIN_READABILITY_WORDS: u32 = 100;
+
+pub struct ContentQualityPlugin {}
+
+impl Default for ContentQualityPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContentQualityPlugin {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+const PLUGIN_NAME: &str = "Content Quality";
+
+// The metrics published by `derive_outputs`, computed on the spot when the plugin
+// runs outside a registry
+fn metrics(page: &Page) -> ContentMetrics {
+    page.outputs()
+        .get::<ContentMetrics>()
+        .map(|metrics| (*metrics).clone())
+        .unwrap_or_else(|| ContentMetrics::from(page))
+}
+
+impl SeoPlugin for ContentQualityPlugin {
+    fn name(&self) -> &str {
+        PLUGIN_NAME
+    }
+
+    fn description(&self) -> &str {
+        "Measures how much the main content says and how hard it is to read"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    // Computes its metrics from the `MainText` the main text plugin publishes
+    fn dependencies(&self) -> Vec<TypeId> {
+        vec![TypeId::of::<MainTextPlugin>()]
+    }
+
+    fn derive_outputs(&self, page: &Page) -> Result<(), PageError> {
+        page.outputs().insert(ContentMetrics::from(page));
+        Ok(())
+    }
+
+    fn available_rules(&self) -> Vec<Rule> {
+        vec![
+            Rule {
+                id: "content_quality.thin_content",
+                name: "Page has enough content",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that the main content, without navigation and other boilerplate, has enough words",
+                default_severity: Severity::Warning,
+                category: RuleCategory::SEO,
+                passed_message: "Page has enough content",
+                failed_message: "Page has thin content",
+                remediation: Some(
+                    Remediation::new(
+                        "Pages with little content of their own rarely answer a search well, and many of them drag down how search engines rate the whole site.",
+                        "Expand the page with content that is useful to its visitors, merge it with a related page, or keep it out of the index with noindex.",
+                        Effort::High,
+                    )
+                    .with_reference("https://developers.google.com/search/docs/fundamentals/creating-helpful-content")
+                ),
+                check: |page, params| {
+                    let min_words = params.number("min_words")?;
+                    let metrics = metrics(page);
+                    let passed = f64::from(metrics.word_count) >= min_words;
+                    Ok(CheckResult {
+                        rule_id: "content_quality.thin_content".to_string(),
+                        passed,
+                        message: format!(
+                            "Main content has {} words, the minimum is {}",
+                            metrics.word_count, min_words
+                        ),
+                        findings: vec![],
+                    })
+                },
+                params: vec![RuleParam::number(
+                    "min_words",
+                    "Min words",
+                    "Fewest words of main content a page may have",
+                    300.0,
+                    0.0,
+                    5000.0,
+                )],
+            },
+            Rule {
+                id: "content_quality.readability",
+                name: "Content is easy to read",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks the Flesch reading ease of the main content, with the variant calibrated for the page's language",
+                default_severity: Severity::Info,
+                category: RuleCategory::SEO,
+                passed_message: "Content is easy enough to read",
+                failed_message: "Content is hard to read",
+                remediation: Some(
+                    Remediation::new(
+                        "Long sentences and long words make visitors skim or leave, especially on mobile.",
+                        "Split long sentences, prefer short everyday words over jargon, and break the text up with headings and lists.",
+                        Effort::Medium,
+                    )
+                    .with_reference("https://en.wikipedia.org/wiki/Flesch%E2%80%93Kincaid_readability_tests")
+                ),
+                check: |page, params| {
+                    let min_reading_ease = params.number("min_reading_ease")?;
+                    let metrics = metrics(page);
+                    if metrics.word_count < MIN_READABILITY_WORDS {
+                        return Err(CheckError::NotApplicable(format!(
+                            "Fewer than {} words of main content",
+                            MIN_READABILITY_WORDS
+                        )));
+                    }
+                    let readability = metrics.readability.ok_or(CheckError::NotApplicable(
+                        "No readability formula for the page's language".to_string(),
+                    ))?;
+                    let passed = f64::from(readability.reading_ease) >= min_reading_ease;
+                    Ok(CheckResult {
+                        rule_id: "content_quality.readability".to_string(),
+                        passed,
+                        message: format!(
+                            "Reading ease is {:.0} ({}), the minimum is {}. Grade level {:.1}",
+                            readability.reading_ease,
+                            readability.formula,
+                            min_reading_ease,
+                            readability.grade_level
+                        ),
+                        findings: vec![],
+                    })
+                },
+                params: vec![RuleParam::number(
+                    "min_reading_ease",
+                    "Min reading ease",
+                    "Lowest reading ease allowed, from 0 (very hard) to 100 (very easy). 30 and below reads like an academic paper.",
+                    30.0,
+                    0.0,
+                    100.0,
+                )],
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{all_rules_config, status};
+    use crate::utils::config::{RuleResult, RuleStatus};
+
+    const EASY: &str = "We sell shoes. They are good for the trail. You can run far in them. \
+        They do not weigh much. Our team tests each pair. We run in rain and mud. We run on rock. \
+        The soles grip well. Your feet stay dry. Each pair comes in five colors. You can send them \
+        back for free. We ship in two days. Most shoes last a long time. Ask us if you need help. \
+        We are here each day. Come and see our shop. It is next to the park. We hope to see you soon. \
+        Our shoes are made to last for many years.";
+
+    fn results(page: &Page) -> Vec<RuleResult> {
+        let plugin = ContentQualityPlugin::new();
+        plugin.analyze(page, &all_rules_config(&plugin))
+    }
+
+    #[test]
+    fn test_thin_content() {
+        let page = Page::from_html(format!(
+            "<html><body><nav>Home Shoes About</nav><main><p>{EASY}</p></main></body></html>"
+        ));
+        let metrics = metrics(&page);
+        assert_eq!(metrics.word_count, 104);
+        assert_eq!(metrics.paragraph_count, 1);
+        assert_eq!(
+            status(&results(&page), "content_quality.thin_content"),
+            RuleStatus::Failed
+        );
+
+        let long = Page::from_html(format!(
+            "<html><body><main><p>{EASY}</p><p>{EASY}</p><p>{EASY}</p></main></body></html>"
+        ));
+        assert_eq!(
+            status(&results(&long), "content_quality.thin_content"),
+            RuleStatus::Passed
+        );
+    }
+
+    #[test]
+    fn test_readability() {
+        let easy = Page::from_html(format!("<html><body><p>{EASY}</p></body></html>"));
+        assert_eq!(
+            status(&results(&easy), "content_quality.readability"),
+            RuleStatus::Passed
+        );
+
+        let hard = Page::from_html(format!(
+            "<html><body><p>{}</p></body></html>",
+            "Notwithstanding considerable methodological heterogeneity, comprehensive \
+             investigations consistently demonstrate that interdisciplinary collaboration \
+             substantially facilitates organizational innovation. "
+                .repeat(10)
+        ));
+        assert_eq!(
+            status(&results(&hard), "content_quality.readability"),
+            RuleStatus::Failed
+        );
+
+        let japanese = Page::from_html(format!(
+            r#"<html lang="ja"><body><p>{EASY}</p></body></html>"#
+        ));
+        assert_eq!(
+            status(&results(&japanese), "content_quality.readability"),
+            RuleStatus::NotApplicable
+        );
+    }
+}
@@ -0,0 +1,537 @@
+use std::any::Any;
+use std::collections::HashSet;
+use std::path::Path;
+
+use regex::Regex;
+use scraper::{ElementRef, Selector};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::utils::{
+    config::{
+        CheckError, CheckResult, Finding, Remediation, Rule, RuleCategory, RuleConfig, RuleDisplay,
+        RuleResult, RuleType, Severity, page_rule_result,
+    },
+    page::Page,
+    page_plugin::SeoPlugin,
+};
+
+const PLUGIN_NAME: &str = "Custom Rules";
+// Keeps custom rule ids apart from built-in and WASM plugin rule ids
+const RULE_ID_PREFIX: &str = "custom.";
+
+#[derive(Debug, Error)]
+pub enum CustomRuleError {
+    #[error("Failed to read {path}: {error}")]
+    ReadError { path: String, error: std::io::Error },
+    #[error("Invalid TOML rules: {0}")]
+    TomlError(#[from] toml::de::Error),
+    #[error("Invalid JSON rules: {0}")]
+    JsonError(#[from] serde_json::Error),
+    #[error("Rule {rule_id}: invalid selector {selector}: {message}")]
+    InvalidSelector {
+        rule_id: String,
+        selector: String,
+        message: String,
+    },
+    #[error("Rule {rule_id}: invalid pattern {pattern}: {error}")]
+    InvalidPattern {
+        rule_id: String,
+        pattern: String,
+        error: regex::Error,
+    },
+    #[error("Duplicate rule id: {0}")]
+    DuplicateRule(String),
+    #[error("Rule id {0} must start with \"{prefix}\"", prefix = RULE_ID_PREFIX)]
+    InvalidRuleId(String),
+}
+
+// What must hold for the elements matching the rule's selector
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CustomAssertion {
+    // At least one element matches
+    Exists,
+    // The number of matching elements is within the range, e.g. `max = 0` for "none"
+    Count {
+        #[serde(default)]
+        min: Option<usize>,
+        #[serde(default)]
+        max: Option<usize>,
+    },
+    // Every matching element has the attribute and its value matches the pattern
+    AttributeMatches {
+        attribute: String,
+        pattern: String,
+    },
+    // The text of at least one matching element matches the pattern
+    TextMatches {
+        pattern: String,
+    },
+}
+
+// A house rule written in TOML or JSON rather than Rust
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CustomRuleDefinition {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    // Regex the page URL must match, every page when missing
+    #[serde(default)]
+    pub url_pattern: Option<String>,
+    pub selector: String,
+    pub assert: CustomAssertion,
+    #[serde(default = "default_severity")]
+    pub severity: Severity,
+    #[serde(default = "default_category")]
+    pub category: RuleCategory,
+    pub passed_message: String,
+    pub failed_message: String,
+    #[serde(default)]
+    pub remediation: Option<Remediation>,
+}
+
+fn default_severity() -> Severity {
+    Severity::Warning
+}
+
+fn default_category() -> RuleCategory {
+    RuleCategory::BestPractices
+}
+
+#[derive(Debug, Deserialize)]
+struct CustomRuleFile {
+    #[serde(default)]
+    rules: Vec<CustomRuleDefinition>,
+}
+
+#[derive(Clone)]
+enum CompiledAssertion {
+    Exists,
+    Count {
+        min: Option<usize>,
+        max: Option<usize>,
+    },
+    AttributeMatches {
+        attribute: String,
+        pattern: Regex,
+    },
+    TextMatches {
+        pattern: Regex,
+    },
+}
+
+#[derive(Clone)]
+struct CustomRule {
+    definition: CustomRuleDefinition,
+    url_pattern: Option<Regex>,
+    selector: Selector,
+    assertion: CompiledAssertion,
+}
+
+impl CustomRule {
+    fn compile(definition: CustomRuleDefinition) -> Result<Self, CustomRuleError> {
+        let compile_pattern = |pattern: &str| {
+            Regex::new(pattern).map_err(|error| CustomRuleError::InvalidPattern {
+                rule_id: definition.id.clone(),
+                pattern: pattern.to_string(),
+                error,
+            })
+        };
+        let url_pattern = definition
+            .url_pattern
+            .as_deref()
+            .map(compile_pattern)
+            .transpose()?;
+        let assertion = match &definition.assert {
+            CustomAssertion::Exists => CompiledAssertion::Exists,
+            CustomAssertion::Count { min, max } => CompiledAssertion::Count {
+                min: *min,
+                max: *max,
+            },
+            CustomAssertion::AttributeMatches { attribute, pattern } => {
+                CompiledAssertion::AttributeMatches {
+                    attribute: attribute.clone(),
+                    pattern: compile_pattern(pattern)?,
+                }
+            }
+            CustomAssertion::TextMatches { pattern } => CompiledAssertion::TextMatches {
+                pattern: compile_pattern(pattern)?,
+            },
+        };
+        let selector = Selector::parse(&definition.selector).map_err(|e| {
+            CustomRuleError::InvalidSelector {
+                rule_id: definition.id.clone(),
+                selector: definition.selector.clone(),
+                message: e.to_string(),
+            }
+        })?;
+
+        Ok(Self {
+            definition,
+            url_pattern,
+            selector,
+            assertion,
+        })
+    }
+
+    fn check(&self, page: &Page) -> Result<CheckResult, CheckError> {
+        if let Some(url_pattern) = &self.url_pattern
+            && !url_pattern.is_match(page.get_url().as_str())
+        {
+            return Err(CheckError::NotApplicable(
+                "Page is outside the rule's URL pattern".to_string(),
+            ));
+        }
+
+        let document = page.get_document()?;
+        let elements: Vec<ElementRef> = document.select(&self.selector).collect();
+        let count = elements.len();
+
+        let (passed, findings): (bool, Vec<Finding>) = match &self.assertion {
+            CompiledAssertion::Exists => (count > 0, vec![]),
+            CompiledAssertion::Count { min, max } => {
+                let passed = count >= min.unwrap_or(0) && max.is_none_or(|max| count <= max);
+                let findings = if passed {
+                    vec![]
+                } else {
+                    elements
+                        .iter()
+                        .map(|element| page.finding(element))
+                        .collect()
+                };
+                (passed, findings)
+            }
+            CompiledAssertion::AttributeMatches { attribute, pattern } => {
+                if elements.is_empty() {
+                    return Err(CheckError::NotApplicable(format!(
+                        "No elements match {}",
+                        self.definition.selector
+                    )));
+                }
+                let findings: Vec<Finding> = elements
+                    .iter()
+                    .filter_map(|element| match element.value().attr(attribute) {
+                        Some(value) if pattern.is_match(value) => None,
+                        Some(value) => Some(page.finding(element).with_value(value)),
+                        None => Some(page.finding(element)),
+                    })
+                    .collect();
+                (findings.is_empty(), findings)
+            }
+            CompiledAssertion::TextMatches { pattern } => {
+                let passed = elements
+                    .iter()
+                    .any(|element| pattern.is_match(&element.text().collect::<String>()));
+                let findings = if passed {
+                    vec![]
+                } else {
+                    elements
+                        .iter()
+                        .map(|element| page.finding(element))
+                        .collect()
+                };
+                (passed, findings)
+            }
+        };
+
+        Ok(CheckResult {
+            rule_id: self.definition.id.clone(),
+            passed,
+            message: format!(
+                "{} ({} matching {})",
+                if passed {
+                    &self.definition.passed_message
+                } else {
+                    &self.definition.failed_message
+                },
+                count,
+                if count == 1 { "element" } else { "elements" }
+            ),
+            findings,
+        })
+    }
+
+    fn to_display(&self) -> RuleDisplay {
+        let definition = &self.definition;
+        RuleDisplay {
+            id: definition.id.clone(),
+            name: definition.name.clone(),
+            plugin_name: PLUGIN_NAME.to_string(),
+            description: definition.description.clone(),
+            severity: definition.severity.clone(),
+            category: definition.category.clone(),
+            rule_type: RuleType::Page,
+            passed_message: definition.passed_message.clone(),
+            failed_message: definition.failed_message.clone(),
+            params: vec![],
+            remediation: definition.remediation.clone(),
+        }
+    }
+}
+
+// Runs the rules defined in rule files. Their ids and checks are only known at runtime,
+// so they are reported through `rule_displays` rather than `available_rules`.
+#[derive(Clone, Default)]
+pub struct CustomRulePlugin {
+    rules: Vec<CustomRule>,
+}
+
+impl CustomRulePlugin {
+    pub fn new(definitions: Vec<CustomRuleDefinition>) -> Result<Self, CustomRuleError> {
+        let mut ids = HashSet::new();
+        let rules = definitions
+            .into_iter()
+            .map(|definition| {
+                if !definition.id.starts_with(RULE_ID_PREFIX) {
+                    return Err(CustomRuleError::InvalidRuleId(definition.id));
+                }
+                if !ids.insert(definition.id.clone()) {
+                    return Err(CustomRuleError::DuplicateRule(definition.id));
+                }
+                CustomRule::compile(definition)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { rules })
+    }
+
+    pub fn parse_toml(contents: &str) -> Result<Vec<CustomRuleDefinition>, CustomRuleError> {
+        let file: CustomRuleFile = toml::from_str(contents)?;
+        Ok(file.rules)
+    }
+
+    pub fn parse_json(contents: &str) -> Result<Vec<CustomRuleDefinition>, CustomRuleError> {
+        let file: CustomRuleFile = serde_json::from_str(contents)?;
+        Ok(file.rules)
+    }
+
+    // Loads every `.toml` and `.json` file in `dir`, a missing directory has no rules
+    pub fn load_dir(dir: &Path) -> Result<Self, CustomRuleError> {
+        if !dir.is_dir() {
+            return Ok(Self::default());
+        }
+        let read_error = |error| CustomRuleError::ReadError {
+            path: dir.to_string_lossy().to_string(),
+            error,
+        };
+        let mut paths: Vec<_> = std::fs::read_dir(dir)
+            .map_err(read_error)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .collect();
+        paths.sort();
+
+        let mut definitions = vec![];
+        for path in paths {
+            let extension = path.extension().and_then(|extension| extension.to_str());
+            if !matches!(extension, Some("toml") | Some("json")) {
+                continue;
+            }
+            let contents =
+                std::fs::read_to_string(&path).map_err(|error| CustomRuleError::ReadError {
+                    path: path.to_string_lossy().to_string(),
+                    error,
+                })?;
+            let rules = match extension {
+                Some("toml") => Self::parse_toml(&contents)?,
+                _ => Self::parse_json(&contents)?,
+            };
+            definitions.extend(rules);
+        }
+        Self::new(definitions)
+    }
+}
+
+#[async_trait::async_trait]
+impl SeoPlugin for CustomRulePlugin {
+    fn name(&self) -> &str {
+        PLUGIN_NAME
+    }
+
+    fn description(&self) -> &str {
+        "Rules defined in TOML or JSON rule files"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn available_rules(&self) -> Vec<Rule> {
+        vec![]
+    }
+
+    fn rule_displays(&self) -> Vec<RuleDisplay> {
+        self.rules.iter().map(|rule| rule.to_display()).collect()
+    }
+
+    async fn analyze_async(&self, page: &Page, config: &RuleConfig) -> Vec<RuleResult> {
+        self.analyze(page, config)
+    }
+
+    fn analyze(&self, page: &Page, config: &RuleConfig) -> Vec<RuleResult> {
+        self.rules
+            .iter()
+            .filter(|rule| config.is_rule_enabled(&rule.definition.id))
+            .map(|rule| {
+                let definition = &rule.definition;
+                page_rule_result(
+                    &definition.id,
+                    &definition.name,
+                    PLUGIN_NAME,
+                    config.get_severity(&definition.id, definition.severity.clone()),
+                    definition.category.clone(),
+                    rule.check(page),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{all_rules_config, result, status};
+    use crate::utils::config::{Effort, RuleStatus};
+
+    const RULES: &str = r#"
+        [[rules]]
+        id = "custom.product_price"
+        name = "Product pages show a price"
+        url_pattern = "/products/"
+        selector = "[itemprop=price]"
+        severity = "Error"
+        category = "SEO"
+        passed_message = "Price is shown"
+        failed_message = "Price is missing"
+        assert = { type = "exists" }
+
+        [[rules]]
+        id = "custom.blank_target_noopener"
+        name = "New tab links use rel=noopener"
+        selector = "a[target=_blank]"
+        passed_message = "New tab links use rel=noopener"
+        failed_message = "New tab links are missing rel=noopener"
+        assert = { type = "attribute_matches", attribute = "rel", pattern = "noopener" }
+        remediation = { why_it_matters = "The opened page can navigate this one", how_to_fix = "Add rel=noopener", effort = "Low" }
+
+        [[rules]]
+        id = "custom.footer_copyright"
+        name = "Footer has a copyright notice"
+        selector = "footer"
+        passed_message = "Footer has a copyright notice"
+        failed_message = "Footer is missing a copyright notice"
+        assert = { type = "text_matches", pattern = "©\\s*20\\d\\d" }
+
+        [[rules]]
+        id = "custom.single_h1"
+        name = "Page has one h1"
+        selector = "h1"
+        passed_message = "Page has one h1"
+        failed_message = "Page does not have exactly one h1"
+        assert = { type = "count", min = 1, max = 1 }
+    "#;
+
+    fn page(url: &str, html: &str) -> Page {
+        let mut page = Page::from_html(html.to_string());
+        page.set_url(url::Url::parse(url).unwrap());
+        page
+    }
+
+    fn results(plugin: &CustomRulePlugin, page: &Page) -> Vec<RuleResult> {
+        plugin.analyze(page, &all_rules_config(plugin))
+    }
+
+    #[test]
+    fn test_custom_rules_pass() {
+        let plugin = CustomRulePlugin::new(CustomRulePlugin::parse_toml(RULES).unwrap()).unwrap();
+        assert_eq!(plugin.rule_displays().len(), 4);
+        let remediation = plugin.rule_displays()[1].remediation.clone().unwrap();
+        assert_eq!(remediation.effort, Effort::Low);
+        assert!(remediation.references.is_empty());
+
+        let page = page(
+            "https://example.com/products/chair",
+            r#"<html><body>
+                <h1>Chair</h1>
+                <span itemprop="price">10</span>
+                <a href="https://example.org" target="_blank" rel="noopener noreferrer">Maker</a>
+                <footer>© 2025 Example</footer>
+            </body></html>"#,
+        );
+        let results = results(&plugin, &page);
+        assert!(results.iter().all(|result| result.passed), "{results:#?}");
+    }
+
+    #[test]
+    fn test_custom_rules_fail_with_findings() {
+        let plugin = CustomRulePlugin::new(CustomRulePlugin::parse_toml(RULES).unwrap()).unwrap();
+
+        let page = page(
+            "https://example.com/about",
+            r#"<html><body>
+                <h1>About</h1>
+                <h1>Team</h1>
+                <a href="https://example.org" target="_blank">Partner</a>
+                <footer>Example Inc.</footer>
+            </body></html>"#,
+        );
+        let results = results(&plugin, &page);
+        assert_eq!(
+            status(&results, "custom.product_price"),
+            RuleStatus::NotApplicable
+        );
+        assert_eq!(
+            status(&results, "custom.blank_target_noopener"),
+            RuleStatus::Failed
+        );
+        assert_eq!(
+            status(&results, "custom.footer_copyright"),
+            RuleStatus::Failed
+        );
+        assert_eq!(status(&results, "custom.single_h1"), RuleStatus::Failed);
+
+        assert_eq!(result(&results, "custom.single_h1").findings.len(), 2);
+    }
+
+    #[test]
+    fn test_invalid_rules_are_rejected() {
+        let definitions = CustomRulePlugin::parse_json(
+            r#"{ "rules": [{
+                "id": "custom.bad",
+                "name": "Bad",
+                "selector": "a[",
+                "passed_message": "",
+                "failed_message": "",
+                "assert": { "type": "exists" }
+            }] }"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            CustomRulePlugin::new(definitions.clone()),
+            Err(CustomRuleError::InvalidSelector { .. })
+        ));
+
+        let mut definition = definitions[0].clone();
+        definition.selector = "a".to_string();
+        definition.assert = CustomAssertion::TextMatches {
+            pattern: "(".to_string(),
+        };
+        assert!(matches!(
+            CustomRulePlugin::new(vec![definition.clone()]),
+            Err(CustomRuleError::InvalidPattern { .. })
+        ));
+
+        definition.assert = CustomAssertion::Exists;
+        assert!(matches!(
+            CustomRulePlugin::new(vec![definition.clone(), definition.clone()]),
+            Err(CustomRuleError::DuplicateRule(_))
+        ));
+
+        // Built-in rule ids can't be taken over
+        definition.id = "title.has_title".to_string();
+        assert!(matches!(
+            CustomRulePlugin::new(vec![definition]),
+            Err(CustomRuleError::InvalidRuleId(_))
+        ));
+    }
+}
@@ -0,0 +1,301 @@
+use std::any::Any;
+
+use crate::utils::{
+    config::{CheckError, CheckResult, Effort, Finding, Remediation, Rule, RuleCategory, Severity},
+    page::{Heading, Page},
+    page_plugin::SeoPlugin,
+    text::shares_topic,
+};
+
+// Checks the heading outline of a page
+pub struct HeadingsPlugin {}
+
+impl Default for HeadingsPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HeadingsPlugin {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+const PLUGIN_NAME: &str = "Headings";
+
+// Headings in document order, each with a finding pointing at its element
+fn headings(page: &Page) -> Result<Vec<(Heading, Finding)>, CheckError> {
+    Ok(page.map_headings(|heading, element| (heading, page.finding(element)))?)
+}
+
+fn h1s(page: &Page) -> Result<Vec<(Heading, Finding)>, CheckError> {
+    Ok(headings(page)?
+        .into_iter()
+        .filter(|(heading, _)| heading.level == 1)
+        .collect())
+}
+
+impl SeoPlugin for HeadingsPlugin {
+    fn name(&self) -> &str {
+        PLUGIN_NAME
+    }
+    fn description(&self) -> &str {
+        "Checks that the page has one clear h1 and a heading outline without gaps"
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn available_rules(&self) -> Vec<Rule> {
+        vec![
+            Rule {
+                id: "headings.single_h1",
+                name: "Page has one h1",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that the page has exactly one h1 heading",
+                default_severity: Severity::Error,
+                passed_message: "Page has one h1",
+                failed_message: "Page doesn't have exactly one h1",
+                category: RuleCategory::SEO,
+                remediation: Some(
+                    Remediation::new(
+                        "The h1 tells visitors and search engines what the page is about. Without one, or with several, the main topic is unclear.",
+                        "Use a single h1 for the page's main heading and h2-h6 for the sections below it.",
+                        Effort::Low,
+                    )
+                    .with_example("<h1>Trail Running Shoes</h1>")
+                    .with_reference("https://developer.mozilla.org/en-US/docs/Web/HTML/Element/Heading_Elements")
+                ),
+                check: |page, _params| {
+                    let h1s = h1s(page)?;
+                    let message = match h1s.len() {
+                        0 => "Page has no h1".to_string(),
+                        1 => "Page has one h1".to_string(),
+                        count => format!("Page has {count} h1 headings"),
+                    };
+                    Ok(CheckResult {
+                        rule_id: "headings.single_h1".to_string(),
+                        passed: h1s.len() == 1,
+                        message,
+                        findings: if h1s.len() > 1 {
+                            h1s.into_iter()
+                                .map(|(heading, finding)| finding.with_value(heading.text))
+                                .collect()
+                        } else {
+                            vec![]
+                        },
+                    })
+                },
+                params: vec![],
+            },
+            Rule {
+                id: "headings.h1_not_empty",
+                name: "h1 has text",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that the page's h1 headings have text",
+                default_severity: Severity::Error,
+                passed_message: "h1 has text",
+                failed_message: "h1 is empty",
+                category: RuleCategory::SEO,
+                remediation: Some(Remediation::new(
+                    "An empty h1, or one holding only an image without alt text, gives the page no readable main heading.",
+                    "Put the page's main heading as text in the h1.",
+                    Effort::Low,
+                )),
+                check: |page, _params| {
+                    let h1s = h1s(page)?;
+                    if h1s.is_empty() {
+                        return Err(CheckError::NotApplicable("Page has no h1".to_string()));
+                    }
+                    let empty: Vec<Finding> = h1s
+                        .into_iter()
+                        .filter(|(heading, _)| heading.text.is_empty())
+                        .map(|(_, finding)| finding)
+                        .collect();
+                    Ok(CheckResult {
+                        rule_id: "headings.h1_not_empty".to_string(),
+                        passed: empty.is_empty(),
+                        message: if empty.is_empty() {
+                            "h1 has text".to_string()
+                        } else {
+                            format!("{} empty h1 headings", empty.len())
+                        },
+                        findings: empty,
+                    })
+                },
+                params: vec![],
+            },
+            Rule {
+                id: "headings.no_skipped_levels",
+                name: "Heading levels aren't skipped",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that headings go down one level at a time, e.g. h2 to h3 rather than h2 to h4",
+                default_severity: Severity::Warning,
+                passed_message: "Heading levels aren't skipped",
+                failed_message: "Heading levels are skipped",
+                category: RuleCategory::Accessibility,
+                remediation: Some(
+                    Remediation::new(
+                        "Screen reader users navigate by headings. A skipped level suggests a missing section and breaks the outline of the page.",
+                        "Change the heading levels so each one is at most one level below the previous heading. Style headings with CSS rather than picking a level for its size.",
+                        Effort::Low,
+                    )
+                    .with_reference("https://www.w3.org/WAI/tutorials/page-structure/headings/")
+                ),
+                check: |page, _params| {
+                    let headings = headings(page)?;
+                    let skipped: Vec<Finding> = headings
+                        .windows(2)
+                        .filter_map(|pair| match pair {
+                            [(previous, _), (heading, finding)]
+                                if heading.level > previous.level + 1 =>
+                            {
+                                Some(
+                                    finding
+                                        .clone()
+                                        .with_value(format!("{} → {}", previous.tag, heading.tag)),
+                                )
+                            }
+                            _ => None,
+                        })
+                        .collect();
+                    Ok(CheckResult {
+                        rule_id: "headings.no_skipped_levels".to_string(),
+                        passed: skipped.is_empty(),
+                        message: if skipped.is_empty() {
+                            "Heading levels aren't skipped".to_string()
+                        } else {
+                            format!("{} headings skip a level", skipped.len())
+                        },
+                        findings: skipped,
+                    })
+                },
+                params: vec![],
+            },
+            Rule {
+                id: "headings.h1_matches_title",
+                name: "h1 matches the title",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that the h1 and the title tag share a topic word",
+                default_severity: Severity::Warning,
+                passed_message: "h1 matches the title",
+                failed_message: "h1 and title are about different things",
+                category: RuleCategory::SEO,
+                remediation: Some(Remediation::new(
+                    "Searchers click a result because of its title and expect the page's heading to confirm they landed in the right place.",
+                    "Make the h1 and the title describe the same topic. They don't have to be identical.",
+                    Effort::Low,
+                )),
+                check: |page, _params| {
+                    let title = page
+                        .extract_meta_tags()
+                        .title
+                        .filter(|title| !title.trim().is_empty())
+                        .ok_or_else(|| CheckError::NotApplicable("Page has no title".to_string()))?;
+                    let h1s: Vec<(Heading, Finding)> = h1s(page)?
+                        .into_iter()
+                        .filter(|(heading, _)| !heading.text.is_empty())
+                        .collect();
+                    if h1s.is_empty() {
+                        return Err(CheckError::NotApplicable("Page has no h1 with text".to_string()));
+                    }
+                    let passed = h1s
+                        .iter()
+                        .any(|(heading, _)| shares_topic(&heading.text, &title));
+                    Ok(CheckResult {
+                        rule_id: "headings.h1_matches_title".to_string(),
+                        passed,
+                        message: if passed {
+                            "h1 matches the title".to_string()
+                        } else {
+                            format!("h1 shares no words with the title \"{}\"", title.trim())
+                        },
+                        findings: if passed {
+                            vec![]
+                        } else {
+                            h1s.into_iter()
+                                .map(|(heading, finding)| finding.with_value(heading.text))
+                                .collect()
+                        },
+                    })
+                },
+                params: vec![],
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{all_rules_config, result};
+    use crate::utils::config::{RuleResult, RuleStatus};
+
+    fn results(html: &str) -> Vec<RuleResult> {
+        let plugin = HeadingsPlugin::new();
+        plugin.analyze(
+            &Page::from_html(html.to_string()),
+            &all_rules_config(&plugin),
+        )
+    }
+
+    #[test]
+    fn test_good_outline_passes() {
+        let results = results(
+            "<html><head><title>Trail Running Shoes | Example</title></head><body>
+                <h1>Trail running shoes</h1>
+                <h2>Men</h2><h3>Sale</h3>
+                <h2>Women</h2>
+            </body></html>",
+        );
+        for result in &results {
+            assert_eq!(result.status, RuleStatus::Passed, "{}", result.rule_id);
+        }
+    }
+
+    #[test]
+    fn test_bad_outline_fails() {
+        let results = results(
+            "<html><head><title>Trail Running Shoes</title></head><body>
+                <h1>Welcome</h1>
+                <h2>Men</h2><h4>Sale</h4>
+                <h1> </h1>
+            </body></html>",
+        );
+        let single_h1 = result(&results, "headings.single_h1");
+        assert_eq!(single_h1.status, RuleStatus::Failed);
+        assert_eq!(single_h1.message, "Page has 2 h1 headings");
+        assert_eq!(single_h1.findings.len(), 2);
+
+        let not_empty = result(&results, "headings.h1_not_empty");
+        assert_eq!(not_empty.status, RuleStatus::Failed);
+        assert_eq!(not_empty.findings.len(), 1);
+
+        let skipped = result(&results, "headings.no_skipped_levels");
+        assert_eq!(skipped.status, RuleStatus::Failed);
+        assert_eq!(skipped.findings.len(), 1);
+        assert_eq!(skipped.findings[0].value.as_deref(), Some("h2 → h4"));
+
+        let matches_title = result(&results, "headings.h1_matches_title");
+        assert_eq!(matches_title.status, RuleStatus::Failed);
+        assert_eq!(matches_title.findings[0].value.as_deref(), Some("Welcome"));
+    }
+
+    #[test]
+    fn test_missing_h1() {
+        let results = results("<html><body><h2>Section</h2></body></html>");
+        assert_eq!(
+            result(&results, "headings.single_h1").status,
+            RuleStatus::Failed
+        );
+        assert_eq!(
+            result(&results, "headings.h1_not_empty").status,
+            RuleStatus::NotApplicable
+        );
+        assert_eq!(
+            result(&results, "headings.h1_matches_title").status,
+            RuleStatus::NotApplicable
+        );
+    }
+}
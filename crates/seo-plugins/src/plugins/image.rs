@@ -1,9 +1,10 @@
 use std::any::{Any, TypeId};
 
+use scraper::Selector;
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
-use crate::utils::config::{CheckResult, Rule, RuleCategory, Severity};
+use crate::utils::config::{CheckResult, Effort, Remediation, Rule, RuleCategory, Severity};
 use crate::utils::page_plugin::SeoPlugin;
 
 #[derive(Debug, Serialize, Deserialize, Type, Clone)]
@@ -57,15 +58,24 @@ impl SeoPlugin for ImagePlugin {
                 failed_message: "{} images missing srcset",
                 default_severity: Severity::Warning,
                 category: RuleCategory::SEO,
-                check: |page| {
-                    let page = page.clone();
-                    let images = page.extract_images();
-                    let images_without_srcset = images
-                        .iter()
-                        .filter(|img| img.srcset.is_none())
+                remediation: Some(
+                    Remediation::new(
+                        "Without srcset every device downloads the same, often oversized, image, slowing the page on phones.",
+                        "Export each image in several widths and list them in srcset, with sizes describing how wide the image is displayed.",
+                        Effort::Medium,
+                    )
+                    .with_example(r#"<img src="hero-800.jpg" srcset="hero-400.jpg 400w, hero-800.jpg 800w, hero-1600.jpg 1600w" sizes="(max-width: 600px) 100vw, 800px" alt="Runner on a mountain trail">"#)
+                    .with_reference("https://developer.mozilla.org/en-US/docs/Web/HTML/Responsive_images")
+                ),
+                check: |page, _params| {
+                    let selector = Selector::parse("img")?;
+                    let document = page.get_document()?;
+                    let images_without_srcset = document
+                        .select(&selector)
+                        .filter(|img| img.attr("srcset").is_none())
                         .collect::<Vec<_>>();
 
-                    CheckResult {
+                    Ok(CheckResult {
                         rule_id: "images.responsive".to_string(),
                         passed: images_without_srcset.is_empty(),
                         message: if images_without_srcset.is_empty() {
@@ -73,8 +83,16 @@ impl SeoPlugin for ImagePlugin {
                         } else {
                             format!("{} images missing srcset", images_without_srcset.len())
                         },
-                    }
+                        findings: images_without_srcset
+                            .iter()
+                            .map(|img| {
+                                page.finding(img)
+                                    .with_value(img.attr("src").unwrap_or_default())
+                            })
+                            .collect(),
+                    })
                 },
+                params: vec![],
             },
             // More rules...
         ]
@@ -0,0 +1,46 @@
+use std::any::Any;
+
+use crate::utils::{
+    config::Rule,
+    content::MainText,
+    page::{Page, PageError},
+    page_plugin::SeoPlugin,
+};
+
+// Publishes the main text of each page for the plugins that read it, it has no rules
+pub struct MainTextPlugin {}
+
+impl Default for MainTextPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MainTextPlugin {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl SeoPlugin for MainTextPlugin {
+    fn name(&self) -> &str {
+        "Main Text"
+    }
+
+    fn description(&self) -> &str {
+        "Extracts the main text of pages, without navigation and other boilerplate"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn derive_outputs(&self, page: &Page) -> Result<(), PageError> {
+        page.outputs().insert(MainText(page.extract_main_text()?));
+        Ok(())
+    }
+
+    fn available_rules(&self) -> Vec<Rule> {
+        vec![]
+    }
+}
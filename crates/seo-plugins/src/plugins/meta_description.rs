@@ -1,8 +1,9 @@
 use std::any::Any;
 
 use crate::utils::{
-    config::{CheckResult, Rule, RuleCategory, Severity},
+    config::{CheckResult, Effort, Remediation, Rule, RuleCategory, Severity},
     page_plugin::SeoPlugin,
+    rule_param::RuleParam,
 };
 
 // MetaDescription Plugin
@@ -44,12 +45,20 @@ impl SeoPlugin for MetaDescriptionPlugin {
                 category: RuleCategory::SEO,
                 passed_message: "Page has a meta description",
                 failed_message: "Page is missing a meta description",
-                check: |page| {
-                    let page = page.clone();
+                remediation: Some(
+                    Remediation::new(
+                        "Search engines often use the meta description as the snippet below the result title. Without one they pick text from the page.",
+                        "Add a meta description that summarizes the page in one or two sentences.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<meta name="description" content="Lightweight trail running shoes with grippy soles. Free returns within 30 days.">"#)
+                    .with_reference("https://developers.google.com/search/docs/appearance/snippet")
+                ),
+                check: |page, _params| {
                     let meta_tags = page.extract_meta_tags();
                     let has_description = meta_tags.description.is_some();
 
-                    CheckResult {
+                    Ok(CheckResult {
                         rule_id: "meta_description.has_meta_description".to_string(),
                         passed: has_description,
                         message: if has_description {
@@ -58,33 +67,53 @@ impl SeoPlugin for MetaDescriptionPlugin {
                             "Page is missing a meta description"
                         }
                         .to_string(),
-                    }
+                        findings: vec![],
+                    })
                 },
+                params: vec![],
             },
             Rule {
                 id: "meta_description.description_length",
-                name: "Meta description length is less than 155 characters",
+                name: "Meta description length",
                 plugin_name: PLUGIN_NAME,
-                description: "Checks if the meta description length is less than 155 characters",
+                description: "Checks if the meta description is short enough to show in search results without being truncated",
                 default_severity: Severity::Warning,
-                passed_message: "Meta description length is less than 155 characters",
-                failed_message: "Meta description length is greater than 155 characters",
+                passed_message: "Meta description length is within the limit",
+                failed_message: "Meta description is longer than the limit",
                 category: RuleCategory::SEO,
-                check: |page| {
-                    let page = page.clone();
+                remediation: Some(
+                    Remediation::new(
+                        "Search results truncate long descriptions, hiding the end of the summary.",
+                        "Shorten the description to fit the configured length, keeping the key information at the start.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<meta name="description" content="Lightweight trail running shoes with grippy soles. Free returns within 30 days.">"#)
+                    .with_reference("https://developers.google.com/search/docs/appearance/snippet")
+                ),
+                check: |page, params| {
+                    let max_length = params.number("max_length")?;
                     let meta_tags = page.extract_meta_tags();
                     let description = meta_tags.description.unwrap_or_default();
-                    let description_length = description.len();
-                    let passed = description_length < 155 && description_length > 0;
-                    CheckResult {
+                    let description_length = description.chars().count();
+                    let passed = description_length as f64 <= max_length && description_length > 0;
+                    Ok(CheckResult {
                         rule_id: "meta_description.description_length".to_string(),
                         passed,
                         message: format!(
-                            "Meta description length is {} characters",
-                            description_length
+                            "Meta description length is {} characters, the limit is {}",
+                            description_length, max_length
                         ),
-                    }
+                        findings: vec![],
+                    })
                 },
+                params: vec![RuleParam::number(
+                    "max_length",
+                    "Max characters",
+                    "Longest meta description allowed",
+                    155.0,
+                    1.0,
+                    500.0,
+                )],
             },
         ]
     }
@@ -1,6 +1,14 @@
 pub mod axe;
+pub mod content_quality;
+pub mod custom_rule;
+pub mod headings;
 pub mod meta_description;
 pub mod title;
 pub mod request;
+pub mod resources;
 pub mod image;
+pub mod main_text;
 pub mod seo_basic;
+pub mod social;
+pub mod structured_data;
+pub mod wasm;
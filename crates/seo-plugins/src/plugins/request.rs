@@ -1,7 +1,7 @@
 use std::any::Any;
 
 use crate::utils::{
-    config::{CheckResult, Rule, RuleCategory, Severity},
+    config::{CheckResult, Effort, Remediation, Rule, RuleCategory, Severity},
     page_plugin::SeoPlugin,
 };
 
@@ -43,11 +43,18 @@ impl SeoPlugin for RequestPlugin {
             failed_message: "Page has redirects",
             default_severity: Severity::Error,
             category: RuleCategory::Performance,
-            check: |page| {
-                let page = page.clone();
+            remediation: Some(
+                Remediation::new(
+                    "Every redirect adds a round trip before the page starts loading, and long chains may not be followed by crawlers.",
+                    "Link directly to the final URL and update internal links, sitemaps and canonical URLs that point at redirecting URLs.",
+                    Effort::Medium,
+                )
+                .with_reference("https://developers.google.com/search/docs/crawling-indexing/301-redirects")
+            ),
+            check: |page, _params| {
                 let redirected = page.get_redirected();
 
-                CheckResult {
+                Ok(CheckResult {
                     rule_id: "request.redirects".to_string(),
                     passed: !redirected,
                     message: if redirected {
@@ -55,8 +62,10 @@ impl SeoPlugin for RequestPlugin {
                     } else {
                         "Page does not have redirects".to_string()
                     },
-                }
+                    findings: vec![],
+                })
             },
+            params: vec![],
         }]
     }
 }
@@ -0,0 +1,263 @@
+use std::any::Any;
+
+use crate::utils::{
+    config::{
+        AsyncRule, CheckError, CheckResult, Effort, Remediation, Rule, RuleCategory, Severity,
+    },
+    fetcher::{FetchResponse, Fetcher},
+    page::Page,
+    page_plugin::SeoPlugin,
+};
+
+// Checks that resources the page points to can actually be fetched
+pub struct ResourcesPlugin {}
+
+impl Default for ResourcesPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResourcesPlugin {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+const PLUGIN_NAME: &str = "Linked Resources";
+
+// Fetches `href` relative to the page and reports whether `validate` accepts the response
+async fn check_reference(
+    page: &Page,
+    fetcher: &Fetcher,
+    rule_id: &str,
+    href: Option<String>,
+    missing_message: &str,
+    passed_message: &str,
+    validate: fn(&str, &FetchResponse) -> Result<(), String>,
+) -> Result<CheckResult, CheckError> {
+    let href = href.ok_or_else(|| CheckError::NotApplicable(missing_message.to_string()))?;
+    let outcome = match page.get_url().join(&href) {
+        Ok(url) => match fetcher.fetch(url.as_str()).await {
+            Ok(response) if !response.is_success() => {
+                Err(format!("{} returns {}", url, response.status))
+            }
+            Ok(response) => validate(url.as_str(), &response),
+            Err(error) => Err(error.to_string()),
+        },
+        Err(_) => Err(format!("Invalid URL {}", href)),
+    };
+    Ok(CheckResult {
+        rule_id: rule_id.to_string(),
+        passed: outcome.is_ok(),
+        message: match outcome {
+            Ok(()) => passed_message.to_string(),
+            Err(message) => message,
+        },
+        findings: vec![],
+    })
+}
+
+fn any_response(_url: &str, _response: &FetchResponse) -> Result<(), String> {
+    Ok(())
+}
+
+impl SeoPlugin for ResourcesPlugin {
+    fn name(&self) -> &str {
+        PLUGIN_NAME
+    }
+    fn description(&self) -> &str {
+        "Fetches the favicon, og:image and web manifest the page links to"
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn available_rules(&self) -> Vec<Rule> {
+        vec![]
+    }
+
+    fn async_rules(&self) -> Vec<AsyncRule> {
+        vec![
+            AsyncRule {
+                id: "resources.favicon_resolves",
+                name: "Favicon resolves",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that the favicon URL returns a successful response",
+                default_severity: Severity::Warning,
+                category: RuleCategory::BestPractices,
+                passed_message: "Favicon resolves",
+                failed_message: "Favicon does not resolve",
+                remediation: Some(
+                    Remediation::new(
+                        "Search results and browser tabs show the favicon. A broken one shows a generic icon and wastes a request.",
+                        "Fix the favicon URL or upload the missing file so it returns a 2xx response.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<link rel="icon" href="/favicon.ico" sizes="32x32">"#)
+                    .with_reference("https://developers.google.com/search/docs/appearance/favicon-in-search")
+                ),
+                check: |page, _params, fetcher| {
+                    Box::pin(check_reference(
+                        page,
+                        fetcher,
+                        "resources.favicon_resolves",
+                        page.extract_meta_tags().favicon,
+                        "Page declares no favicon",
+                        "Favicon resolves",
+                        any_response,
+                    ))
+                },
+                params: vec![],
+            },
+            AsyncRule {
+                id: "resources.og_image_reachable",
+                name: "og:image is reachable",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that the Open Graph image can be fetched",
+                default_severity: Severity::Warning,
+                category: RuleCategory::SEO,
+                passed_message: "og:image is reachable",
+                failed_message: "og:image is not reachable",
+                remediation: Some(
+                    Remediation::new(
+                        "Social networks show no preview image when sharing the page if og:image can't be fetched.",
+                        "Use an absolute URL to an image that is publicly reachable, ideally at least 1200x630 pixels.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<meta property="og:image" content="https://example.com/images/trail-shoes-1200x630.jpg">"#)
+                    .with_reference("https://ogp.me/")
+                ),
+                check: |page, _params, fetcher| {
+                    Box::pin(check_reference(
+                        page,
+                        fetcher,
+                        "resources.og_image_reachable",
+                        page.extract_meta_tags().og_tags.get("image").cloned(),
+                        "Page has no og:image",
+                        "og:image is reachable",
+                        any_response,
+                    ))
+                },
+                params: vec![],
+            },
+            AsyncRule {
+                id: "resources.manifest_valid_json",
+                name: "Web manifest is valid JSON",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that the web app manifest can be fetched and parsed",
+                default_severity: Severity::Warning,
+                category: RuleCategory::BestPractices,
+                passed_message: "Web manifest is valid JSON",
+                failed_message: "Web manifest is not valid JSON",
+                remediation: Some(
+                    Remediation::new(
+                        "Browsers ignore an unreadable web app manifest, so the site can't be installed and loses its app name and icons.",
+                        "Serve the manifest as a valid JSON object, e.g. check it with a JSON validator, and fix the link if it returns an error.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"{ "name": "Example Store", "short_name": "Example", "start_url": "/", "display": "standalone", "icons": [{ "src": "/icon-192.png", "sizes": "192x192", "type": "image/png" }] }"#)
+                    .with_reference("https://developer.mozilla.org/en-US/docs/Web/Manifest")
+                ),
+                check: |page, _params, fetcher| {
+                    Box::pin(check_reference(
+                        page,
+                        fetcher,
+                        "resources.manifest_valid_json",
+                        page.extract_meta_tags().webmanifest,
+                        "Page links no web manifest",
+                        "Web manifest is valid JSON",
+                        |url, response| match serde_json::from_str::<serde_json::Value>(
+                            &response.body,
+                        ) {
+                            Ok(manifest) if manifest.is_object() => Ok(()),
+                            Ok(_) => Err(format!("{} is not a JSON object", url)),
+                            Err(error) => Err(format!("{} is not valid JSON: {}", url, error)),
+                        },
+                    ))
+                },
+                params: vec![],
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{all_rules_config, not_found, start_test_server, status};
+    use crate::utils::config::{RuleResult, RuleStatus};
+    use crate::utils::registry::PluginRegistry;
+    use hyper::{Body, Response};
+    use std::net::SocketAddr;
+
+    async fn start_server() -> SocketAddr {
+        start_test_server(|path| match path {
+            "/favicon.ico" => Response::new(Body::from("ok")),
+            "/manifest.json" => Response::new(Body::from(r#"{"name": "Site"}"#)),
+            "/broken.json" => Response::new(Body::from("{ name: ")),
+            _ => not_found(),
+        })
+        .await
+    }
+
+    async fn results(addr: SocketAddr, head: &str) -> Vec<RuleResult> {
+        let mut registry = PluginRegistry::new();
+        registry.register(ResourcesPlugin::new()).await.unwrap();
+        registry.set_config(all_rules_config(&ResourcesPlugin::new()));
+
+        let mut page = Page::from_html(format!("<html><head>{head}</head><body></body></html>"));
+        page.set_url(format!("http://{}/page", addr).as_str());
+        registry.analyze_async(&page).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_linked_resources_resolve() {
+        let addr = start_server().await;
+        let results = results(
+            addr,
+            r#"<link rel="icon" href="/favicon.ico">
+            <link rel="manifest" href="manifest.json">"#,
+        )
+        .await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            status(&results, "resources.favicon_resolves"),
+            RuleStatus::Passed
+        );
+        assert_eq!(
+            status(&results, "resources.manifest_valid_json"),
+            RuleStatus::Passed
+        );
+        assert_eq!(
+            status(&results, "resources.og_image_reachable"),
+            RuleStatus::NotApplicable
+        );
+    }
+
+    #[tokio::test]
+    async fn test_broken_resources_fail() {
+        let addr = start_server().await;
+        let results = results(
+            addr,
+            r#"<link rel="icon" href="/missing.ico">
+            <meta property="og:image" content="/missing.png">
+            <link rel="manifest" href="/broken.json">"#,
+        )
+        .await;
+
+        assert_eq!(
+            status(&results, "resources.favicon_resolves"),
+            RuleStatus::Failed
+        );
+        assert_eq!(
+            status(&results, "resources.og_image_reachable"),
+            RuleStatus::Failed
+        );
+        assert_eq!(
+            status(&results, "resources.manifest_valid_json"),
+            RuleStatus::Failed
+        );
+    }
+}
@@ -1,9 +1,7 @@
-#![allow(clippy::unwrap_used)]
-
 use std::any::Any;
 
 use crate::utils::{
-    config::{CheckResult, Rule, RuleCategory, Severity},
+    config::{CheckError, CheckResult, Effort, Remediation, Rule, RuleCategory, Severity},
     page_plugin::SeoPlugin,
 };
 
@@ -46,11 +44,19 @@ impl SeoPlugin for SeoBasicPlugin {
                 category: RuleCategory::SEO,
                 passed_message: "Page has a canonical url",
                 failed_message: "Page is missing a canonical url",
-                check: |page| {
-                    let page = page.clone();
+                remediation: Some(
+                    Remediation::new(
+                        "A canonical URL tells search engines which URL to index when the same content is reachable under several URLs, e.g. with tracking parameters.",
+                        r#"Add a <link rel="canonical"> to the <head> pointing at the preferred absolute URL of the page."#,
+                        Effort::Low,
+                    )
+                    .with_example(r#"<link rel="canonical" href="https://example.com/shoes/trail/">"#)
+                    .with_reference("https://developers.google.com/search/docs/crawling-indexing/consolidate-duplicate-urls")
+                ),
+                check: |page, _params| {
                     let canonical_url = page.extract_meta_tags().canonical;
                     let has_canonical_url = canonical_url.is_some();
-                    CheckResult {
+                    Ok(CheckResult {
                         rule_id: "seo_basic.has_canonical_url".to_string(),
                         passed: has_canonical_url,
                         message: if has_canonical_url {
@@ -59,8 +65,10 @@ impl SeoPlugin for SeoBasicPlugin {
                             "Page is missing a canonical url"
                         }
                         .to_string(),
-                    }
+                        findings: vec![],
+                    })
                 },
+                params: vec![],
             },
             Rule {
                 id: "seo_basic.canonical_url_matches_site",
@@ -71,12 +79,21 @@ impl SeoPlugin for SeoBasicPlugin {
                 category: RuleCategory::SEO,
                 passed_message: "Canonical url matches site",
                 failed_message: "Canonical url does not match site",
-                check: |page| {
-                    let page = page.clone();
+                remediation: Some(
+                    Remediation::new(
+                        "A canonical URL pointing to another site or page asks search engines to index that URL instead of this page.",
+                        "Point the canonical URL at this page's own absolute URL, unless the page deliberately duplicates another one.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<link rel="canonical" href="https://example.com/shoes/trail/">"#)
+                    .with_reference("https://developers.google.com/search/docs/crawling-indexing/consolidate-duplicate-urls")
+                ),
+                check: |page, _params| {
                     let canonical_url = page.extract_meta_tags().canonical;
-                    let canonical_url_matches_site = canonical_url.is_some()
-                        && canonical_url.unwrap().starts_with(page.get_url().as_str());
-                    CheckResult {
+                    let canonical_url_matches_site = canonical_url.is_some_and(|canonical_url| {
+                        canonical_url.starts_with(page.get_url().as_str())
+                    });
+                    Ok(CheckResult {
                         rule_id: "seo_basic.canonical_url_matches_site".to_string(),
                         passed: canonical_url_matches_site,
                         message: if canonical_url_matches_site {
@@ -85,8 +102,10 @@ impl SeoPlugin for SeoBasicPlugin {
                             "Canonical url does not match site"
                         }
                         .to_string(),
-                    }
+                        findings: vec![],
+                    })
                 },
+                params: vec![],
             },
             Rule {
                 id: "seo_basic.is_scrapeable",
@@ -97,12 +116,21 @@ impl SeoPlugin for SeoBasicPlugin {
                 category: RuleCategory::SEO,
                 passed_message: "Page is scrapeable",
                 failed_message: "Page is not scrapeable",
-                check: |page| {
-                    let page = page.clone();
+                remediation: Some(
+                    Remediation::new(
+                        "A noindex robots directive keeps the page out of search results entirely.",
+                        "Remove noindex from the robots meta tag if the page should be found in search. Keep it only on pages such as internal search results or thank-you pages.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<meta name="robots" content="index, follow">"#)
+                    .with_reference("https://developers.google.com/search/docs/crawling-indexing/robots-meta-tag")
+                ),
+                check: |page, _params| {
                     let meta_tags = page.extract_meta_tags();
-                    let is_scrapeable =
-                        meta_tags.robots.is_some() && meta_tags.robots.unwrap().contains("noindex");
-                    CheckResult {
+                    let is_scrapeable = meta_tags
+                        .robots
+                        .is_some_and(|robots| robots.contains("noindex"));
+                    Ok(CheckResult {
                         rule_id: "seo_basic.is_scrapeable".to_string(),
                         passed: !is_scrapeable,
                         message: if is_scrapeable {
@@ -111,8 +139,10 @@ impl SeoPlugin for SeoBasicPlugin {
                             "Page is scrapeable"
                         }
                         .to_string(),
-                    }
+                        findings: vec![],
+                    })
                 },
+                params: vec![],
             },
             Rule {
                 id: "seo_basic.has_valid_charset",
@@ -123,11 +153,19 @@ impl SeoPlugin for SeoBasicPlugin {
                 passed_message: "Page has a valid charset",
                 failed_message: "Page is missing a valid charset",
                 category: RuleCategory::SEO,
-                check: |page| {
-                    let page = page.clone();
+                remediation: Some(
+                    Remediation::new(
+                        "Without a declared character encoding browsers may guess wrong and show garbled text.",
+                        "Declare UTF-8 as the first element in the <head>.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<meta charset="utf-8">"#)
+                    .with_reference("https://developer.mozilla.org/en-US/docs/Web/HTML/Element/meta#charset")
+                ),
+                check: |page, _params| {
                     let meta_tags = page.extract_meta_tags();
                     let has_valid_charset = meta_tags.charset.is_some();
-                    CheckResult {
+                    Ok(CheckResult {
                         rule_id: "seo_basic.has_valid_charset".to_string(),
                         passed: has_valid_charset,
                         message: if has_valid_charset {
@@ -136,8 +174,10 @@ impl SeoPlugin for SeoBasicPlugin {
                             "Page is missing a valid charset"
                         }
                         .to_string(),
-                    }
+                        findings: vec![],
+                    })
                 },
+                params: vec![],
             },
             Rule {
                 id: "seo_basic.has_html_doctype",
@@ -148,11 +188,21 @@ impl SeoPlugin for SeoBasicPlugin {
                 category: RuleCategory::SEO,
                 passed_message: "Page has an html doctype",
                 failed_message: "Page is missing an html doctype",
-                check: |page| {
-                    let page = page.clone();
-                    let html = page.get_html().unwrap();
+                remediation: Some(
+                    Remediation::new(
+                        "Without a doctype browsers render the page in quirks mode, which changes layout rules.",
+                        "Start the document with the HTML5 doctype.",
+                        Effort::Low,
+                    )
+                    .with_example("<!DOCTYPE html>")
+                    .with_reference("https://developer.mozilla.org/en-US/docs/Glossary/Doctype")
+                ),
+                check: |page, _params| {
+                    let html = page.get_html().ok_or(CheckError::NotApplicable(
+                        "Page has no HTML document".to_string(),
+                    ))?;
                     let has_doctype = html.contains("<!DOCTYPE html>");
-                    CheckResult {
+                    Ok(CheckResult {
                         rule_id: "seo_basic.has_html_doctype".to_string(),
                         passed: has_doctype,
                         message: if has_doctype {
@@ -161,8 +211,10 @@ impl SeoPlugin for SeoBasicPlugin {
                             "Page is missing an html doctype"
                         }
                         .to_string(),
-                    }
+                        findings: vec![],
+                    })
                 },
+                params: vec![],
             },
         ]
     }
@@ -171,7 +223,11 @@ impl SeoPlugin for SeoBasicPlugin {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::utils::{config::RuleConfig, page::Page, page_plugin::SeoPlugin};
+    use crate::utils::{
+        config::{RuleConfig, RuleStatus},
+        page::Page,
+        page_plugin::SeoPlugin,
+    };
     use hyper::service::{make_service_fn, service_fn};
     use hyper::{Body, Response, Server};
 
@@ -227,6 +283,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_seo_basic_plugin_without_document() {
+        let plugin = SeoBasicPlugin::new();
+        let page = Page::default();
+        let mut config = RuleConfig::new();
+        config.enable_rule("seo_basic.has_html_doctype");
+
+        let results = plugin.analyze(&page, &config);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].status, RuleStatus::NotApplicable);
+        assert!(!results[0].passed);
+    }
+
     async fn start_test_server() -> SocketAddr {
         let addr = SocketAddr::from(([127, 0, 0, 1], 0));
         let listener = TcpListener::bind(addr).await.unwrap();
@@ -0,0 +1,377 @@
+use std::any::Any;
+
+use scraper::Selector;
+use url::Url;
+
+use crate::utils::{
+    config::{CheckError, CheckResult, Effort, Finding, Remediation, Rule, RuleCategory, Severity},
+    page::Page,
+    page_plugin::SeoPlugin,
+};
+
+// Checks the Open Graph and Twitter Card tags that make up a shared link's preview
+pub struct SocialPlugin {}
+
+impl Default for SocialPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SocialPlugin {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+const PLUGIN_NAME: &str = "Social Sharing";
+
+const REQUIRED_OG_TAGS: &[&str] = &["title", "type", "image", "url"];
+
+const TWITTER_CARD_TYPES: &[&str] = &["summary", "summary_large_image", "app", "player"];
+
+// The meta element declaring a property such as `og:url`, for a finding
+fn meta_finding(page: &Page, property: &str) -> Result<Option<Finding>, CheckError> {
+    let document = page.get_document()?;
+    let selector = Selector::parse(&format!(
+        r#"meta[property="{property}"], meta[name="{property}"]"#
+    ))?;
+    Ok(document
+        .select(&selector)
+        .next()
+        .map(|element| page.finding(&element)))
+}
+
+// Tags a card type needs, each listing alternatives, one of which must be set.
+// Title, description and image fall back to their og tags.
+fn twitter_card_requirements(card: &str) -> &'static [&'static [&'static str]] {
+    match card {
+        "summary" | "summary_large_image" => &[&["twitter:title", "og:title"]],
+        "app" => &[
+            &["twitter:site"],
+            &[
+                "twitter:app:id:iphone",
+                "twitter:app:id:ipad",
+                "twitter:app:id:googleplay",
+            ],
+        ],
+        "player" => &[
+            &["twitter:title", "og:title"],
+            &["twitter:site"],
+            &["twitter:player"],
+            &["twitter:player:width"],
+            &["twitter:player:height"],
+            &["twitter:image", "og:image"],
+        ],
+        _ => &[],
+    }
+}
+
+impl SeoPlugin for SocialPlugin {
+    fn name(&self) -> &str {
+        PLUGIN_NAME
+    }
+    fn description(&self) -> &str {
+        "Checks the Open Graph and Twitter Card tags social networks use to preview the page"
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn available_rules(&self) -> Vec<Rule> {
+        vec![
+            Rule {
+                id: "social.og_required",
+                name: "Open Graph tags are complete",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that the page sets og:title, og:type, og:image and og:url",
+                default_severity: Severity::Warning,
+                passed_message: "Page has every required Open Graph tag",
+                failed_message: "Page is missing required Open Graph tags",
+                category: RuleCategory::SEO,
+                remediation: Some(
+                    Remediation::new(
+                        "Without these tags, social networks and chat apps guess the preview of a shared link, often picking the wrong image or no image at all.",
+                        "Add the missing og tags to the head of the page.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<meta property="og:title" content="Trail Running Shoes">
+<meta property="og:type" content="website">
+<meta property="og:image" content="https://example.com/images/trail-shoes.jpg">
+<meta property="og:url" content="https://example.com/shoes/trail/">"#)
+                    .with_reference("https://ogp.me/#metadata")
+                ),
+                check: |page, _params| {
+                    let og_tags = page.extract_meta_tags().og_tags;
+                    let missing: Vec<String> = REQUIRED_OG_TAGS
+                        .iter()
+                        .filter(|tag| og_tags.get(**tag).is_none_or(|value| value.trim().is_empty()))
+                        .map(|tag| format!("og:{}", tag))
+                        .collect();
+                    Ok(CheckResult {
+                        rule_id: "social.og_required".to_string(),
+                        passed: missing.is_empty(),
+                        message: if missing.is_empty() {
+                            "Page has every required Open Graph tag".to_string()
+                        } else {
+                            format!("Page is missing {}", missing.join(", "))
+                        },
+                        findings: vec![],
+                    })
+                },
+                params: vec![],
+            },
+            Rule {
+                id: "social.og_url_matches_canonical",
+                name: "og:url matches the canonical URL",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that og:url and the canonical link point at the same URL",
+                default_severity: Severity::Warning,
+                passed_message: "og:url matches the canonical URL",
+                failed_message: "og:url doesn't match the canonical URL",
+                category: RuleCategory::SEO,
+                remediation: Some(
+                    Remediation::new(
+                        "Social networks count likes and shares per og:url. When it differs from the canonical URL, shares are split across URLs or attributed to a page search engines don't index.",
+                        "Set og:url to the same URL as the canonical link.",
+                        Effort::Low,
+                    )
+                    .with_reference("https://ogp.me/#metadata")
+                ),
+                check: |page, _params| {
+                    let meta_tags = page.extract_meta_tags();
+                    let (Some(og_url), Some(canonical)) =
+                        (meta_tags.og_tags.get("url"), meta_tags.canonical.as_ref())
+                    else {
+                        return Err(CheckError::NotApplicable(
+                            "Page doesn't set both og:url and a canonical URL".to_string(),
+                        ));
+                    };
+                    let resolve = |url: &str| page.get_url().join(url.trim()).ok();
+                    let passed = resolve(og_url).is_some() && resolve(og_url) == resolve(canonical);
+                    Ok(CheckResult {
+                        rule_id: "social.og_url_matches_canonical".to_string(),
+                        passed,
+                        message: if passed {
+                            "og:url matches the canonical URL".to_string()
+                        } else {
+                            format!("og:url {} doesn't match the canonical URL {}", og_url, canonical)
+                        },
+                        findings: if passed {
+                            vec![]
+                        } else {
+                            meta_finding(page, "og:url")?
+                                .map(|finding| finding.with_value(og_url))
+                                .into_iter()
+                                .collect()
+                        },
+                    })
+                },
+                params: vec![],
+            },
+            Rule {
+                id: "social.og_image_absolute",
+                name: "og:image is an absolute URL",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that og:image is an absolute http(s) URL",
+                default_severity: Severity::Warning,
+                passed_message: "og:image is an absolute URL",
+                failed_message: "og:image is not an absolute URL",
+                category: RuleCategory::SEO,
+                remediation: Some(
+                    Remediation::new(
+                        "Most social networks don't resolve a relative og:image against the page, so the preview shows no image.",
+                        "Use the full URL of the image, including https:// and the domain.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<meta property="og:image" content="https://example.com/images/trail-shoes.jpg">"#)
+                    .with_reference("https://ogp.me/#metadata")
+                ),
+                check: |page, _params| {
+                    let image = page
+                        .extract_meta_tags()
+                        .og_tags
+                        .get("image")
+                        .cloned()
+                        .ok_or_else(|| CheckError::NotApplicable("Page has no og:image".to_string()))?;
+                    let passed = Url::parse(image.trim())
+                        .is_ok_and(|url| matches!(url.scheme(), "http" | "https"));
+                    Ok(CheckResult {
+                        rule_id: "social.og_image_absolute".to_string(),
+                        passed,
+                        message: if passed {
+                            "og:image is an absolute URL".to_string()
+                        } else {
+                            format!("og:image {} is not an absolute URL", image)
+                        },
+                        findings: if passed {
+                            vec![]
+                        } else {
+                            meta_finding(page, "og:image")?
+                                .map(|finding| finding.with_value(image))
+                                .into_iter()
+                                .collect()
+                        },
+                    })
+                },
+                params: vec![],
+            },
+            Rule {
+                id: "social.twitter_card",
+                name: "Twitter Card is complete",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that twitter:card names a card type and the tags that type needs are set",
+                default_severity: Severity::Info,
+                passed_message: "Twitter Card is complete",
+                failed_message: "Twitter Card is missing or incomplete",
+                category: RuleCategory::SEO,
+                remediation: Some(
+                    Remediation::new(
+                        "X only shows a card for links to pages with a valid twitter:card tag, otherwise the link shows as plain text.",
+                        "Set twitter:card to summary, summary_large_image, app or player and add the tags the card type needs. Title, description and image fall back to the og tags.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<meta name="twitter:card" content="summary_large_image">"#)
+                    .with_reference("https://developer.x.com/en/docs/x-for-websites/cards/overview/markup")
+                ),
+                check: |page, _params| {
+                    let meta_tags = page.extract_meta_tags();
+                    let Some(card) = meta_tags.twitter_tags.get("card").map(|card| card.trim()) else {
+                        return Ok(CheckResult {
+                            rule_id: "social.twitter_card".to_string(),
+                            passed: false,
+                            message: "Page has no twitter:card".to_string(),
+                            findings: vec![],
+                        });
+                    };
+                    if !TWITTER_CARD_TYPES.contains(&card) {
+                        return Ok(CheckResult {
+                            rule_id: "social.twitter_card".to_string(),
+                            passed: false,
+                            message: format!("twitter:card {} is not a card type", card),
+                            findings: meta_finding(page, "twitter:card")?
+                                .map(|finding| finding.with_value(card))
+                                .into_iter()
+                                .collect(),
+                        });
+                    }
+                    let is_set = |tag: &str| {
+                        let value = match tag.split_once(':') {
+                            Some(("og", key)) => meta_tags.og_tags.get(key),
+                            Some((_, key)) => meta_tags.twitter_tags.get(key),
+                            None => None,
+                        };
+                        value.is_some_and(|value| !value.trim().is_empty())
+                    };
+                    let missing: Vec<&str> = twitter_card_requirements(card)
+                        .iter()
+                        .filter(|alternatives| !alternatives.iter().any(|tag| is_set(tag)))
+                        .map(|alternatives| alternatives[0])
+                        .collect();
+                    Ok(CheckResult {
+                        rule_id: "social.twitter_card".to_string(),
+                        passed: missing.is_empty(),
+                        message: if missing.is_empty() {
+                            "Twitter Card is complete".to_string()
+                        } else {
+                            format!("{} card is missing {}", card, missing.join(", "))
+                        },
+                        findings: vec![],
+                    })
+                },
+                params: vec![],
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::test_utils::{all_rules_config, result};
+    use crate::utils::config::{RuleResult, RuleStatus};
+
+    fn results(head: &str) -> Vec<RuleResult> {
+        let plugin = SocialPlugin::new();
+        let mut page = Page::from_html(format!("<html><head>{head}</head><body></body></html>"));
+        page.set_url("https://example.com/shoes/trail");
+        plugin.analyze(&page, &all_rules_config(&plugin))
+    }
+
+    #[test]
+    fn test_complete_tags_pass() {
+        let results = results(
+            r#"<link rel="canonical" href="/shoes/trail">
+            <meta property="og:title" content="Trail Shoes">
+            <meta property="og:type" content="website">
+            <meta property="og:image" content="https://example.com/trail.jpg">
+            <meta property="og:url" content="https://example.com/shoes/trail">
+            <meta name="twitter:card" content="summary_large_image">"#,
+        );
+        for result in &results {
+            assert_eq!(result.status, RuleStatus::Passed, "{}", result.rule_id);
+        }
+    }
+
+    #[test]
+    fn test_incomplete_tags_fail() {
+        let results = results(
+            r#"<link rel="canonical" href="https://example.com/shoes/trail">
+            <meta property="og:title" content="Trail Shoes">
+            <meta property="og:image" content="/trail.jpg">
+            <meta property="og:url" content="https://example.com/shoes/trail?utm_source=x">
+            <meta name="twitter:card" content="player">
+            <meta name="twitter:site" content="@example">"#,
+        );
+
+        let required = result(&results, "social.og_required");
+        assert_eq!(required.status, RuleStatus::Failed);
+        assert_eq!(required.message, "Page is missing og:type");
+
+        let canonical = result(&results, "social.og_url_matches_canonical");
+        assert_eq!(canonical.status, RuleStatus::Failed);
+        assert_eq!(canonical.findings.len(), 1);
+
+        let image = result(&results, "social.og_image_absolute");
+        assert_eq!(image.status, RuleStatus::Failed);
+        assert_eq!(image.findings[0].value.as_deref(), Some("/trail.jpg"));
+
+        let twitter = result(&results, "social.twitter_card");
+        assert_eq!(twitter.status, RuleStatus::Failed);
+        assert_eq!(
+            twitter.message,
+            "player card is missing twitter:player, twitter:player:width, twitter:player:height"
+        );
+    }
+
+    #[test]
+    fn test_missing_tags() {
+        let results = results("<title>Trail Shoes</title>");
+        assert_eq!(
+            result(&results, "social.og_url_matches_canonical").status,
+            RuleStatus::NotApplicable
+        );
+        assert_eq!(
+            result(&results, "social.og_image_absolute").status,
+            RuleStatus::NotApplicable
+        );
+        assert_eq!(
+            result(&results, "social.twitter_card").status,
+            RuleStatus::Failed
+        );
+    }
+
+    #[test]
+    fn test_card_requirements_list_each_tag_once() {
+        for card in TWITTER_CARD_TYPES {
+            let tags: Vec<&str> = twitter_card_requirements(card)
+                .iter()
+                .flat_map(|alternatives| alternatives.iter().copied())
+                .collect();
+            let unique: HashSet<&str> = tags.iter().copied().collect();
+            assert_eq!(unique.len(), tags.len(), "{card} lists a tag twice");
+        }
+    }
+}
@@ -0,0 +1,478 @@
+use std::any::Any;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use crate::utils::{
+    config::{CheckError, CheckResult, Effort, Finding, Remediation, Rule, RuleCategory, Severity},
+    page::Page,
+    page_plugin::SeoPlugin,
+    structured_data::Entity,
+};
+
+// Validates JSON-LD, Microdata and RDFa against schema.org and Google's rich results
+pub struct StructuredDataPlugin {}
+
+impl Default for StructuredDataPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StructuredDataPlugin {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+const PLUGIN_NAME: &str = "Structured Data";
+
+#[derive(Debug, Deserialize)]
+struct SchemaType {
+    parents: Vec<String>,
+    properties: Vec<String>,
+}
+
+// Google's requirements for a rich result type. Each required entry lists
+// alternatives, one of which must be present.
+#[derive(Debug, Deserialize)]
+struct RichResult {
+    required: Vec<Vec<String>>,
+    recommended: Vec<String>,
+}
+
+// The schema.org types rich results use, and the types nested in them. Types
+// that aren't bundled aren't validated.
+#[derive(Debug, Deserialize)]
+struct Definitions {
+    types: HashMap<String, SchemaType>,
+    rich_results: HashMap<String, RichResult>,
+}
+
+fn definitions() -> Result<&'static Definitions, CheckError> {
+    static DEFINITIONS: OnceLock<Result<Definitions, String>> = OnceLock::new();
+    DEFINITIONS
+        .get_or_init(|| {
+            serde_json::from_str(include_str!("schema_org.json")).map_err(|e| e.to_string())
+        })
+        .as_ref()
+        .map_err(|e| CheckError::PluginError(format!("Invalid schema.org definitions: {}", e)))
+}
+
+impl Definitions {
+    // The type and its ancestors, closest first
+    fn lineage<'a>(&'a self, type_name: &'a str) -> Option<Vec<&'a str>> {
+        if !self.types.contains_key(type_name) {
+            return None;
+        }
+        let mut lineage = vec![];
+        let mut queue = VecDeque::from([type_name]);
+        while let Some(name) = queue.pop_front() {
+            if lineage.contains(&name) {
+                continue;
+            }
+            lineage.push(name);
+            if let Some(schema_type) = self.types.get(name) {
+                queue.extend(schema_type.parents.iter().map(String::as_str));
+            }
+        }
+        Some(lineage)
+    }
+
+    // Properties none of the entity's types define, or None when a type isn't bundled
+    fn unknown_properties<'a>(&self, entity: &'a Entity) -> Option<Vec<&'a str>> {
+        if entity.types.is_empty() {
+            return None;
+        }
+        let mut known = HashSet::new();
+        for type_name in &entity.types {
+            for name in self.lineage(type_name)? {
+                if let Some(schema_type) = self.types.get(name) {
+                    known.extend(schema_type.properties.iter().map(String::as_str));
+                }
+            }
+        }
+        Some(
+            entity
+                .properties
+                .keys()
+                .map(String::as_str)
+                .filter(|property| !known.contains(property))
+                .collect(),
+        )
+    }
+
+    // The rich result definitions that apply to the entity's types, by type
+    fn rich_results<'a>(&'a self, entity: &'a Entity) -> Vec<(&'a str, &'a RichResult)> {
+        let mut rich_results: Vec<(&str, &RichResult)> = vec![];
+        for type_name in &entity.types {
+            let rich_result = self.lineage(type_name).and_then(|lineage| {
+                lineage
+                    .into_iter()
+                    .find_map(|name| self.rich_results.get(name))
+            });
+            // A Restaurant that is also a LocalBusiness is checked once
+            let is_new = |rich_result: &&RichResult| {
+                !rich_results
+                    .iter()
+                    .any(|(_, seen)| std::ptr::eq(*seen, *rich_result))
+            };
+            if let Some(rich_result) = rich_result.filter(is_new) {
+                rich_results.push((type_name, rich_result));
+            }
+        }
+        rich_results
+    }
+}
+
+// `offers, review or aggregateRating`
+fn one_of(properties: &[String]) -> String {
+    match properties {
+        [] => String::new(),
+        [property] => property.clone(),
+        [rest @ .., last] => format!("{} or {}", rest.join(", "), last),
+    }
+}
+
+// One finding per missing required (or recommended) property of a rich result entity
+fn missing_properties(page: &Page, recommended: bool) -> Result<Vec<Finding>, CheckError> {
+    let definitions = definitions()?;
+    let data = page.extract_structured_data()?;
+    let mut checked = 0;
+    let mut findings = vec![];
+    for entity in data.entities.iter().flat_map(Entity::descendants) {
+        for (type_name, rich_result) in definitions.rich_results(entity) {
+            checked += 1;
+            let groups: Vec<Vec<String>> = if recommended {
+                rich_result
+                    .recommended
+                    .iter()
+                    .map(|property| vec![property.clone()])
+                    .collect()
+            } else {
+                rich_result.required.clone()
+            };
+            for group in groups {
+                if !group.iter().any(|property| entity.has(property)) {
+                    findings.push(entity.finding.clone().with_value(format!(
+                        "{} is missing {}",
+                        type_name,
+                        one_of(&group)
+                    )));
+                }
+            }
+        }
+    }
+    if checked == 0 {
+        return Err(CheckError::NotApplicable(
+            "Page has no structured data for rich results".to_string(),
+        ));
+    }
+    Ok(findings)
+}
+
+impl SeoPlugin for StructuredDataPlugin {
+    fn name(&self) -> &str {
+        PLUGIN_NAME
+    }
+    fn description(&self) -> &str {
+        "Validates JSON-LD, Microdata and RDFa against schema.org and Google's rich result requirements"
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn available_rules(&self) -> Vec<Rule> {
+        vec![
+            Rule {
+                id: "structured_data.valid_json_ld",
+                name: "JSON-LD is valid",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that every JSON-LD script on the page is valid JSON",
+                default_severity: Severity::Error,
+                passed_message: "JSON-LD is valid",
+                failed_message: "JSON-LD is not valid JSON",
+                category: RuleCategory::SEO,
+                remediation: Some(
+                    Remediation::new(
+                        "Search engines ignore a JSON-LD block they can't parse, so the page loses every rich result it describes.",
+                        "Fix the JSON syntax at the reported line, e.g. a missing comma, a trailing comma or an unescaped quote.",
+                        Effort::Low,
+                    )
+                    .with_reference("https://validator.schema.org/")
+                ),
+                check: |page, _params| {
+                    let data = page.extract_structured_data()?;
+                    if data.json_ld_blocks == 0 {
+                        return Err(CheckError::NotApplicable("Page has no JSON-LD".to_string()));
+                    }
+                    let message = match data.errors.as_slice() {
+                        [] => "JSON-LD is valid".to_string(),
+                        [error] => match error.finding.line {
+                            Some(line) => format!("{} (line {})", error.message, line),
+                            None => error.message.clone(),
+                        },
+                        errors => format!(
+                            "{} of {} JSON-LD blocks are not valid JSON",
+                            errors.len(),
+                            data.json_ld_blocks
+                        ),
+                    };
+                    Ok(CheckResult {
+                        rule_id: "structured_data.valid_json_ld".to_string(),
+                        passed: data.errors.is_empty(),
+                        message,
+                        findings: data.errors.into_iter().map(|error| error.finding).collect(),
+                    })
+                },
+                params: vec![],
+            },
+            Rule {
+                id: "structured_data.schema_org",
+                name: "Structured data uses schema.org properties",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that structured data items have a type and only use properties schema.org defines for it",
+                default_severity: Severity::Warning,
+                passed_message: "Structured data uses schema.org properties",
+                failed_message: "Structured data uses properties schema.org doesn't define",
+                category: RuleCategory::SEO,
+                remediation: Some(
+                    Remediation::new(
+                        "Search engines skip properties they don't know, which is usually a typo or a property set on the wrong type.",
+                        "Fix the property name or move the property to the type that defines it, e.g. price belongs on the Offer, not the Product.",
+                        Effort::Low,
+                    )
+                    .with_reference("https://schema.org/docs/full.html")
+                ),
+                check: |page, _params| {
+                    let definitions = definitions()?;
+                    let data = page.extract_structured_data()?;
+                    if data.entities.is_empty() {
+                        return Err(CheckError::NotApplicable(
+                            "Page has no structured data".to_string(),
+                        ));
+                    }
+                    let mut findings = vec![];
+                    for item in &data.entities {
+                        if item.types.is_empty() {
+                            findings.push(
+                                item.finding
+                                    .clone()
+                                    .with_value(format!("{} item has no type", item.format)),
+                            );
+                            continue;
+                        }
+                        for entity in item.descendants() {
+                            for property in definitions.unknown_properties(entity).unwrap_or_default() {
+                                findings.push(entity.finding.clone().with_value(format!(
+                                    "{} has no property {}",
+                                    entity.types.join(", "),
+                                    property
+                                )));
+                            }
+                        }
+                    }
+                    Ok(CheckResult {
+                        rule_id: "structured_data.schema_org".to_string(),
+                        passed: findings.is_empty(),
+                        message: if findings.is_empty() {
+                            "Structured data uses schema.org properties".to_string()
+                        } else {
+                            format!("{} structured data problems", findings.len())
+                        },
+                        findings,
+                    })
+                },
+                params: vec![],
+            },
+            Rule {
+                id: "structured_data.rich_results_required",
+                name: "Rich results have required properties",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks Article, Product, BreadcrumbList, FAQPage, Organization and LocalBusiness items for the properties Google requires",
+                default_severity: Severity::Error,
+                passed_message: "Rich results have every required property",
+                failed_message: "Rich results are missing required properties",
+                category: RuleCategory::SEO,
+                remediation: Some(
+                    Remediation::new(
+                        "Google doesn't show a rich result for an item missing a required property, e.g. a Product without offers, review or aggregateRating.",
+                        "Add the missing properties to the item, or to the nested item they belong to.",
+                        Effort::Medium,
+                    )
+                    .with_reference("https://developers.google.com/search/docs/appearance/structured-data/search-gallery")
+                ),
+                check: |page, _params| {
+                    let findings = missing_properties(page, false)?;
+                    Ok(CheckResult {
+                        rule_id: "structured_data.rich_results_required".to_string(),
+                        passed: findings.is_empty(),
+                        message: if findings.is_empty() {
+                            "Rich results have every required property".to_string()
+                        } else {
+                            format!("{} required properties are missing", findings.len())
+                        },
+                        findings,
+                    })
+                },
+                params: vec![],
+            },
+            Rule {
+                id: "structured_data.rich_results_recommended",
+                name: "Rich results have recommended properties",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks rich result items for the properties Google recommends",
+                default_severity: Severity::Info,
+                passed_message: "Rich results have every recommended property",
+                failed_message: "Rich results are missing recommended properties",
+                category: RuleCategory::SEO,
+                remediation: Some(
+                    Remediation::new(
+                        "Recommended properties such as an image, a brand or a publication date make the rich result more complete and more likely to show.",
+                        "Add the recommended properties that apply to the item.",
+                        Effort::Low,
+                    )
+                    .with_reference("https://developers.google.com/search/docs/appearance/structured-data/search-gallery")
+                ),
+                check: |page, _params| {
+                    let findings = missing_properties(page, true)?;
+                    Ok(CheckResult {
+                        rule_id: "structured_data.rich_results_recommended".to_string(),
+                        passed: findings.is_empty(),
+                        message: if findings.is_empty() {
+                            "Rich results have every recommended property".to_string()
+                        } else {
+                            format!("{} recommended properties are missing", findings.len())
+                        },
+                        findings,
+                    })
+                },
+                params: vec![],
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{all_rules_config, result};
+    use crate::utils::config::{RuleResult, RuleStatus};
+
+    fn results(head: &str, body: &str) -> Vec<RuleResult> {
+        let plugin = StructuredDataPlugin::new();
+        let html = format!("<html><head>{head}</head><body>{body}</body></html>");
+        plugin.analyze(&Page::from_html(html), &all_rules_config(&plugin))
+    }
+
+    fn values(result: &RuleResult) -> Vec<&str> {
+        result
+            .findings
+            .iter()
+            .filter_map(|finding| finding.value.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn test_definitions_are_consistent() {
+        let definitions = definitions().unwrap();
+        for (name, schema_type) in &definitions.types {
+            for parent in &schema_type.parents {
+                assert!(definitions.types.contains_key(parent), "{name}: {parent}");
+            }
+        }
+        for (name, rich_result) in &definitions.rich_results {
+            let properties: Vec<&str> = definitions
+                .lineage(name)
+                .unwrap_or_else(|| panic!("{name} is not a bundled type"))
+                .into_iter()
+                .flat_map(|type_name| definitions.types[type_name].properties.iter())
+                .map(String::as_str)
+                .collect();
+            for property in rich_result
+                .required
+                .iter()
+                .flatten()
+                .chain(&rich_result.recommended)
+            {
+                assert!(
+                    properties.contains(&property.as_str()),
+                    "{name}: {property}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_valid_product() {
+        let results = results(
+            r#"<script type="application/ld+json">
+            {"@context": "https://schema.org", "@type": "Product", "name": "Trail Shoe",
+             "image": "https://example.com/shoe.jpg", "description": "A shoe", "sku": "TS-1",
+             "brand": {"@type": "Brand", "name": "Example"},
+             "offers": {"@type": "Offer", "price": "89.50", "priceCurrency": "EUR",
+                        "availability": "https://schema.org/InStock"}}
+            </script>"#,
+            "",
+        );
+        for result in &results {
+            assert_eq!(result.status, RuleStatus::Passed, "{}", result.rule_id);
+        }
+    }
+
+    #[test]
+    fn test_invalid_json_ld() {
+        let results = results(
+            "<script type=\"application/ld+json\">\n{\"@type\": \"Article\",}\n</script>",
+            "",
+        );
+        let valid = result(&results, "structured_data.valid_json_ld");
+        assert_eq!(valid.status, RuleStatus::Failed);
+        assert_eq!(valid.findings.len(), 1);
+        assert!(valid.message.contains("line"), "{}", valid.message);
+        assert_eq!(
+            result(&results, "structured_data.rich_results_required").status,
+            RuleStatus::NotApplicable
+        );
+    }
+
+    #[test]
+    fn test_missing_and_unknown_properties() {
+        let results = results(
+            r#"<script type="application/ld+json">
+            [{"@type": "Product", "name": "Trail Shoe", "prize": "89"},
+             {"@type": "Restaurant", "name": "Luigi's", "servesCuisine": "Italian"},
+             {"name": "No type"},
+             {"@type": "Event", "name": "Not bundled", "whatever": "x"}]
+            </script>"#,
+            r#"<ol itemscope itemtype="https://schema.org/BreadcrumbList">
+                <li itemprop="itemListElement" itemscope itemtype="https://schema.org/ListItem">
+                    <a itemprop="item" href="/shoes">Shoes</a>
+                </li>
+            </ol>"#,
+        );
+
+        let schema_org = result(&results, "structured_data.schema_org");
+        assert_eq!(schema_org.status, RuleStatus::Failed);
+        assert_eq!(
+            values(schema_org),
+            vec!["Product has no property prize", "JSON-LD item has no type"]
+        );
+
+        let required = result(&results, "structured_data.rich_results_required");
+        assert_eq!(required.status, RuleStatus::Failed);
+        assert_eq!(
+            values(required),
+            vec![
+                "Product is missing offers, review or aggregateRating",
+                "Restaurant is missing address",
+                "ListItem is missing position",
+            ]
+        );
+
+        let recommended = result(&results, "structured_data.rich_results_recommended");
+        assert_eq!(recommended.status, RuleStatus::Failed);
+        assert!(values(recommended).contains(&"Restaurant is missing telephone"));
+    }
+}
@@ -1,8 +1,10 @@
 use std::any::Any;
 
 use crate::utils::{
-    config::{CheckResult, Rule, RuleCategory, Severity},
+    config::{CheckResult, Effort, Remediation, Rule, RuleCategory, Severity},
     page_plugin::SeoPlugin,
+    rule_param::RuleParam,
+    text_width::title_pixel_width,
 };
 
 // Title Plugin
@@ -45,12 +47,20 @@ impl SeoPlugin for TitlePlugin {
                 passed_message: "Page has a title tag",
                 failed_message: "Page is missing a title tag",
                 category: RuleCategory::SEO,
-                check: |page| {
-                    let page = page.clone();
+                remediation: Some(
+                    Remediation::new(
+                        "Search engines show the title as the headline of the result and browsers show it in tabs and bookmarks.",
+                        "Add one <title> element to the <head> that describes the page's content.",
+                        Effort::Low,
+                    )
+                    .with_example("<title>Running Shoes for Trail and Road | Example Store</title>")
+                    .with_reference("https://developers.google.com/search/docs/appearance/title-link")
+                ),
+                check: |page, _params| {
                     let meta_tags = page.extract_meta_tags();
                     let has_title = meta_tags.title.is_some();
 
-                    CheckResult {
+                    Ok(CheckResult {
                         rule_id: "title.has_title".to_string(),
                         passed: has_title,
                         message: if has_title {
@@ -59,30 +69,95 @@ impl SeoPlugin for TitlePlugin {
                             "Page is missing a title tag"
                         }
                         .to_string(),
-                    }
+                        findings: vec![],
+                    })
                 },
+                params: vec![],
             },
             Rule {
                 id: "title.title_length",
-                name: "Title length is less than 60 characters",
+                name: "Title fits in search results",
                 plugin_name: PLUGIN_NAME,
-                description: "Checks if the title length is less than 60 characters",
+                description: "Checks if the title is short enough to show in search results without being truncated",
                 default_severity: Severity::Warning,
-                passed_message: "Title length is less than 60 characters",
-                failed_message: "Title length is greater than 60 characters",
+                passed_message: "Title fits in search results",
+                failed_message: "Title is likely truncated in search results",
                 category: RuleCategory::SEO,
-                check: |page| {
-                    let page = page.clone();
+                remediation: Some(
+                    Remediation::new(
+                        "Search results truncate long titles, cutting off the words that tell searchers what the page is about.",
+                        "Shorten the title so it fits the configured width. Put the most important words first and drop filler such as repeated brand names.",
+                        Effort::Low,
+                    )
+                    .with_example("<title>Trail Running Shoes | Example Store</title>")
+                    .with_reference("https://developers.google.com/search/docs/appearance/title-link")
+                ),
+                check: |page, params| {
                     let meta_tags = page.extract_meta_tags();
                     let title = meta_tags.title.unwrap_or_default();
-                    let title_length = title.len();
-                    let passed = title_length < 60 && title_length > 0;
-                    CheckResult {
+                    if title.is_empty() {
+                        return Ok(CheckResult {
+                            rule_id: "title.title_length".to_string(),
+                            passed: false,
+                            message: "Title is empty".to_string(),
+                            findings: vec![],
+                        });
+                    }
+
+                    let (passed, message) = match params.text("measure")? {
+                        "pixels" => {
+                            let max_pixels = params.number("max_pixels")?;
+                            let width = title_pixel_width(&title);
+                            (
+                                width <= max_pixels,
+                                format!(
+                                    "Title is about {width:.0}px wide, the limit is {max_pixels}px"
+                                ),
+                            )
+                        }
+                        _ => {
+                            let max_length = params.number("max_length")?;
+                            let length = title.chars().count();
+                            (
+                                length as f64 <= max_length,
+                                format!(
+                                    "Title length is {length} characters, the limit is {max_length}"
+                                ),
+                            )
+                        }
+                    };
+                    Ok(CheckResult {
                         rule_id: "title.title_length".to_string(),
                         passed,
-                        message: format!("Title length is {} characters", title_length),
-                    }
+                        message,
+                        findings: vec![],
+                    })
                 },
+                params: vec![
+                    RuleParam::choice(
+                        "measure",
+                        "Measure",
+                        "Count characters, or estimate the rendered width in pixels",
+                        "characters",
+                        &["characters", "pixels"],
+                    ),
+                    RuleParam::number(
+                        "max_length",
+                        "Max characters",
+                        "Longest title allowed when measuring characters",
+                        60.0,
+                        1.0,
+                        200.0,
+                    ),
+                    RuleParam::number(
+                        "max_pixels",
+                        "Max width (px)",
+                        "Widest title allowed when measuring pixels",
+                        580.0,
+                        100.0,
+                        1200.0,
+                    ),
+                ],
             },
         ]
     }
@@ -91,7 +166,12 @@ impl SeoPlugin for TitlePlugin {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::utils::{config::RuleConfig, page::Page, page_plugin::SeoPlugin};
+    use crate::utils::{
+        config::{CheckError, RuleConfig},
+        page::Page,
+        page_plugin::SeoPlugin,
+        rule_param::RuleParamValue,
+    };
     use hyper::service::{make_service_fn, service_fn};
     use hyper::{Body, Response, Server};
 
@@ -140,6 +220,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_title_length_measures_pixels() {
+        // 50 characters, but wide ones
+        let page = Page::from_html(format!(
+            "<html><head><title>{}</title></head></html>",
+            "W".repeat(50)
+        ));
+        let rule = TitlePlugin::new()
+            .available_rules()
+            .into_iter()
+            .find(|rule| rule.id == "title.title_length")
+            .unwrap();
+
+        let mut config = RuleConfig::new();
+        assert!(rule.run(&page, &config).unwrap().passed);
+
+        config.set_param(
+            "title.title_length",
+            "measure",
+            RuleParamValue::Text("pixels".to_string()),
+        );
+        assert!(!rule.run(&page, &config).unwrap().passed);
+
+        config.set_param(
+            "title.title_length",
+            "max_pixels",
+            RuleParamValue::Number(1000.0),
+        );
+        assert!(rule.run(&page, &config).unwrap().passed);
+
+        config.set_param(
+            "title.title_length",
+            "max_pixels",
+            RuleParamValue::Number(0.0),
+        );
+        assert!(matches!(
+            rule.run(&page, &config),
+            Err(CheckError::InvalidParam(_))
+        ));
+    }
+
     async fn start_test_server() -> SocketAddr {
         let addr = SocketAddr::from(([127, 0, 0, 1], 0));
         let listener = TcpListener::bind(addr).await.unwrap();
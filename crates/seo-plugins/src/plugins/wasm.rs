@@ -0,0 +1,723 @@
+// Runs third-party plugins compiled to WebAssembly, sandboxed with fuel and memory limits.
+//
+// A plugin is a core WASM module that exports:
+//   memory
+//   serpeo_alloc(len: i32) -> i32             memory the host writes its answers into
+//   serpeo_plugin() -> i64                    JSON manifest: { name, rules: [`WasmRule`] }
+//   serpeo_check(id_ptr: i32, id_len: i32) -> i64
+//                                             JSON outcome for one rule id:
+//                                             { status: "passed" | "failed" | "not_applicable",
+//                                               message, findings? }
+// Strings cross the boundary as UTF-8 packed into an i64, `ptr << 32 | len`.
+//
+// It can import from the `serpeo` module, where -1 means "absent":
+//   page_url() -> i64
+//   page_html() -> i64
+//   page_status() -> i32                      0 when unknown
+//   meta_tags() -> i64                        JSON `MetaTagInfo`
+//   header(name_ptr: i32, name_len: i32) -> i64
+//   links() -> i64                            JSON array of `Link`
+//   query(selector_ptr: i32, selector_len: i32) -> i64
+//                                             JSON array of `WasmElement`, -1 on a bad selector
+
+use std::any::Any;
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use scraper::Selector;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use wasmtime::{
+    Caller, Config, Engine, Instance, InstancePre, Linker, Module, Store, StoreLimits,
+    StoreLimitsBuilder,
+};
+
+use crate::utils::{
+    config::{
+        CheckError, CheckResult, Finding, Remediation, Rule, RuleCategory, RuleConfig, RuleDisplay,
+        RuleResult, RuleType, Severity, page_rule_result,
+    },
+    page::Page,
+    page_plugin::SeoPlugin,
+};
+
+const HOST_MODULE: &str = "serpeo";
+// Keeps WASM rule ids apart from built-in and custom rule ids
+const RULE_ID_PREFIX: &str = "wasm.";
+const ABSENT: i64 = -1;
+
+#[derive(Debug, Error)]
+pub enum WasmPluginError {
+    #[error("Failed to read {path}: {error}")]
+    ReadError { path: String, error: std::io::Error },
+    #[error("Failed to load WASM plugin {plugin}: {message}")]
+    LoadError { plugin: String, message: String },
+    #[error("Invalid manifest in WASM plugin {plugin}: {error}")]
+    InvalidManifest {
+        plugin: String,
+        error: serde_json::Error,
+    },
+    #[error("Duplicate rule id: {0}")]
+    DuplicateRule(String),
+    #[error("Rule id {0} must start with \"{prefix}\"", prefix = RULE_ID_PREFIX)]
+    InvalidRuleId(String),
+}
+
+// Resources a plugin may use for each rule check
+#[derive(Debug, Clone, Copy)]
+pub struct WasmLimits {
+    // Roughly one unit per WASM instruction
+    pub fuel: u64,
+    pub memory_bytes: usize,
+}
+
+impl Default for WasmLimits {
+    fn default() -> Self {
+        Self {
+            fuel: 50_000_000,
+            memory_bytes: 64 * 1024 * 1024,
+        }
+    }
+}
+
+// Rule metadata as declared in a plugin's manifest, the same fields as `Rule`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct WasmRule {
+    // Must start with "wasm."
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub severity: Severity,
+    pub category: RuleCategory,
+    pub passed_message: String,
+    pub failed_message: String,
+    #[serde(default)]
+    pub remediation: Option<Remediation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WasmManifest {
+    name: String,
+    rules: Vec<WasmRule>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum WasmOutcome {
+    Passed {
+        message: String,
+        #[serde(default)]
+        findings: Vec<Finding>,
+    },
+    Failed {
+        message: String,
+        #[serde(default)]
+        findings: Vec<Finding>,
+    },
+    NotApplicable {
+        message: String,
+    },
+}
+
+// An element matched by the `query` host function
+#[derive(Debug, Serialize)]
+pub struct WasmElement {
+    pub tag: String,
+    pub text: String,
+    pub attributes: BTreeMap<String, String>,
+    // Location of the element, for plugins to return in their findings
+    pub finding: Finding,
+}
+
+struct HostState {
+    page: Page,
+    limits: StoreLimits,
+}
+
+#[derive(Clone)]
+struct WasmPlugin {
+    name: String,
+    rules: Vec<WasmRule>,
+    instance_pre: InstancePre<HostState>,
+}
+
+fn pack(ptr: i32, len: usize) -> i64 {
+    ((ptr as u32 as i64) << 32) | (len as u32 as i64)
+}
+
+fn unpack(packed: i64) -> (usize, usize) {
+    (
+        (packed as u64 >> 32) as usize,
+        (packed as u64 & 0xffff_ffff) as usize,
+    )
+}
+
+fn read_guest_string(memory: &[u8], ptr: usize, len: usize) -> Result<String, wasmtime::Error> {
+    let bytes = memory
+        .get(ptr..ptr.saturating_add(len))
+        .ok_or_else(|| wasmtime::Error::msg("String is outside the plugin's memory"))?;
+    Ok(String::from_utf8(bytes.to_vec())?)
+}
+
+fn caller_string(
+    caller: &mut Caller<'_, HostState>,
+    ptr: i32,
+    len: i32,
+) -> Result<String, wasmtime::Error> {
+    let memory = caller
+        .get_export("memory")
+        .and_then(|export| export.into_memory())
+        .ok_or_else(|| wasmtime::Error::msg("Plugin does not export memory"))?;
+    read_guest_string(memory.data(&caller), ptr as usize, len as usize)
+}
+
+// Copies `bytes` into memory handed out by the plugin's `serpeo_alloc`
+fn caller_write(caller: &mut Caller<'_, HostState>, bytes: &[u8]) -> Result<i64, wasmtime::Error> {
+    let alloc = caller
+        .get_export("serpeo_alloc")
+        .and_then(|export| export.into_func())
+        .ok_or_else(|| wasmtime::Error::msg("Plugin does not export serpeo_alloc"))?
+        .typed::<i32, i32>(&caller)?;
+    let ptr = alloc.call(&mut *caller, bytes.len() as i32)?;
+    let memory = caller
+        .get_export("memory")
+        .and_then(|export| export.into_memory())
+        .ok_or_else(|| wasmtime::Error::msg("Plugin does not export memory"))?;
+    memory.write(&mut *caller, ptr as usize, bytes)?;
+    Ok(pack(ptr, bytes.len()))
+}
+
+fn caller_write_json<T: Serialize>(
+    caller: &mut Caller<'_, HostState>,
+    value: &T,
+) -> Result<i64, wasmtime::Error> {
+    let json = serde_json::to_vec(value)?;
+    caller_write(caller, &json)
+}
+
+fn query(page: &Page, selector: &str) -> Option<Vec<WasmElement>> {
+    let selector = Selector::parse(selector).ok()?;
+    let document = page.get_document().ok()?;
+    let elements = document
+        .select(&selector)
+        .map(|element| WasmElement {
+            tag: element.value().name().to_string(),
+            text: element.text().collect(),
+            attributes: element
+                .value()
+                .attrs()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            finding: page.finding(&element),
+        })
+        .collect();
+    Some(elements)
+}
+
+fn host_linker(engine: &Engine) -> Result<Linker<HostState>, wasmtime::Error> {
+    let mut linker = Linker::new(engine);
+    linker.func_wrap(
+        HOST_MODULE,
+        "page_url",
+        |mut caller: Caller<'_, HostState>| {
+            let url = caller.data().page.get_url().to_string();
+            caller_write(&mut caller, url.as_bytes())
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "page_html",
+        |mut caller: Caller<'_, HostState>| {
+            let Some(html) = caller.data().page.get_html().map(|html| html.to_string()) else {
+                return Ok(ABSENT);
+            };
+            caller_write(&mut caller, html.as_bytes())
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "page_status",
+        |caller: Caller<'_, HostState>| {
+            caller
+                .data()
+                .page
+                .get_status_code()
+                .map_or(0, |status| i32::from(status.get()))
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "meta_tags",
+        |mut caller: Caller<'_, HostState>| {
+            let meta_tags = caller.data().page.extract_meta_tags();
+            caller_write_json(&mut caller, &meta_tags)
+        },
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "header",
+        |mut caller: Caller<'_, HostState>, ptr: i32, len: i32| {
+            let name = caller_string(&mut caller, ptr, len)?;
+            let Some(value) = caller.data().page.get_header(&name).map(|v| v.to_string()) else {
+                return Ok(ABSENT);
+            };
+            caller_write(&mut caller, value.as_bytes())
+        },
+    )?;
+    linker.func_wrap(HOST_MODULE, "links", |mut caller: Caller<'_, HostState>| {
+        let links = caller.data().page.extract_links().unwrap_or_default();
+        caller_write_json(&mut caller, &links)
+    })?;
+    linker.func_wrap(
+        HOST_MODULE,
+        "query",
+        |mut caller: Caller<'_, HostState>, ptr: i32, len: i32| {
+            let selector = caller_string(&mut caller, ptr, len)?;
+            let Some(elements) = query(&caller.data().page, &selector) else {
+                return Ok(ABSENT);
+            };
+            caller_write_json(&mut caller, &elements)
+        },
+    )?;
+    Ok(linker)
+}
+
+impl WasmPlugin {
+    fn load(
+        engine: &Engine,
+        linker: &Linker<HostState>,
+        limits: WasmLimits,
+        plugin: &str,
+        bytes: &[u8],
+    ) -> Result<Self, WasmPluginError> {
+        let load_error = |error: wasmtime::Error| WasmPluginError::LoadError {
+            plugin: plugin.to_string(),
+            message: error.to_string(),
+        };
+        let module = Module::new(engine, bytes).map_err(load_error)?;
+        let instance_pre = linker.instantiate_pre(&module).map_err(load_error)?;
+
+        let (mut store, instance) =
+            Self::instantiate(&instance_pre, Page::default(), limits).map_err(load_error)?;
+        let manifest = instance
+            .get_typed_func::<(), i64>(&mut store, "serpeo_plugin")
+            .and_then(|plugin| plugin.call(&mut store, ()))
+            .and_then(|packed| Self::read_string(&mut store, &instance, packed))
+            .map_err(load_error)?;
+        let manifest: WasmManifest =
+            serde_json::from_str(&manifest).map_err(|error| WasmPluginError::InvalidManifest {
+                plugin: plugin.to_string(),
+                error,
+            })?;
+
+        Ok(Self {
+            name: manifest.name,
+            rules: manifest.rules,
+            instance_pre,
+        })
+    }
+
+    fn instantiate(
+        instance_pre: &InstancePre<HostState>,
+        page: Page,
+        limits: WasmLimits,
+    ) -> Result<(Store<HostState>, Instance), wasmtime::Error> {
+        let state = HostState {
+            page,
+            limits: StoreLimitsBuilder::new()
+                .memory_size(limits.memory_bytes)
+                .build(),
+        };
+        let mut store = Store::new(instance_pre.module().engine(), state);
+        store.limiter(|state| &mut state.limits);
+        store.set_fuel(limits.fuel)?;
+        let instance = instance_pre.instantiate(&mut store)?;
+        Ok((store, instance))
+    }
+
+    fn read_string(
+        store: &mut Store<HostState>,
+        instance: &Instance,
+        packed: i64,
+    ) -> Result<String, wasmtime::Error> {
+        let memory = instance
+            .get_memory(&mut *store, "memory")
+            .ok_or_else(|| wasmtime::Error::msg("Plugin does not export memory"))?;
+        let (ptr, len) = unpack(packed);
+        read_guest_string(memory.data(&*store), ptr, len)
+    }
+
+    fn write_string(
+        store: &mut Store<HostState>,
+        instance: &Instance,
+        value: &str,
+    ) -> Result<(i32, i32), wasmtime::Error> {
+        let alloc = instance.get_typed_func::<i32, i32>(&mut *store, "serpeo_alloc")?;
+        let ptr = alloc.call(&mut *store, value.len() as i32)?;
+        let memory = instance
+            .get_memory(&mut *store, "memory")
+            .ok_or_else(|| wasmtime::Error::msg("Plugin does not export memory"))?;
+        memory.write(&mut *store, ptr as usize, value.as_bytes())?;
+        Ok((ptr, value.len() as i32))
+    }
+
+    fn check(
+        store: &mut Store<HostState>,
+        instance: &Instance,
+        limits: WasmLimits,
+        rule: &WasmRule,
+    ) -> Result<CheckResult, CheckError> {
+        let plugin_error = |error: wasmtime::Error| CheckError::PluginError(error.to_string());
+        // Each rule gets the full budget, whatever earlier rules used
+        store.set_fuel(limits.fuel).map_err(plugin_error)?;
+        let (ptr, len) = Self::write_string(store, instance, &rule.id).map_err(plugin_error)?;
+        let outcome = instance
+            .get_typed_func::<(i32, i32), i64>(&mut *store, "serpeo_check")
+            .and_then(|check| check.call(&mut *store, (ptr, len)))
+            .and_then(|packed| Self::read_string(store, instance, packed))
+            .map_err(plugin_error)?;
+        let outcome: WasmOutcome = serde_json::from_str(&outcome)
+            .map_err(|error| CheckError::PluginError(error.to_string()))?;
+
+        let (passed, message, findings) = match outcome {
+            WasmOutcome::Passed { message, findings } => (true, message, findings),
+            WasmOutcome::Failed { message, findings } => (false, message, findings),
+            WasmOutcome::NotApplicable { message } => {
+                return Err(CheckError::NotApplicable(message));
+            }
+        };
+        Ok(CheckResult {
+            rule_id: rule.id.clone(),
+            passed,
+            message,
+            findings,
+        })
+    }
+
+    fn to_display(&self, rule: &WasmRule) -> RuleDisplay {
+        RuleDisplay {
+            id: rule.id.clone(),
+            name: rule.name.clone(),
+            plugin_name: self.name.clone(),
+            description: rule.description.clone(),
+            severity: rule.severity.clone(),
+            category: rule.category.clone(),
+            rule_type: RuleType::Page,
+            passed_message: rule.passed_message.clone(),
+            failed_message: rule.failed_message.clone(),
+            params: vec![],
+            remediation: rule.remediation.clone(),
+        }
+    }
+
+    fn to_result(
+        &self,
+        rule: &WasmRule,
+        config: &RuleConfig,
+        result: Result<CheckResult, CheckError>,
+    ) -> RuleResult {
+        page_rule_result(
+            &rule.id,
+            &rule.name,
+            &self.name,
+            config.get_severity(&rule.id, rule.severity.clone()),
+            rule.category.clone(),
+            result,
+        )
+    }
+
+    fn analyze(&self, page: &Page, config: &RuleConfig, limits: WasmLimits) -> Vec<RuleResult> {
+        let rules: Vec<&WasmRule> = self
+            .rules
+            .iter()
+            .filter(|rule| config.is_rule_enabled(&rule.id))
+            .collect();
+        if rules.is_empty() {
+            return vec![];
+        }
+
+        // One instance per page, shared by the plugin's rules
+        match Self::instantiate(&self.instance_pre, page.clone(), limits) {
+            Ok((mut store, instance)) => rules
+                .into_iter()
+                .map(|rule| {
+                    let result = Self::check(&mut store, &instance, limits, rule);
+                    self.to_result(rule, config, result)
+                })
+                .collect(),
+            Err(error) => rules
+                .into_iter()
+                .map(|rule| {
+                    let result = Err(CheckError::PluginError(error.to_string()));
+                    self.to_result(rule, config, result)
+                })
+                .collect(),
+        }
+    }
+}
+
+// Runs every loaded WASM plugin. Registered as a single plugin, like `CustomRulePlugin`,
+// since the registry keys plugins by type.
+#[derive(Clone)]
+pub struct WasmPluginRuntime {
+    engine: Engine,
+    linker: Linker<HostState>,
+    limits: WasmLimits,
+    plugins: Vec<WasmPlugin>,
+}
+
+impl Default for WasmPluginRuntime {
+    fn default() -> Self {
+        #[allow(clippy::unwrap_used)]
+        Self::new(WasmLimits::default()).unwrap()
+    }
+}
+
+impl WasmPluginRuntime {
+    pub fn new(limits: WasmLimits) -> Result<Self, WasmPluginError> {
+        let load_error = |error: wasmtime::Error| WasmPluginError::LoadError {
+            plugin: HOST_MODULE.to_string(),
+            message: error.to_string(),
+        };
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config).map_err(load_error)?;
+        let linker = host_linker(&engine).map_err(load_error)?;
+        Ok(Self {
+            engine,
+            linker,
+            limits,
+            plugins: vec![],
+        })
+    }
+
+    // Loads a plugin from WASM bytes, `plugin` names it in errors
+    pub fn add_plugin(&mut self, plugin: &str, bytes: &[u8]) -> Result<(), WasmPluginError> {
+        let plugin = WasmPlugin::load(&self.engine, &self.linker, self.limits, plugin, bytes)?;
+        let mut ids: HashSet<&str> = self
+            .plugins
+            .iter()
+            .flat_map(|plugin| plugin.rules.iter().map(|rule| rule.id.as_str()))
+            .collect();
+        for rule in &plugin.rules {
+            if !rule.id.starts_with(RULE_ID_PREFIX) {
+                return Err(WasmPluginError::InvalidRuleId(rule.id.clone()));
+            }
+            if !ids.insert(&rule.id) {
+                return Err(WasmPluginError::DuplicateRule(rule.id.clone()));
+            }
+        }
+        self.plugins.push(plugin);
+        Ok(())
+    }
+
+    // Loads every `.wasm` file in `dir`, a missing directory has no plugins
+    pub fn load_dir(dir: &Path, limits: WasmLimits) -> Result<Self, WasmPluginError> {
+        let mut runtime = Self::new(limits)?;
+        if !dir.is_dir() {
+            return Ok(runtime);
+        }
+        let mut paths: Vec<_> = std::fs::read_dir(dir)
+            .map_err(|error| WasmPluginError::ReadError {
+                path: dir.to_string_lossy().to_string(),
+                error,
+            })?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.extension()
+                    .is_some_and(|extension| extension == "wasm")
+            })
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let path_name = path.to_string_lossy().to_string();
+            let bytes = std::fs::read(&path).map_err(|error| WasmPluginError::ReadError {
+                path: path_name.clone(),
+                error,
+            })?;
+            runtime.add_plugin(&path_name, &bytes)?;
+        }
+        Ok(runtime)
+    }
+}
+
+#[async_trait::async_trait]
+impl SeoPlugin for WasmPluginRuntime {
+    fn name(&self) -> &str {
+        "WASM Plugins"
+    }
+
+    fn description(&self) -> &str {
+        "Third-party plugins compiled to WebAssembly"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn available_rules(&self) -> Vec<Rule> {
+        vec![]
+    }
+
+    fn rule_displays(&self) -> Vec<RuleDisplay> {
+        self.plugins
+            .iter()
+            .flat_map(|plugin| plugin.rules.iter().map(|rule| plugin.to_display(rule)))
+            .collect()
+    }
+
+    async fn analyze_async(&self, page: &Page, config: &RuleConfig) -> Vec<RuleResult> {
+        self.analyze(page, config)
+    }
+
+    fn analyze(&self, page: &Page, config: &RuleConfig) -> Vec<RuleResult> {
+        self.plugins
+            .iter()
+            .flat_map(|plugin| plugin.analyze(page, config, self.limits))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{all_rules_config, result};
+    use crate::utils::config::RuleStatus;
+
+    const MANIFEST: &str = r#"{"name":"Test WASM","rules":[
+        {"id":"wasm.has_h1","name":"Has h1","severity":"Error","category":"SEO","passed_message":"Page has an h1","failed_message":"Page has no h1"},
+        {"id":"wasm.spin","name":"Spins","severity":"Info","category":"Performance","passed_message":"","failed_message":""},
+        {"id":"wasm.grow","name":"Grows","severity":"Info","category":"Performance","passed_message":"","failed_message":""}]}"#;
+    const PASSED: &str = r#"{"status":"passed","message":"Found an h1"}"#;
+    const FAILED: &str = r#"{"status":"failed","message":"No h1"}"#;
+    const SELECTOR: &str = "h1";
+
+    // Lays out the strings one after another in a data segment at 0
+    fn test_module(manifest: &str) -> String {
+        let mut offset = 0;
+        let mut segments = String::new();
+        let mut packed = vec![];
+        for value in [manifest, PASSED, FAILED, SELECTOR] {
+            segments.push_str(&format!(
+                "(data (i32.const {offset}) \"{}\")\n",
+                value
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+                    .replace('\n', "\\n")
+            ));
+            packed.push((offset, value.len()));
+            offset += value.len();
+        }
+        let pack = |(offset, len): (usize, usize)| pack(offset as i32, len);
+        format!(
+            r#"(module
+                (import "serpeo" "query" (func $query (param i32 i32) (result i64)))
+                (memory (export "memory") 1)
+                (global $heap (mut i32) (i32.const 16384))
+                {segments}
+                (func (export "serpeo_alloc") (param $len i32) (result i32)
+                    (global.get $heap)
+                    (global.set $heap (i32.add (global.get $heap) (local.get $len))))
+                (func (export "serpeo_plugin") (result i64) (i64.const {manifest}))
+                (func (export "serpeo_check") (param $ptr i32) (param $len i32) (result i64)
+                    (local $rule i32)
+                    ;; "wasm.h", "wasm.s" or "wasm.g"
+                    (local.set $rule (i32.load8_u (i32.add (local.get $ptr) (i32.const 5))))
+                    (if (i32.eq (local.get $rule) (i32.const 104))
+                        (then
+                            ;; An empty result is `[]`
+                            (if (i64.gt_u
+                                    (i64.and
+                                        (call $query (i32.const {selector_ptr}) (i32.const {selector_len}))
+                                        (i64.const 0xffffffff))
+                                    (i64.const 2))
+                                (then (return (i64.const {passed}))))
+                            (return (i64.const {failed}))))
+                    (if (i32.eq (local.get $rule) (i32.const 115))
+                        (then (loop $spin (br $spin))))
+                    (if (i32.eq (memory.grow (i32.const 1024)) (i32.const -1))
+                        (then (return (i64.const {failed}))))
+                    (i64.const {passed})))"#,
+            manifest = pack(packed[0]),
+            passed = pack(packed[1]),
+            failed = pack(packed[2]),
+            selector_ptr = packed[3].0,
+            selector_len = packed[3].1,
+        )
+    }
+
+    fn runtime(limits: WasmLimits) -> WasmPluginRuntime {
+        let mut runtime = WasmPluginRuntime::new(limits).unwrap();
+        runtime
+            .add_plugin("test.wat", test_module(MANIFEST).as_bytes())
+            .unwrap();
+        runtime
+    }
+
+    fn results(runtime: &WasmPluginRuntime, html: &str) -> Vec<RuleResult> {
+        runtime.analyze(
+            &Page::from_html(html.to_string()),
+            &all_rules_config(runtime),
+        )
+    }
+
+    #[test]
+    fn test_wasm_plugin_reports_manifest_rules() {
+        let runtime = runtime(WasmLimits::default());
+        let rules = runtime.rule_displays();
+        assert_eq!(rules.len(), 3);
+        assert_eq!(rules[0].id, "wasm.has_h1");
+        assert_eq!(rules[0].plugin_name, "Test WASM");
+        assert_eq!(rules[0].severity, Severity::Error);
+
+        assert!(matches!(
+            runtime
+                .clone()
+                .add_plugin("again.wat", test_module(MANIFEST).as_bytes()),
+            Err(WasmPluginError::DuplicateRule(_))
+        ));
+        // Built-in rule ids can't be taken over
+        let manifest = MANIFEST.replace("wasm.has_h1", "title.has_title");
+        assert!(matches!(
+            runtime
+                .clone()
+                .add_plugin("other.wat", test_module(&manifest).as_bytes()),
+            Err(WasmPluginError::InvalidRuleId(_))
+        ));
+        assert!(matches!(
+            runtime.clone().add_plugin("bad.wasm", b"not wasm"),
+            Err(WasmPluginError::LoadError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_wasm_plugin_queries_the_page() {
+        let runtime = runtime(WasmLimits::default());
+
+        let passing = results(&runtime, "<html><body><h1>Title</h1></body></html>");
+        let has_h1 = result(&passing, "wasm.has_h1");
+        assert_eq!(has_h1.status, RuleStatus::Passed);
+        assert_eq!(has_h1.plugin_name, "Test WASM");
+        assert_eq!(has_h1.message, "Found an h1");
+
+        let failing = results(&runtime, "<html><body><p>Text</p></body></html>");
+        assert_eq!(result(&failing, "wasm.has_h1").status, RuleStatus::Failed);
+    }
+
+    #[test]
+    fn test_wasm_plugin_limits() {
+        let runtime = runtime(WasmLimits {
+            fuel: 1_000_000,
+            memory_bytes: 1024 * 1024,
+        });
+        let results = results(&runtime, "<html><body><h1>Title</h1></body></html>");
+
+        // Running out of fuel stops the rule without affecting the others
+        assert_eq!(result(&results, "wasm.spin").status, RuleStatus::Errored);
+        assert_eq!(result(&results, "wasm.has_h1").status, RuleStatus::Passed);
+        // Growing memory past the limit is refused
+        assert_eq!(result(&results, "wasm.grow").status, RuleStatus::Failed);
+    }
+}
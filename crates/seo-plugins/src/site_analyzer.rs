@@ -1,7 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
 use futures::stream::{self, StreamExt};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::sync::Arc;
@@ -12,9 +12,11 @@ use url::Url;
 
 use crate::utils::{
     config::{RuleResult, SiteCheckContext},
-    link_parser::{FromUrl, LinkParseError, LinkType, parse_link},
-    page::{Page, PageError},
+    link_graph::{LinkGraph, PageLinkMetrics},
+    link_parser::{FromUrl, Link, LinkParseError, LinkType, parse_link},
+    page::{Page, PageError, PageMetadata},
     registry::PluginRegistry,
+    site_plugin::SiteRuns,
     sitemap_parser::{SitemapParser, SitemapParserError},
 };
 
@@ -43,6 +45,16 @@ pub struct PageLink {
     pub link_type: LinkType,
     pub found_in: HashSet<PageLinkSource>,
     pub result: Option<PageResult>,
+    // Clicks from the start page, `None` for pages only found in the sitemap
+    pub depth: Option<u32>,
+    // How the crawler first found the page
+    pub discovered_by: LinkSourceType,
+    // Set once the page has been fetched
+    pub metadata: Option<PageMetadata>,
+    // Links on the page, with hrefs cleaned like the crawl's link keys
+    pub outlinks: Vec<Link>,
+    // Set for internal pages once the crawl is over
+    pub link_metrics: Option<PageLinkMetrics>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Type)]
@@ -55,7 +67,7 @@ pub struct CrawlResult {
 #[derive(Debug, Serialize, Deserialize, Clone, Type)]
 pub enum AnalysisProgressType {
     FoundLink,
-    AnalyzedPage(PageLink),
+    AnalyzedPage(Box<PageLink>),
     AnalyzedSite(Vec<RuleResult>),
 }
 
@@ -87,6 +99,8 @@ pub struct SiteAnalyzer {
     url: Url,
     links: Arc<RwLock<HashMap<String, PageLink>>>,
     registry: Arc<RwLock<PluginRegistry>>,
+    // Site plugin state for the crawl in progress
+    site_runs: Arc<Mutex<SiteRuns>>,
     progress_callback: Arc<RwLock<ProgressCallback>>,
 }
 
@@ -97,6 +111,7 @@ impl SiteAnalyzer {
             url,
             links: Arc::new(RwLock::new(HashMap::new())),
             registry: Arc::new(RwLock::new(registry)),
+            site_runs: Default::default(),
             progress_callback: Arc::new(RwLock::new(None)),
         })
     }
@@ -107,6 +122,7 @@ impl SiteAnalyzer {
             url,
             links: Arc::new(RwLock::new(HashMap::new())),
             registry: Arc::new(RwLock::new(PluginRegistry::default_with_config())),
+            site_runs: Default::default(),
             progress_callback: Arc::new(RwLock::new(None)),
         })
     }
@@ -119,6 +135,11 @@ impl SiteAnalyzer {
         self
     }
 
+    // The start URL of the crawl
+    pub fn get_url(&self) -> &Url {
+        &self.url
+    }
+
     pub fn get_links(&self) -> HashMap<String, PageLink> {
         self.links.read().clone()
     }
@@ -215,7 +236,7 @@ impl SiteAnalyzer {
                 .ok_or(SiteAnalyzerError::LinkNotFound(url.to_string()))?
                 .clone();
             self.report_progress(
-                AnalysisProgressType::AnalyzedPage(link.clone()),
+                AnalysisProgressType::AnalyzedPage(Box::new(link.clone())),
                 Some(url.to_string()),
             )
             .await;
@@ -224,7 +245,7 @@ impl SiteAnalyzer {
         Ok(())
     }
 
-    fn clean_url(url: String) -> String {
+    pub(crate) fn clean_url(url: String) -> String {
         let mut url = url;
         if let Some(query_start) = url.find('?') {
             url.truncate(query_start);
@@ -253,6 +274,7 @@ impl SiteAnalyzer {
         if let Some(existing) = self.links.write().get_mut(&url_string) {
             existing.found_in.insert(page_link_source);
         } else {
+            let discovered_by = page_link_source.link_source_type.clone();
             let mut found_in = HashSet::new();
             found_in.insert(page_link_source);
             self.links.write().insert(
@@ -262,6 +284,11 @@ impl SiteAnalyzer {
                     link_type: link.link_type,
                     found_in,
                     result: None,
+                    depth: None,
+                    discovered_by,
+                    metadata: None,
+                    outlinks: vec![],
+                    link_metrics: None,
                 },
             );
             println!("links length: {}", self.links.read().len());
@@ -294,6 +321,11 @@ impl SiteAnalyzer {
             let registry = self.registry.read().clone();
             registry.analyze_async(&page).await?
         };
+        // After the analysis, which publishes the plugin outputs metadata reads
+        if let Some(link) = self.links.write().get_mut(&url.to_string()) {
+            link.metadata = Some(PageMetadata::from(&page));
+        }
+        self.site_runs.lock().after_page(&page, &results);
 
         // Record the page results
         self.record_page_result(
@@ -307,7 +339,7 @@ impl SiteAnalyzer {
 
         // Extract and add any new links found on the page
         let links = page.extract_links().map_err(SiteAnalyzerError::PageError)?;
-        for link in links {
+        for link in &links {
             self.add_link(
                 &link.href,
                 PageLinkSource {
@@ -317,11 +349,28 @@ impl SiteAnalyzer {
             )
             .await?;
         }
+        let outlinks = links
+            .into_iter()
+            .map(|link| Link {
+                href: Self::clean_url(link.href.clone()),
+                ..link
+            })
+            .collect();
+        if let Some(link) = self.links.write().get_mut(&url.to_string()) {
+            link.outlinks = outlinks;
+        }
 
         Ok(())
     }
 
+    // Done once the crawl is over, since pages are processed concurrently, in no
+    // particular order
+    fn assign_link_metrics(&self) {
+        LinkGraph::assign_metrics(&mut self.links.write());
+    }
+
     pub async fn crawl(&self) -> Result<CrawlResult, SiteAnalyzerError> {
+        *self.site_runs.lock() = self.registry.read().start_site_runs();
         let sitemap_urls = self.fetch_sitemap().await?;
         println!("sitemap_urls: {:#?}", sitemap_urls);
         for sitemap_url in sitemap_urls {
@@ -380,9 +429,12 @@ impl SiteAnalyzer {
             }
         }
 
+        self.assign_link_metrics();
+
         let registry = self.registry.read().clone();
+        let site_runs = std::mem::take(&mut *self.site_runs.lock());
 
-        let site_result = registry.analyze_site(self).await?;
+        let site_result = registry.analyze_site(self, &site_runs).await?;
 
         self.record_site_results(&site_result).await?;
 
@@ -457,6 +509,11 @@ mod tests {
                                 link_type: LinkType::Internal,
                                 found_in: HashSet::new(),
                                 result: None,
+                                depth: None,
+                                discovered_by: LinkSourceType::Link,
+                                metadata: None,
+                                outlinks: vec![],
+                                link_metrics: None,
                             },
                         );
                     }
@@ -464,7 +521,7 @@ mod tests {
                         local_results_clone
                             .lock()
                             .unwrap()
-                            .insert(link.url.clone(), link);
+                            .insert(link.url.clone(), *link);
                     }
                     _ => {}
                 }
@@ -516,6 +573,32 @@ mod tests {
         let base_url = format!("http://{}", addr);
         let page1 = links.get(&format!("{}/page1", base_url)).unwrap();
         assert_eq!(page1.found_in.len(), 2);
+        assert_eq!(page1.discovered_by, LinkSourceType::Sitemap);
+        let metadata = page1.metadata.as_ref().unwrap();
+        assert_eq!(metadata.status_code, Some(200));
+        assert_eq!(metadata.title.as_deref(), Some("Test Page"));
+        assert!(metadata.content_metrics.is_some());
+        let root = links.get(&format!("{}/", base_url)).unwrap();
+        assert!(
+            root.outlinks
+                .iter()
+                .any(|link| link.href == format!("{}/page1", base_url)
+                    && link.anchor_text == "Page 1")
+        );
+        for (path, depth) in [
+            ("/", Some(0)),
+            ("/page1", Some(1)),
+            ("/page5", Some(2)),
+            ("/page6", Some(3)),
+            ("/page4", Some(4)),
+            ("/page3", None),
+        ] {
+            let link = links.get(&format!("{}{}", base_url, path)).unwrap();
+            assert_eq!(link.depth, depth, "depth of {}", path);
+        }
+        let page4 = links.get(&format!("{}/page4", base_url)).unwrap();
+        let metrics = page4.link_metrics.as_ref().unwrap();
+        assert_eq!((metrics.inlinks, metrics.outlinks), (1, 0));
         for path in [
             "/", "/page1", "/page2", "/page3", "/page4", "/page5", "/page6",
         ] {
@@ -538,12 +621,12 @@ mod tests {
             results
                 .site_result
                 .iter()
-                .any(|result| result.rule_id == "meta_description_uniqueness")
+                .any(|result| result.rule_id == "uniqueness.meta_description")
         );
         let meta_description_uniqueness = results
             .site_result
             .iter()
-            .find(|result| result.rule_id == "meta_description_uniqueness")
+            .find(|result| result.rule_id == "uniqueness.meta_description")
             .unwrap();
         assert!(!meta_description_uniqueness.passed);
         let orphaned_page = results
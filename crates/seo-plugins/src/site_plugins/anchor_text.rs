@@ -0,0 +1,397 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::site_analyzer::{LinkSourceType, PageLink, SiteAnalyzer};
+use crate::utils::config::{CheckError, SiteCheckContext, SiteCheckResult};
+use crate::utils::{
+    config::{Effort, Remediation, RuleCategory, Severity, SiteRule},
+    link_parser::{Link, LinkType},
+    rule_param::RuleParams,
+    site_plugin::{SitePlugin, SiteRun},
+    text::{collapse_whitespace, shares_topic, topic_words},
+};
+
+// Anchors that say nothing about the page they point to
+const GENERIC_ANCHORS: &[&str] = &[
+    "click here",
+    "click",
+    "here",
+    "read more",
+    "more",
+    "learn more",
+    "find out more",
+    "more info",
+    "more information",
+    "details",
+    "continue",
+    "continue reading",
+    "go",
+    "link",
+    "this",
+    "this page",
+    "website",
+];
+
+#[derive(Clone)]
+pub struct AnchorTextPlugin {}
+
+impl Default for AnchorTextPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnchorTextPlugin {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+const PLUGIN_NAME: &str = "Anchor Text";
+
+impl SitePlugin for AnchorTextPlugin {
+    fn name(&self) -> &str {
+        PLUGIN_NAME
+    }
+
+    fn description(&self) -> &str {
+        "Checks the text of internal links across the site"
+    }
+
+    fn available_rules(&self) -> Vec<SiteRule> {
+        vec![
+            SiteRule {
+                id: "anchor_text.descriptive",
+                name: "Anchor text is descriptive",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that internal links don't use generic text like \"click here\" or \"read more\"",
+                default_severity: Severity::Warning,
+                category: RuleCategory::SEO,
+                passed_message: "Internal links have descriptive text",
+                failed_message: "Internal links use generic text",
+                remediation: Some(
+                    Remediation::new(
+                        "Search engines and screen reader users rely on anchor text to know what the linked page is about. \"Click here\" tells them nothing.",
+                        "Replace generic anchors with a few words describing the linked page.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<a href="/shoes/trail/">Browse our trail running shoes</a>"#)
+                    .with_reference("https://developers.google.com/search/docs/crawling-indexing/links-crawlable#anchor-text-placement")
+                ),
+                params: vec![],
+            },
+            SiteRule {
+                id: "anchor_text.not_empty",
+                name: "Links have text",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that internal links have text, or alt text on the images they wrap",
+                default_severity: Severity::Error,
+                category: RuleCategory::Accessibility,
+                passed_message: "Every internal link has text",
+                failed_message: "Internal links without text found",
+                remediation: Some(
+                    Remediation::new(
+                        "A link without text or image alt text has no name: screen readers announce just \"link\" and search engines learn nothing about the target.",
+                        "Add text to the link, or alt text to the image it wraps describing where the link goes.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<a href="/"><img src="/logo.png" alt="Example Store home"></a>"#)
+                    .with_reference("https://www.w3.org/WAI/WCAG22/Understanding/link-purpose-in-context.html")
+                ),
+                params: vec![],
+            },
+            SiteRule {
+                id: "anchor_text.consistent_targets",
+                name: "Anchor text points to one page",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that the same anchor text doesn't link to different pages",
+                default_severity: Severity::Info,
+                category: RuleCategory::SEO,
+                passed_message: "Each anchor text links to a single page",
+                failed_message: "The same anchor text links to different pages",
+                remediation: Some(Remediation::new(
+                    "Using one anchor for several pages makes them compete for the same topic and confuses visitors about where a link goes.",
+                    "Give each page its own anchor text, or point the anchors at the one page that should rank for that topic.",
+                    Effort::Medium,
+                )),
+                params: vec![],
+            },
+            SiteRule {
+                id: "anchor_text.matches_title",
+                name: "Inbound anchors match the page topic",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that at least one internal link to a page shares a word with its title",
+                default_severity: Severity::Info,
+                category: RuleCategory::SEO,
+                passed_message: "Pages are linked with anchors matching their title",
+                failed_message: "Pages are never linked with anchors matching their title",
+                remediation: Some(Remediation::new(
+                    "Anchor text is a strong hint about what a page is about. When no link uses the page's topic, that hint is lost or points elsewhere.",
+                    "Use the page's main topic in the text of at least some of the links pointing to it.",
+                    Effort::Medium,
+                )),
+                params: vec![],
+            },
+        ]
+    }
+
+    fn start_run(&self) -> Box<dyn SiteRun> {
+        Box::new(AnchorTextRun)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+// Reads the links of the crawled pages at check time
+struct AnchorTextRun;
+
+impl SiteRun for AnchorTextRun {
+    fn check(
+        &self,
+        rule: &SiteRule,
+        site: &SiteAnalyzer,
+        _params: &RuleParams,
+    ) -> Result<SiteCheckResult, CheckError> {
+        let links = site.get_links();
+        let (context, message) = match rule.id {
+            "anchor_text.descriptive" => {
+                let generic = generic_anchors(&links);
+                let message = format!("{} generic anchor texts used", generic.len());
+                (generic, message)
+            }
+            "anchor_text.not_empty" => {
+                let empty = empty_anchors(&links);
+                let message = format!("{} pages linked without text", empty.len());
+                (empty, message)
+            }
+            "anchor_text.consistent_targets" => {
+                let inconsistent = inconsistent_anchors(&links);
+                let message = format!(
+                    "{} anchor texts link to more than one page",
+                    inconsistent.len()
+                );
+                (inconsistent, message)
+            }
+            "anchor_text.matches_title" => {
+                let pages = pages_without_matching_anchor(&links);
+                let message = format!(
+                    "{} pages have no inbound anchor matching their title",
+                    pages.len()
+                );
+                return Ok(SiteCheckResult {
+                    rule_id: rule.id.to_string(),
+                    passed: pages.is_empty(),
+                    message,
+                    context: SiteCheckContext::Urls(pages),
+                });
+            }
+            _ => return Err(CheckError::UnknownRule(rule.id.to_string())),
+        };
+
+        Ok(SiteCheckResult {
+            rule_id: rule.id.to_string(),
+            passed: context.is_empty(),
+            message,
+            context: SiteCheckContext::Values(context.into_iter().collect()),
+        })
+    }
+}
+
+// Internal links of the crawled pages, as (page url, link), self links left out
+fn internal_links(links: &HashMap<String, PageLink>) -> impl Iterator<Item = (&str, &Link)> {
+    links.values().flat_map(|page| {
+        page.outlinks
+            .iter()
+            .filter(move |link| link.link_type == LinkType::Internal && link.href != page.url)
+            .map(move |link| (page.url.as_str(), link))
+    })
+}
+
+fn normalize(text: &str) -> String {
+    collapse_whitespace(text)
+        .to_lowercase()
+        .trim_matches(|c: char| !c.is_alphanumeric())
+        .to_string()
+}
+
+fn is_generic(text: &str) -> bool {
+    GENERIC_ANCHORS.contains(&text)
+}
+
+// Collects `value` under `key`, each value once and in order
+fn group(groups: &mut BTreeMap<String, Vec<String>>, key: String, value: &str) {
+    let values = groups.entry(key).or_default();
+    if !values.iter().any(|existing| existing == value) {
+        values.push(value.to_string());
+        values.sort();
+    }
+}
+
+// Generic anchor text, with the pages using it
+fn generic_anchors(links: &HashMap<String, PageLink>) -> BTreeMap<String, Vec<String>> {
+    let mut generic = BTreeMap::new();
+    for (page, link) in internal_links(links) {
+        let text = normalize(link.text().unwrap_or_default());
+        if is_generic(&text) {
+            group(&mut generic, text, page);
+        }
+    }
+    generic
+}
+
+// Targets linked without any text, with the pages linking to them
+fn empty_anchors(links: &HashMap<String, PageLink>) -> BTreeMap<String, Vec<String>> {
+    let mut empty = BTreeMap::new();
+    for (page, link) in internal_links(links) {
+        if link.text().is_none() {
+            group(&mut empty, link.href.clone(), page);
+        }
+    }
+    empty
+}
+
+// Descriptive anchor text used for more than one target, with its targets
+fn inconsistent_anchors(links: &HashMap<String, PageLink>) -> BTreeMap<String, Vec<String>> {
+    let mut targets = BTreeMap::new();
+    for (_, link) in internal_links(links) {
+        let text = normalize(link.text().unwrap_or_default());
+        if !text.is_empty() && !is_generic(&text) {
+            group(&mut targets, text, &link.href);
+        }
+    }
+    targets.retain(|_, targets| targets.len() > 1);
+    targets
+}
+
+// Pages besides the root, with a title and inbound links, none of which share a word
+// with the title
+fn pages_without_matching_anchor(links: &HashMap<String, PageLink>) -> Vec<String> {
+    let mut anchors: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (_, link) in internal_links(links) {
+        if let Some(text) = link.text() {
+            anchors.entry(link.href.as_str()).or_default().push(text);
+        }
+    }
+
+    let mut pages: Vec<String> = links
+        .values()
+        .filter(|page| {
+            !page
+                .found_in
+                .iter()
+                .any(|source| source.link_source_type == LinkSourceType::Root)
+        })
+        .filter_map(|page| {
+            let title = page.metadata.as_ref()?.title.as_deref()?;
+            let anchors = anchors.get(page.url.as_str())?;
+            if topic_words(title).is_empty() {
+                return None;
+            }
+            let matches = anchors.iter().any(|anchor| shares_topic(anchor, title));
+            (!matches).then(|| page.url.clone())
+        })
+        .collect();
+    pages.sort();
+    pages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{BASE, page, url};
+    use crate::utils::link_parser::parse_link;
+    use crate::utils::page::PageMetadata;
+
+    fn link(path: &str, text: &str) -> Link {
+        Link {
+            anchor_text: text.to_string(),
+            ..parse_link(&url(path), BASE).unwrap()
+        }
+    }
+
+    fn titled_page(path: &str, title: &str, outlinks: Vec<Link>) -> (String, PageLink) {
+        let source = if path == "/" {
+            LinkSourceType::Root
+        } else {
+            LinkSourceType::Link
+        };
+        let (url, mut page) = page(path, source, &[]);
+        page.metadata = Some(PageMetadata {
+            title: Some(title.to_string()),
+            ..Default::default()
+        });
+        page.outlinks = outlinks;
+        (url, page)
+    }
+
+    fn links() -> HashMap<String, PageLink> {
+        let image_without_alt = Link {
+            image_alt: Some(String::new()),
+            ..link("/cart", "")
+        };
+        [
+            titled_page(
+                "/",
+                "Example Store",
+                vec![
+                    link("/", "Home"),
+                    link("/shoes", "Running shoes"),
+                    link("/about", "Click here!"),
+                    link("/blog", "Read more"),
+                    image_without_alt,
+                ],
+            ),
+            titled_page(
+                "/shoes",
+                "Shoes | Example Store",
+                vec![
+                    link("/", "Home"),
+                    link("/sale", "Running shoes"),
+                    link("/about", "  click   HERE "),
+                ],
+            ),
+            titled_page("/about", "About us", vec![]),
+            titled_page("/blog", "Blog", vec![link("/cart", "")]),
+            titled_page("/sale", "Sale", vec![]),
+            titled_page("/cart", "Cart", vec![]),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn test_generic_anchors() {
+        let generic = generic_anchors(&links());
+        assert_eq!(generic.len(), 2);
+        assert_eq!(generic["click here"], vec![url("/"), url("/shoes")]);
+        assert_eq!(generic["read more"], vec![url("/")]);
+    }
+
+    #[test]
+    fn test_empty_anchors() {
+        let empty = empty_anchors(&links());
+        assert_eq!(empty.len(), 1);
+        assert_eq!(empty[&url("/cart")], vec![url("/"), url("/blog")]);
+    }
+
+    #[test]
+    fn test_inconsistent_anchors() {
+        let inconsistent = inconsistent_anchors(&links());
+        assert_eq!(inconsistent.len(), 1);
+        assert_eq!(
+            inconsistent["running shoes"],
+            vec![url("/sale"), url("/shoes")]
+        );
+    }
+
+    #[test]
+    fn test_pages_without_matching_anchor() {
+        // "/shoes" matches "Running shoes", the root is skipped and "/cart" has no
+        // inbound text
+        assert_eq!(
+            pages_without_matching_anchor(&links()),
+            vec![url("/about"), url("/blog"), url("/sale")]
+        );
+    }
+}
@@ -0,0 +1,683 @@
+use std::collections::{BTreeMap, HashMap};
+
+use url::Url;
+
+use crate::site_analyzer::{PageLink, SiteAnalyzer};
+use crate::utils::config::{CheckError, SiteCheckContext, SiteCheckResult};
+use crate::utils::{
+    config::{Effort, Remediation, RuleCategory, RuleResult, Severity, SiteRule},
+    link_parser::LinkType,
+    page::Page,
+    rule_param::RuleParams,
+    site_plugin::{SitePlugin, SiteRun},
+};
+
+#[derive(Clone)]
+pub struct CanonicalPlugin {}
+
+impl Default for CanonicalPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CanonicalPlugin {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+const PLUGIN_NAME: &str = "Canonical";
+
+const CANONICAL_REFERENCE: &str =
+    "https://developers.google.com/search/docs/crawling-indexing/consolidate-duplicate-urls";
+
+impl SitePlugin for CanonicalPlugin {
+    fn name(&self) -> &str {
+        PLUGIN_NAME
+    }
+
+    fn description(&self) -> &str {
+        "Resolves canonical URLs against the crawl"
+    }
+
+    fn available_rules(&self) -> Vec<SiteRule> {
+        vec![
+            SiteRule {
+                id: "canonical.target_ok",
+                name: "Canonical targets load",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that canonical URLs point to pages returning 200",
+                default_severity: Severity::Error,
+                category: RuleCategory::SEO,
+                passed_message: "Every canonical target returns 200",
+                failed_message: "Canonical URLs point to pages that don't return 200",
+                remediation: Some(
+                    Remediation::new(
+                        "Search engines ignore a canonical pointing to a broken page, and may drop the page that declares it along with it.",
+                        "Point the canonical at a live page, usually the page itself.",
+                        Effort::Low,
+                    )
+                    .with_reference(CANONICAL_REFERENCE),
+                ),
+                params: vec![],
+            },
+            SiteRule {
+                id: "canonical.target_not_redirected",
+                name: "Canonical targets don't redirect",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that canonical URLs don't point to redirects",
+                default_severity: Severity::Warning,
+                category: RuleCategory::SEO,
+                passed_message: "No canonical target redirects",
+                failed_message: "Canonical URLs point to redirects",
+                remediation: Some(
+                    Remediation::new(
+                        "A canonical pointing to a redirect sends mixed signals: the page names one URL as preferred while that URL names another.",
+                        "Point the canonical at the final URL of the redirect.",
+                        Effort::Low,
+                    )
+                    .with_reference(CANONICAL_REFERENCE),
+                ),
+                params: vec![],
+            },
+            SiteRule {
+                id: "canonical.target_indexable",
+                name: "Canonical targets are indexable",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that canonical URLs don't point to noindex pages",
+                default_severity: Severity::Error,
+                category: RuleCategory::SEO,
+                passed_message: "Every canonical target can be indexed",
+                failed_message: "Canonical URLs point to noindex pages",
+                remediation: Some(
+                    Remediation::new(
+                        "The canonical asks search engines to index the target instead of this page, while the target asks not to be indexed: neither ends up in search results.",
+                        "Remove noindex from the target, or point the canonical at an indexable page.",
+                        Effort::Low,
+                    )
+                    .with_reference(CANONICAL_REFERENCE),
+                ),
+                params: vec![],
+            },
+            SiteRule {
+                id: "canonical.target_crawled",
+                name: "Canonical targets were crawled",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that canonical URLs on this site point to pages found in the crawl",
+                default_severity: Severity::Warning,
+                category: RuleCategory::SEO,
+                passed_message: "Every canonical target was crawled",
+                failed_message: "Canonical URLs point to pages outside the crawl",
+                remediation: Some(Remediation::new(
+                    "A target that isn't linked from the site or listed in the sitemap is hard for search engines to find, and often a typo or a leftover.",
+                    "Link to the target from the site or list it in the sitemap, or fix the canonical URL.",
+                    Effort::Medium,
+                )),
+                params: vec![],
+            },
+            SiteRule {
+                id: "canonical.target_external",
+                name: "Canonical targets are on this site",
+                plugin_name: PLUGIN_NAME,
+                description: "Reports canonical URLs that point to another domain",
+                default_severity: Severity::Info,
+                category: RuleCategory::SEO,
+                passed_message: "Every canonical target is on this site",
+                failed_message: "Canonical URLs point to another domain",
+                remediation: Some(
+                    Remediation::new(
+                        "Search engines index the other domain's page instead of this one. That is intended for syndicated content, but often a leftover from a staging or old domain.",
+                        "If the content isn't syndicated from that domain, point the canonical to the live URL on this site.",
+                        Effort::Low,
+                    )
+                    .with_reference(CANONICAL_REFERENCE),
+                ),
+                params: vec![],
+            },
+            SiteRule {
+                id: "canonical.no_chains",
+                name: "No canonical chains",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that canonical targets don't canonicalize to yet another URL",
+                default_severity: Severity::Warning,
+                category: RuleCategory::SEO,
+                passed_message: "No canonical chains",
+                failed_message: "Canonical targets canonicalize to another URL",
+                remediation: Some(
+                    Remediation::new(
+                        "Search engines may not follow a canonical to a page which itself names another canonical, and pick a URL on their own.",
+                        "Point every page of the chain at the last URL, which should be canonical to itself.",
+                        Effort::Low,
+                    )
+                    .with_reference(CANONICAL_REFERENCE),
+                ),
+                params: vec![],
+            },
+            SiteRule {
+                id: "canonical.consistent",
+                name: "Canonical link and header agree",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that the canonical link in the HTML and the canonical Link header name the same URL",
+                default_severity: Severity::Error,
+                category: RuleCategory::SEO,
+                passed_message: "HTML and header canonicals agree",
+                failed_message: "HTML and header canonicals conflict",
+                remediation: Some(
+                    Remediation::new(
+                        "With two different canonicals, search engines can't tell which one is meant and may ignore both.",
+                        "Declare the canonical in one place, or make the Link header and the <link rel=\"canonical\"> name the same URL.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"Link: <https://example.com/shoes/trail/>; rel="canonical""#)
+                    .with_reference(CANONICAL_REFERENCE),
+                ),
+                params: vec![],
+            },
+            SiteRule {
+                id: "canonical.absolute",
+                name: "Canonical URLs are absolute",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that canonical URLs include the scheme and host",
+                default_severity: Severity::Warning,
+                category: RuleCategory::SEO,
+                passed_message: "Every canonical URL is absolute",
+                failed_message: "Relative canonical URLs found",
+                remediation: Some(
+                    Remediation::new(
+                        "Relative canonicals are resolved against whatever URL the page was reached on, so a copy on another host or protocol canonicalizes to itself.",
+                        "Use the full URL, scheme and host included.",
+                        Effort::Low,
+                    )
+                    .with_example(r#"<link rel="canonical" href="https://example.com/shoes/trail/">"#)
+                    .with_reference(CANONICAL_REFERENCE),
+                ),
+                params: vec![],
+            },
+            SiteRule {
+                id: "canonical.clusters",
+                name: "Canonical clusters",
+                plugin_name: PLUGIN_NAME,
+                description: "Lists the pages canonicalized to another URL, grouped by target",
+                default_severity: Severity::Info,
+                category: RuleCategory::SEO,
+                passed_message: "Every page is canonical to itself",
+                failed_message: "Pages are canonicalized to other URLs",
+                remediation: Some(Remediation::new(
+                    "Only the target of a cluster is indexed. Pages that ended up in a cluster by mistake, e.g. through a template setting the home page as canonical, disappear from search results.",
+                    "Check that the pages of each cluster really duplicate the target. Give the others a canonical to themselves.",
+                    Effort::Medium,
+                )),
+                params: vec![],
+            },
+        ]
+    }
+
+    fn start_run(&self) -> Box<dyn SiteRun> {
+        Box::new(CanonicalRun::default())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+// The canonicals a page declares, as written
+#[derive(Debug, Default)]
+struct PageCanonical {
+    html: Option<String>,
+    header: Option<String>,
+    noindex: bool,
+}
+
+impl PageCanonical {
+    // The canonical search engines go by, the HTML link winning over the header
+    fn target(&self, page: &str) -> Option<String> {
+        self.html
+            .as_deref()
+            .or(self.header.as_deref())
+            .and_then(|href| resolve(page, href))
+    }
+}
+
+// Canonicals per page seen so far in one crawl
+#[derive(Default)]
+struct CanonicalRun {
+    pages: HashMap<String, PageCanonical>,
+}
+
+impl SiteRun for CanonicalRun {
+    fn after_page(&mut self, page: &Page, _results: &[RuleResult]) -> Result<(), String> {
+        // The content belongs to the URL redirected to
+        if page.get_redirected() {
+            return Ok(());
+        }
+        let text = |value: Option<&str>| {
+            value
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let meta_tags = page.extract_meta_tags();
+        let robots = meta_tags.robots.unwrap_or_default();
+        let x_robots = page.get_header("x-robots-tag").unwrap_or_default();
+        self.pages.insert(
+            page.get_url().to_string(),
+            PageCanonical {
+                html: text(meta_tags.canonical.as_deref()),
+                header: text(page.get_header("link").and_then(link_header_canonical)),
+                noindex: [robots.as_str(), x_robots]
+                    .iter()
+                    .any(|value| value.to_lowercase().contains("noindex")),
+            },
+        );
+        Ok(())
+    }
+
+    fn check(
+        &self,
+        rule: &SiteRule,
+        site: &SiteAnalyzer,
+        _params: &RuleParams,
+    ) -> Result<SiteCheckResult, CheckError> {
+        let links = site.get_links();
+        let status = |target: &str| target_status(&links, site.get_url(), target);
+        let (context, message) = match rule.id {
+            "canonical.target_ok" => self.target_rule("don't return 200", |target| {
+                status(target) == TargetStatus::Error
+            })?,
+            "canonical.target_not_redirected" => self.target_rule("redirect", |target| {
+                status(target) == TargetStatus::Redirected
+            })?,
+            "canonical.target_indexable" => self.target_rule("are noindex", |target| {
+                self.pages.get(target).is_some_and(|page| page.noindex)
+            })?,
+            "canonical.target_crawled" => self.target_rule("weren't crawled", |target| {
+                status(target) == TargetStatus::NotCrawled
+            })?,
+            "canonical.target_external" => self.target_rule("are on another domain", |target| {
+                status(target) == TargetStatus::External
+            })?,
+            "canonical.no_chains" => {
+                self.require_canonicalized()?;
+                let chains = self.chains();
+                let message = format!(
+                    "{} pages are canonicalized to a URL with another canonical",
+                    chains.len()
+                );
+                (chains, message)
+            }
+            "canonical.consistent" => {
+                if !self.pages.values().any(|page| page.header.is_some()) {
+                    return Err(CheckError::NotApplicable(
+                        "No pages with a canonical Link header".to_string(),
+                    ));
+                }
+                let conflicting = self.conflicting();
+                let message = format!(
+                    "{} pages have conflicting HTML and header canonicals",
+                    conflicting.len()
+                );
+                (conflicting, message)
+            }
+            "canonical.absolute" => {
+                if !self
+                    .pages
+                    .values()
+                    .any(|page| page.html.is_some() || page.header.is_some())
+                {
+                    return Err(CheckError::NotApplicable(
+                        "No pages with a canonical URL".to_string(),
+                    ));
+                }
+                let relative = self.relative();
+                let message = format!("{} pages use a relative canonical", relative.len());
+                (relative, message)
+            }
+            "canonical.clusters" => {
+                self.require_canonicalized()?;
+                let clusters = self.targets(|_| true);
+                let message = format!(
+                    "{} pages are canonicalized to {} other URLs",
+                    clusters.values().map(Vec::len).sum::<usize>(),
+                    clusters.len()
+                );
+                (clusters, message)
+            }
+            _ => return Err(CheckError::UnknownRule(rule.id.to_string())),
+        };
+
+        Ok(SiteCheckResult {
+            rule_id: rule.id.to_string(),
+            passed: context.is_empty(),
+            message,
+            context: SiteCheckContext::Values(context.into_iter().collect()),
+        })
+    }
+}
+
+impl CanonicalRun {
+    fn require_canonicalized(&self) -> Result<(), CheckError> {
+        if self.canonicalized().is_empty() {
+            return Err(CheckError::NotApplicable(
+                "No pages canonicalized to another URL".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn target_rule(
+        &self,
+        problem: &str,
+        filter: impl Fn(&str) -> bool,
+    ) -> Result<(BTreeMap<String, Vec<String>>, String), CheckError> {
+        self.require_canonicalized()?;
+        let targets = self.targets(filter);
+        let message = format!("{} canonical targets {problem}", targets.len());
+        Ok((targets, message))
+    }
+
+    // (page, target) for each page canonicalized to another URL, as crawl keys
+    fn canonicalized(&self) -> Vec<(&str, String)> {
+        let mut canonicalized: Vec<(&str, String)> = self
+            .pages
+            .iter()
+            .filter_map(|(page, canonical)| {
+                let target = SiteAnalyzer::clean_url(canonical.target(page)?);
+                (target != *page).then_some((page.as_str(), target))
+            })
+            .collect();
+        canonicalized.sort();
+        canonicalized
+    }
+
+    // Targets matching `filter`, with the pages canonicalized to them
+    fn targets(&self, filter: impl Fn(&str) -> bool) -> BTreeMap<String, Vec<String>> {
+        let mut targets: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for (page, target) in self.canonicalized() {
+            if filter(&target) {
+                targets.entry(target).or_default().push(page.to_string());
+            }
+        }
+        targets
+    }
+
+    // Pages whose target canonicalizes elsewhere, with the URLs of the chain
+    fn chains(&self) -> BTreeMap<String, Vec<String>> {
+        self.canonicalized()
+            .into_iter()
+            .filter_map(|(page, target)| {
+                let next = self.pages.get(&target)?.target(&target)?;
+                (SiteAnalyzer::clean_url(next.clone()) != target)
+                    .then(|| (page.to_string(), vec![target, next]))
+            })
+            .collect()
+    }
+
+    // Pages whose HTML and header canonicals differ, with both
+    fn conflicting(&self) -> BTreeMap<String, Vec<String>> {
+        self.pages
+            .iter()
+            .filter_map(|(page, canonical)| {
+                let html = resolve(page, canonical.html.as_deref()?)?;
+                let header = resolve(page, canonical.header.as_deref()?)?;
+                (html != header).then(|| (page.clone(), vec![html, header]))
+            })
+            .collect()
+    }
+
+    // Pages with a relative canonical, with the canonical as written
+    fn relative(&self) -> BTreeMap<String, Vec<String>> {
+        self.pages
+            .iter()
+            .filter_map(|(page, canonical)| {
+                let relative: Vec<String> = [&canonical.html, &canonical.header]
+                    .into_iter()
+                    .flatten()
+                    .filter(|href| Url::parse(href).is_err())
+                    .cloned()
+                    .collect();
+                (!relative.is_empty()).then(|| (page.clone(), relative))
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum TargetStatus {
+    Ok,
+    Error,
+    Redirected,
+    NotCrawled,
+    // On another domain than the crawl's start URL, so never crawled
+    External,
+}
+
+fn target_status(links: &HashMap<String, PageLink>, base: &Url, target: &str) -> TargetStatus {
+    let is_external = Url::parse(target).is_ok_and(|target| target.host() != base.host());
+    let Some(link) = links.get(target) else {
+        return if is_external {
+            TargetStatus::External
+        } else {
+            TargetStatus::NotCrawled
+        };
+    };
+    match &link.result {
+        _ if link.link_type == LinkType::External => TargetStatus::External,
+        _ if link.link_type != LinkType::Internal => TargetStatus::NotCrawled,
+        None => TargetStatus::NotCrawled,
+        Some(result) if result.error => TargetStatus::Error,
+        Some(_)
+            if link
+                .metadata
+                .as_ref()
+                .is_some_and(|metadata| metadata.redirected) =>
+        {
+            TargetStatus::Redirected
+        }
+        Some(_) => TargetStatus::Ok,
+    }
+}
+
+// `href` resolved against the page, without the fragment
+fn resolve(page: &str, href: &str) -> Option<String> {
+    let mut url = Url::parse(page).ok()?.join(href).ok()?;
+    url.set_fragment(None);
+    Some(url.to_string())
+}
+
+// The target of the rel="canonical" link in a Link header, e.g.
+// `<https://example.com/>; rel="canonical", <https://example.com/feed>; rel="alternate"`
+fn link_header_canonical(value: &str) -> Option<&str> {
+    value.split(',').find_map(|link| {
+        let (target, params) = link.trim().strip_prefix('<')?.split_once('>')?;
+        let canonical = params.split(';').any(|param| {
+            param.split_once('=').is_some_and(|(name, value)| {
+                name.trim().eq_ignore_ascii_case("rel")
+                    && value
+                        .trim()
+                        .trim_matches('"')
+                        .split_whitespace()
+                        .any(|rel| rel.eq_ignore_ascii_case("canonical"))
+            })
+        });
+        canonical.then_some(target)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::site_analyzer::{LinkSourceType, PageResult};
+    use crate::test_utils::{BASE, page, url};
+    use crate::utils::page::PageMetadata;
+
+    fn canonical(html: Option<&str>, header: Option<&str>) -> PageCanonical {
+        PageCanonical {
+            html: html.map(|href| href.to_string()),
+            header: header.map(|href| href.to_string()),
+            noindex: false,
+        }
+    }
+
+    fn run() -> CanonicalRun {
+        let mut pages: HashMap<String, PageCanonical> = [
+            ("/", canonical(Some("https://example.com/"), None)),
+            ("/shoes", canonical(Some("/shoes"), None)),
+            (
+                "/shoes/red",
+                canonical(Some("https://example.com/shoes"), None),
+            ),
+            (
+                "/shoes/blue",
+                canonical(None, Some("https://example.com/shoes")),
+            ),
+            (
+                "/old-shoes",
+                canonical(Some("https://example.com/shoes/red"), None),
+            ),
+            ("/sale", canonical(Some("https://example.com/moved"), None)),
+            (
+                "/promo",
+                canonical(Some("https://example.com/private#top"), None),
+            ),
+            ("/private", canonical(None, None)),
+            (
+                "/blog",
+                canonical(Some("https://example.com/missing"), None),
+            ),
+            (
+                "/about",
+                canonical(
+                    Some("https://example.com/about"),
+                    Some("https://example.com/"),
+                ),
+            ),
+        ]
+        .into_iter()
+        .map(|(path, canonical)| (url(path), canonical))
+        .collect();
+        if let Some(private) = pages.get_mut(&url("/private")) {
+            private.noindex = true;
+        }
+        CanonicalRun { pages }
+    }
+
+    fn link(path: &str, error: bool, redirected: bool) -> (String, PageLink) {
+        let (url, mut page) = page(path, LinkSourceType::Link, &[]);
+        page.result = Some(PageResult {
+            error,
+            results: vec![],
+        });
+        page.metadata = (!error).then(|| PageMetadata {
+            redirected,
+            ..Default::default()
+        });
+        (url, page)
+    }
+
+    #[test]
+    fn test_clusters() {
+        let clusters = run().targets(|_| true);
+        assert_eq!(
+            clusters[&url("/shoes")],
+            vec![url("/shoes/blue"), url("/shoes/red")]
+        );
+        assert_eq!(clusters[&url("/private")], vec![url("/promo")]);
+        assert!(!clusters.contains_key(&url("/about")));
+        assert_eq!(clusters.len(), 5);
+    }
+
+    #[test]
+    fn test_target_status() {
+        let links: HashMap<String, PageLink> = [
+            link("/shoes", false, false),
+            link("/moved", false, true),
+            link("/private", true, false),
+        ]
+        .into_iter()
+        .collect();
+        let mut run = run();
+        run.pages.insert(
+            url("/partner"),
+            canonical(Some("https://partner.example.org/shoes"), None),
+        );
+        let base = Url::parse(BASE).unwrap();
+        let status = |status: TargetStatus| {
+            let targets = run.targets(|target| target_status(&links, &base, target) == status);
+            targets.into_keys().collect::<Vec<_>>()
+        };
+        assert_eq!(status(TargetStatus::Redirected), vec![url("/moved")]);
+        assert_eq!(status(TargetStatus::Error), vec![url("/private")]);
+        assert_eq!(
+            status(TargetStatus::NotCrawled),
+            vec![url("/missing"), url("/shoes/red")]
+        );
+        assert_eq!(
+            status(TargetStatus::External),
+            vec!["https://partner.example.org/shoes".to_string()]
+        );
+
+        let noindex = run.targets(|target| run.pages.get(target).is_some_and(|page| page.noindex));
+        assert_eq!(noindex[&url("/private")], vec![url("/promo")]);
+    }
+
+    #[test]
+    fn test_chains() {
+        let chains = run().chains();
+        assert_eq!(chains.len(), 1);
+        assert_eq!(
+            chains[&url("/old-shoes")],
+            vec![url("/shoes/red"), url("/shoes")]
+        );
+    }
+
+    #[test]
+    fn test_conflicting_and_relative() {
+        let run = run();
+        let conflicting = run.conflicting();
+        assert_eq!(conflicting.len(), 1);
+        assert_eq!(conflicting[&url("/about")], vec![url("/about"), url("/")]);
+
+        let relative = run.relative();
+        assert_eq!(relative.len(), 1);
+        assert_eq!(relative[&url("/shoes")], vec!["/shoes".to_string()]);
+    }
+
+    #[test]
+    fn test_after_page() {
+        let mut page = Page::from_html(
+            r#"<html><head>
+                <meta name="robots" content="NOINDEX, follow">
+                <link rel="canonical" href=" /shoes ">
+            </head></html>"#
+                .to_string(),
+        );
+        page.set_url(url("/shoes/red").as_str());
+        let mut run = CanonicalRun::default();
+        run.after_page(&page, &[]).unwrap();
+
+        let canonical = &run.pages[&url("/shoes/red")];
+        assert_eq!(canonical.html.as_deref(), Some("/shoes"));
+        assert!(canonical.header.is_none());
+        assert!(canonical.noindex);
+        assert_eq!(canonical.target(&url("/shoes/red")), Some(url("/shoes")));
+    }
+
+    #[test]
+    fn test_link_header_canonical() {
+        assert_eq!(
+            link_header_canonical(
+                r#"<https://example.com/feed>; rel="alternate", <https://example.com/>; rel="canonical""#
+            ),
+            Some("https://example.com/")
+        );
+        assert_eq!(
+            link_header_canonical("</shoes>;REL=Canonical"),
+            Some("/shoes")
+        );
+        assert_eq!(
+            link_header_canonical(r#"<https://example.com/feed>; rel="alternate""#),
+            None
+        );
+    }
+}
@@ -0,0 +1,344 @@
+use std::any::TypeId;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::plugins::main_text::MainTextPlugin;
+use crate::site_analyzer::SiteAnalyzer;
+use crate::utils::config::{CheckError, SiteCheckContext, SiteCheckResult};
+use crate::utils::{
+    config::{Effort, Remediation, RuleCategory, RuleResult, Severity, SiteRule},
+    content::{ContentFingerprint, MainText},
+    page::Page,
+    rule_param::{RuleParam, RuleParams},
+    site_plugin::{SitePlugin, SiteRun},
+};
+
+#[derive(Clone)]
+pub struct DuplicateContentPlugin {}
+
+impl Default for DuplicateContentPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DuplicateContentPlugin {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+const PLUGIN_NAME: &str = "Duplicate Content";
+
+impl SitePlugin for DuplicateContentPlugin {
+    fn name(&self) -> &str {
+        PLUGIN_NAME
+    }
+
+    fn description(&self) -> &str {
+        "Compares the main text of pages to find duplicate and near-duplicate content"
+    }
+
+    // Reads the `MainText` the main text plugin publishes
+    fn dependencies(&self) -> Vec<TypeId> {
+        vec![TypeId::of::<MainTextPlugin>()]
+    }
+
+    fn available_rules(&self) -> Vec<SiteRule> {
+        vec![
+            SiteRule {
+                id: "duplicate_content.exact",
+                name: "No duplicate pages",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that no two pages have the same main text",
+                default_severity: Severity::Warning,
+                category: RuleCategory::SEO,
+                passed_message: "Every page has its own content",
+                failed_message: "Some pages have the same content",
+                remediation: Some(
+                    Remediation::new(
+                        "Search engines index one page of a set of duplicates and pick it themselves, which may not be the one you want to rank.",
+                        "Remove or merge the copies and redirect them to one page, or point their canonical URL at the page to index.",
+                        Effort::Medium,
+                    )
+                    .with_example(r#"<link rel="canonical" href="https://example.com/shoes/trail/">"#)
+                    .with_reference("https://developers.google.com/search/docs/crawling-indexing/consolidate-duplicate-urls"),
+                ),
+                params: vec![],
+            },
+            SiteRule {
+                id: "duplicate_content.near",
+                name: "No near-duplicate pages",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that no two pages have almost the same main text",
+                default_severity: Severity::Info,
+                category: RuleCategory::SEO,
+                passed_message: "No near-duplicate pages",
+                failed_message: "Some pages have almost the same content",
+                remediation: Some(
+                    Remediation::new(
+                        "Pages that differ in a few words, like location or product variant pages built from one template, compete with each other and are often treated as thin content.",
+                        "Merge pages that serve the same purpose, or give each enough content of its own to stand apart.",
+                        Effort::High,
+                    )
+                    .with_reference("https://developers.google.com/search/docs/essentials/spam-policies#scaled-content"),
+                ),
+                params: vec![
+                    RuleParam::number(
+                        "min_similarity",
+                        "Min similarity",
+                        "Similarity of the main text (in %) from which two pages count as near duplicates",
+                        85.0,
+                        50.0,
+                        100.0,
+                    ),
+                    RuleParam::number(
+                        "min_words",
+                        "Min words",
+                        "Pages with fewer words of main text are left out, their fingerprints being too coarse",
+                        50.0,
+                        0.0,
+                        1000.0,
+                    ),
+                ],
+            },
+        ]
+    }
+
+    fn start_run(&self) -> Box<dyn SiteRun> {
+        Box::new(DuplicateContentRun::default())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+// Main text fingerprint per page seen so far in one crawl
+#[derive(Default)]
+struct DuplicateContentRun {
+    fingerprints: HashMap<String, ContentFingerprint>,
+}
+
+impl SiteRun for DuplicateContentRun {
+    fn after_page(&mut self, page: &Page, _results: &[RuleResult]) -> Result<(), String> {
+        // The content belongs to the URL redirected to
+        if page.get_redirected() {
+            return Ok(());
+        }
+        let text = page
+            .outputs()
+            .get::<MainText>()
+            .ok_or_else(|| format!("No main text published for {}", page.get_url()))?;
+        let fingerprint = ContentFingerprint::new(&text.0);
+        if fingerprint.words > 0 {
+            self.fingerprints
+                .insert(page.get_url().to_string(), fingerprint);
+        }
+        Ok(())
+    }
+
+    fn check(
+        &self,
+        rule: &SiteRule,
+        _site: &SiteAnalyzer,
+        params: &RuleParams,
+    ) -> Result<SiteCheckResult, CheckError> {
+        if self.fingerprints.len() < 2 {
+            return Err(CheckError::NotApplicable(
+                "Fewer than two pages with text to compare".to_string(),
+            ));
+        }
+        let (groups, message) = match rule.id {
+            "duplicate_content.exact" => {
+                let groups = self.exact_duplicates();
+                let message = format!("{} groups of pages with the same content", groups.len());
+                (groups, message)
+            }
+            "duplicate_content.near" => {
+                let min_similarity = params.number("min_similarity")?;
+                let groups = self
+                    .near_duplicates(min_similarity / 100.0, params.number("min_words")? as usize);
+                let message = format!(
+                    "{} groups of pages at least {}% similar",
+                    groups.len(),
+                    min_similarity
+                );
+                (groups, message)
+            }
+            _ => return Err(CheckError::UnknownRule(rule.id.to_string())),
+        };
+
+        Ok(SiteCheckResult {
+            rule_id: rule.id.to_string(),
+            passed: groups.is_empty(),
+            message,
+            context: SiteCheckContext::Values(groups.into_iter().map(group_context).collect()),
+        })
+    }
+}
+
+impl DuplicateContentRun {
+    // Pages with the same text, each group sorted
+    fn exact_duplicates(&self) -> Vec<Vec<String>> {
+        let mut groups: BTreeMap<u64, Vec<String>> = BTreeMap::new();
+        for (url, fingerprint) in &self.fingerprints {
+            groups
+                .entry(fingerprint.exact)
+                .or_default()
+                .push(url.clone());
+        }
+        sorted_groups(groups.into_values())
+    }
+
+    // Pages linked by a chain of pairs at least `min_similarity` similar, each group
+    // sorted. Groups where every page has the same text are left to the exact rule.
+    fn near_duplicates(&self, min_similarity: f64, min_words: usize) -> Vec<Vec<String>> {
+        let mut pages: Vec<(&String, &ContentFingerprint)> = self
+            .fingerprints
+            .iter()
+            .filter(|(_, fingerprint)| fingerprint.words >= min_words)
+            .collect();
+        pages.sort_by_key(|(url, _)| *url);
+
+        // Union-find over the page indices
+        let mut parents: Vec<usize> = (0..pages.len()).collect();
+        fn root(parents: &mut [usize], mut index: usize) -> usize {
+            while parents[index] != index {
+                parents[index] = parents[parents[index]];
+                index = parents[index];
+            }
+            index
+        }
+        for a in 0..pages.len() {
+            for b in a + 1..pages.len() {
+                if pages[a].1.similarity(pages[b].1) >= min_similarity {
+                    let (root_a, root_b) = (root(&mut parents, a), root(&mut parents, b));
+                    parents[root_b] = root_a;
+                }
+            }
+        }
+
+        let mut groups: BTreeMap<usize, Vec<(&String, &ContentFingerprint)>> = BTreeMap::new();
+        for (index, page) in pages.iter().enumerate() {
+            groups
+                .entry(root(&mut parents, index))
+                .or_default()
+                .push(*page);
+        }
+        sorted_groups(
+            groups
+                .into_values()
+                .filter(|group| {
+                    group
+                        .iter()
+                        .any(|(_, fingerprint)| fingerprint.exact != group[0].1.exact)
+                })
+                .map(|group| group.into_iter().map(|(url, _)| url.clone()).collect()),
+        )
+    }
+}
+
+// Groups of at least two pages, URLs sorted
+fn sorted_groups(groups: impl Iterator<Item = Vec<String>>) -> Vec<Vec<String>> {
+    let mut groups: Vec<Vec<String>> = groups
+        .filter(|group| group.len() > 1)
+        .map(|mut group| {
+            group.sort();
+            group
+        })
+        .collect();
+    groups.sort();
+    groups
+}
+
+// A group as its first URL, with the other URLs
+fn group_context(mut group: Vec<String>) -> (String, Vec<String>) {
+    let first = group.remove(0);
+    (first, group)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::page_plugin::SeoPlugin;
+
+    const BASE: &str = "https://example.com";
+
+    const ARTICLE: &str = "Trail running shoes need a grippy outsole, a protective toe cap and enough \
+        cushioning for long descents. We tested twelve pairs on rocky and muddy trails over three \
+        months, measuring grip, comfort, weight and durability. The lightest pairs wore out after \
+        two hundred kilometres, while the heavier ones still had most of their tread left.";
+
+    const RETURNS: &str = "Our return policy lets you send back unworn shoes within thirty days of \
+        delivery. Print the label from your account, pack the shoes in their original box and \
+        drop the parcel at any post office. Refunds reach your card within a week.";
+
+    fn run(pages: &[(&str, &str)]) -> DuplicateContentRun {
+        let mut run = DuplicateContentRun::default();
+        for (path, body) in pages {
+            let mut page = Page::from_html(format!(
+                "<html><body><nav>Shoes Returns About</nav><main>{body}</main><footer>Example Store</footer></body></html>"
+            ));
+            page.set_url(format!("{BASE}{path}").as_str());
+            MainTextPlugin::new().derive_outputs(&page).unwrap();
+            run.after_page(&page, &[]).unwrap();
+        }
+        run
+    }
+
+    fn url(path: &str) -> String {
+        format!("{BASE}{path}")
+    }
+
+    #[test]
+    fn test_exact_duplicates() {
+        let run = run(&[
+            ("/shoes", ARTICLE),
+            ("/shoes?sort=price", ARTICLE),
+            (
+                "/print/shoes",
+                &format!("<p>{}</p>", ARTICLE.to_uppercase()),
+            ),
+            ("/returns", RETURNS),
+            ("/empty", ""),
+        ]);
+        assert_eq!(run.fingerprints.len(), 4);
+        assert_eq!(
+            run.exact_duplicates(),
+            vec![vec![
+                url("/print/shoes"),
+                url("/shoes"),
+                url("/shoes?sort=price")
+            ]]
+        );
+        assert!(run.near_duplicates(0.9, 0).is_empty());
+    }
+
+    #[test]
+    fn test_near_duplicates() {
+        let berlin = ARTICLE.replace("rocky and muddy", "rocky Berlin");
+        let munich = ARTICLE.replace("twelve", "ten");
+        let run = run(&[
+            ("/shoes", ARTICLE),
+            ("/shoes/berlin", &berlin),
+            ("/shoes/munich", &munich),
+            ("/returns", RETURNS),
+        ]);
+        assert!(run.exact_duplicates().is_empty());
+        assert_eq!(
+            run.near_duplicates(0.85, 50),
+            vec![vec![
+                url("/shoes"),
+                url("/shoes/berlin"),
+                url("/shoes/munich")
+            ]]
+        );
+        // RETURNS is too short to compare
+        assert!(
+            run.near_duplicates(0.0, 50)
+                .iter()
+                .all(|group| { !group.contains(&url("/returns")) })
+        );
+        assert!(run.near_duplicates(0.85, 100).is_empty());
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+
+use crate::site_analyzer::{LinkSourceType, PageLink, SiteAnalyzer};
+use crate::utils::config::{CheckError, SiteCheckContext, SiteCheckResult};
+use crate::utils::{
+    config::{Effort, Remediation, RuleCategory, Severity, SiteRule},
+    link_graph::PageLinkMetrics,
+    rule_param::{RuleParam, RuleParams},
+    site_plugin::{SitePlugin, SiteRun},
+};
+
+#[derive(Clone)]
+pub struct LinkGraphPlugin {}
+
+impl Default for LinkGraphPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LinkGraphPlugin {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+const PLUGIN_NAME: &str = "Link Graph";
+
+impl SitePlugin for LinkGraphPlugin {
+    fn name(&self) -> &str {
+        PLUGIN_NAME
+    }
+
+    fn description(&self) -> &str {
+        "Checks how pages are linked together: click depth, link score, dead ends and weakly linked pages"
+    }
+
+    fn available_rules(&self) -> Vec<SiteRule> {
+        vec![
+            SiteRule {
+                id: "link_graph.deep_pages",
+                name: "Important pages are close to the start page",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that pages with a high internal link score are reachable within a few clicks",
+                default_severity: Severity::Warning,
+                category: RuleCategory::SEO,
+                passed_message: "Important pages are within reach of the start page",
+                failed_message: "Important pages are too many clicks from the start page",
+                remediation: Some(
+                    Remediation::new(
+                        "Pages buried many clicks deep are crawled less often and get less link authority, however much the site links to them.",
+                        "Link to these pages from the navigation, the start page or a hub page, so they are reachable within a few clicks.",
+                        Effort::Medium,
+                    )
+                    .with_reference("https://developers.google.com/search/docs/crawling-indexing/links-crawlable")
+                ),
+                params: vec![
+                    RuleParam::number(
+                        "max_depth",
+                        "Max clicks",
+                        "Clicks from the start page a page may be at",
+                        3.0,
+                        1.0,
+                        20.0,
+                    ),
+                    RuleParam::number(
+                        "min_link_score",
+                        "Min link score",
+                        "Link score (0-100, internal PageRank relative to the strongest page) from which a page counts as important",
+                        25.0,
+                        0.0,
+                        100.0,
+                    ),
+                ],
+            },
+            SiteRule {
+                id: "link_graph.dead_ends",
+                name: "No dead-end pages",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that every crawled page links to at least one other internal page",
+                default_severity: Severity::Info,
+                category: RuleCategory::SEO,
+                passed_message: "Every page links to other pages of the site",
+                failed_message: "Some pages don't link to any other page of the site",
+                remediation: Some(Remediation::new(
+                    "Dead-end pages leave visitors and crawlers nowhere to go and stop link authority from flowing on.",
+                    "Add navigation or contextual links to related pages, e.g. a breadcrumb or a related articles section.",
+                    Effort::Low,
+                )),
+                params: vec![],
+            },
+            SiteRule {
+                id: "link_graph.near_orphans",
+                name: "Pages have enough inlinks",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that pages are linked from more than a handful of other pages",
+                default_severity: Severity::Info,
+                category: RuleCategory::SEO,
+                passed_message: "Pages are linked from enough other pages",
+                failed_message: "Some pages are linked from too few other pages",
+                remediation: Some(
+                    Remediation::new(
+                        "A page with a single internal link is one edit away from being orphaned and gets little link authority.",
+                        "Link to these pages from other relevant pages, e.g. category pages, related content or the navigation.",
+                        Effort::Medium,
+                    )
+                    .with_reference("https://developers.google.com/search/docs/crawling-indexing/links-crawlable")
+                ),
+                params: vec![RuleParam::number(
+                    "max_inlinks",
+                    "Max inlinks",
+                    "Pages linked from this many other pages or fewer are reported",
+                    1.0,
+                    1.0,
+                    100.0,
+                )],
+            },
+        ]
+    }
+
+    fn start_run(&self) -> Box<dyn SiteRun> {
+        Box::new(LinkGraphRun::default())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+// Reads the depth and link metrics the analyzer assigned to each page after the crawl
+#[derive(Default)]
+struct LinkGraphRun {}
+
+impl SiteRun for LinkGraphRun {
+    fn check(
+        &self,
+        rule: &SiteRule,
+        site: &SiteAnalyzer,
+        params: &RuleParams,
+    ) -> Result<SiteCheckResult, CheckError> {
+        let links = site.get_links();
+        let (urls, message) = match rule.id {
+            "link_graph.deep_pages" => {
+                let max_depth = params.number("max_depth")? as u32;
+                let urls = deep_pages(&links, max_depth, params.number("min_link_score")?);
+                let message = format!(
+                    "{} important pages more than {} clicks from the start page",
+                    urls.len(),
+                    max_depth
+                );
+                (urls, message)
+            }
+            "link_graph.dead_ends" => {
+                let urls = dead_ends(&links);
+                let message = format!("{} pages link to no other page of the site", urls.len());
+                (urls, message)
+            }
+            "link_graph.near_orphans" => {
+                let max_inlinks = params.number("max_inlinks")? as u32;
+                let urls = near_orphans(&links, max_inlinks);
+                let message = format!(
+                    "{} pages linked from {} other pages or fewer",
+                    urls.len(),
+                    max_inlinks
+                );
+                (urls, message)
+            }
+            _ => return Err(CheckError::UnknownRule(rule.id.to_string())),
+        };
+
+        Ok(SiteCheckResult {
+            rule_id: rule.id.to_string(),
+            passed: urls.is_empty(),
+            message,
+            context: SiteCheckContext::Urls(urls),
+        })
+    }
+}
+
+// Sorted URLs of the internal pages whose metrics pass `filter`
+fn urls(
+    links: &HashMap<String, PageLink>,
+    filter: impl Fn(&PageLink, &PageLinkMetrics) -> bool,
+) -> Vec<String> {
+    let mut urls: Vec<String> = links
+        .iter()
+        .filter(|(_, link)| {
+            link.link_metrics
+                .as_ref()
+                .is_some_and(|metrics| filter(link, metrics))
+        })
+        .map(|(url, _)| url.clone())
+        .collect();
+    urls.sort();
+    urls
+}
+
+// Pages more than `max_depth` clicks from the root with at least `min_link_score`
+fn deep_pages(
+    links: &HashMap<String, PageLink>,
+    max_depth: u32,
+    min_link_score: f64,
+) -> Vec<String> {
+    urls(links, |link, metrics| {
+        link.depth.is_some_and(|depth| depth > max_depth) && metrics.link_score >= min_link_score
+    })
+}
+
+// Crawled pages that link to no other internal page. Pages that failed to load have
+// no known outlinks.
+fn dead_ends(links: &HashMap<String, PageLink>) -> Vec<String> {
+    urls(links, |link, metrics| {
+        link.result.as_ref().is_some_and(|result| !result.error) && metrics.outlinks == 0
+    })
+}
+
+// Pages besides the root linked from at least one and at most `max_inlinks` pages.
+// Pages without inlinks are orphans and have their own rule.
+fn near_orphans(links: &HashMap<String, PageLink>, max_inlinks: u32) -> Vec<String> {
+    urls(links, |link, metrics| {
+        let is_root = link
+            .found_in
+            .iter()
+            .any(|source| source.link_source_type == LinkSourceType::Root);
+        !is_root && metrics.inlinks > 0 && metrics.inlinks <= max_inlinks
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{page, url};
+    use crate::utils::link_graph::LinkGraph;
+
+    #[test]
+    fn test_link_graph_rules() {
+        use LinkSourceType::*;
+
+        let mut links: HashMap<String, PageLink> = [
+            page("/", Root, &["/", "/a", "/a", "/b"]),
+            page("/a", Link, &["/", "/b"]),
+            page("/b", Link, &["/", "/a", "/c"]),
+            page("/c", Link, &["/d"]),
+            page("/d", Link, &[]),
+            page("/sitemap-only", Sitemap, &[]),
+        ]
+        .into_iter()
+        .collect();
+        // Nothing is reported before the metrics are assigned
+        assert!(dead_ends(&links).is_empty());
+
+        LinkGraph::assign_metrics(&mut links);
+        assert_eq!(deep_pages(&links, 2, 0.0), vec![url("/d")]);
+        assert!(deep_pages(&links, 2, 100.0).is_empty());
+        assert_eq!(dead_ends(&links), vec![url("/d"), url("/sitemap-only")]);
+        assert_eq!(near_orphans(&links, 1), vec![url("/c"), url("/d")]);
+    }
+}
@@ -1,4 +1,12 @@
-pub mod meta_description;
+pub mod anchor_text;
+pub mod canonical;
+pub mod duplicate_content;
+pub mod link_graph;
 pub mod orphaned_page;
+pub mod uniqueness;
 
-pub use meta_description::MetaDescriptionSitePlugin;
+pub use anchor_text::AnchorTextPlugin;
+pub use canonical::CanonicalPlugin;
+pub use duplicate_content::DuplicateContentPlugin;
+pub use link_graph::LinkGraphPlugin;
+pub use uniqueness::{UniqueField, UniquenessPlugin};
@@ -1,12 +1,10 @@
-use std::sync::{Arc, Mutex as StdMutex};
-
 use crate::site_analyzer::{LinkSourceType, SiteAnalyzer};
 
-use crate::utils::config::{SiteCheckContext, SiteCheckResult};
+use crate::utils::config::{CheckError, SiteCheckContext, SiteCheckResult};
 use crate::utils::{
-    config::{RuleCategory, RuleResult, Severity, SiteRule},
-    page::Page,
-    site_plugin::SitePlugin,
+    config::{Effort, Remediation, RuleCategory, Severity, SiteRule},
+    rule_param::RuleParams,
+    site_plugin::{SitePlugin, SiteRun},
 };
 
 #[derive(Clone)]
@@ -35,14 +33,6 @@ impl SitePlugin for OrphanedPagePlugin {
         "Check if pages are found only in sitemap but not in links"
     }
 
-    fn after_page_hook(
-        &self,
-        _page: Arc<StdMutex<Page>>,
-        _results: &Vec<RuleResult>,
-    ) -> Result<(), String> {
-        Ok(())
-    }
-
     fn available_rules(&self) -> Vec<SiteRule> {
         vec![SiteRule {
             id: "orphaned_page.check",
@@ -53,9 +43,31 @@ impl SitePlugin for OrphanedPagePlugin {
             category: RuleCategory::SEO,
             passed_message: "No orphaned pages found",
             failed_message: "Orphaned pages found",
+            remediation: Some(
+                Remediation::new(
+                    "Pages only listed in the sitemap get no link authority from the rest of the site and are hard for visitors to find.",
+                    "Link to each orphaned page from a relevant page, e.g. a category page or the navigation, or remove it from the sitemap if it is obsolete.",
+                    Effort::Medium,
+                )
+                .with_reference("https://developers.google.com/search/docs/crawling-indexing/links-crawlable")
+            ),
+            params: vec![],
         }]
     }
 
+    fn start_run(&self) -> Box<dyn SiteRun> {
+        Box::new(OrphanedPageRun)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+// Reads the crawled links at check time, nothing to accumulate per page
+struct OrphanedPageRun;
+
+impl SiteRun for OrphanedPageRun {
     // async fn pre_analyze(&self, site: &SiteAnalyzer<'_>) -> Result<(), String> {
     //     let site = site.clone();
     //     let links = site.links.lock().await;
@@ -66,7 +78,12 @@ impl SitePlugin for OrphanedPagePlugin {
     //     // });
     //     Ok(())
     // }
-    fn check(&self, rule: &SiteRule, site: &SiteAnalyzer) -> SiteCheckResult {
+    fn check(
+        &self,
+        rule: &SiteRule,
+        site: &SiteAnalyzer,
+        _params: &RuleParams,
+    ) -> Result<SiteCheckResult, CheckError> {
         let links = site.get_links();
         let orphaned_pages = links
             .iter()
@@ -99,22 +116,13 @@ impl SitePlugin for OrphanedPagePlugin {
         // println!("Orphaned pages: {:#?}", orphaned_pages);
 
         match rule.id {
-            "orphaned_page.check" => SiteCheckResult {
+            "orphaned_page.check" => Ok(SiteCheckResult {
                 rule_id: rule.id.to_string(),
                 passed: orphaned_pages_count == 0,
                 message: format!("Orphaned pages: {}", orphaned_pages_count),
                 context: SiteCheckContext::Urls(orphaned_pages),
-            },
-            _ => SiteCheckResult {
-                rule_id: rule.id.to_string(),
-                passed: false,
-                message: "Unknown rule".to_string(),
-                context: SiteCheckContext::Empty,
-            },
+            }),
+            _ => Err(CheckError::UnknownRule(rule.id.to_string())),
         }
     }
-
-    fn as_any(&self) -> &dyn std::any::Any {
-        self
-    }
 }
@@ -0,0 +1,487 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::site_analyzer::SiteAnalyzer;
+use crate::utils::config::{CheckError, SiteCheckContext, SiteCheckResult};
+use crate::utils::{
+    config::{Effort, Remediation, RuleCategory, RuleResult, Severity, SiteRule},
+    page::{Page, PageError},
+    rule_param::{RuleParam, RuleParams},
+    site_plugin::{SitePlugin, SiteRun},
+    text::{collapse_whitespace, duplicate_texts},
+};
+
+// A page field that should differ from page to page, checked by one rule. The
+// rule fails when pages share a value, and reports each shared value with its URLs.
+#[derive(Clone)]
+pub struct UniqueField {
+    pub rule: SiteRule,
+    // What the field is called in messages, e.g. "title"
+    pub label: &'static str,
+    // The field's values on a page, blank values are ignored
+    pub values: fn(&Page) -> Result<Vec<String>, PageError>,
+}
+
+#[derive(Clone)]
+pub struct UniquenessPlugin {
+    fields: Vec<UniqueField>,
+}
+
+impl Default for UniquenessPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UniquenessPlugin {
+    pub fn new() -> Self {
+        Self {
+            fields: default_fields(),
+        }
+    }
+
+    pub fn with_field(mut self, field: UniqueField) -> Self {
+        self.fields.push(field);
+        self
+    }
+}
+
+const PLUGIN_NAME: &str = "Uniqueness";
+
+// Lets a site tolerate some shared values, e.g. paginated archives sharing a title
+fn min_unique_percent(label: &str) -> RuleParam {
+    RuleParam::number(
+        "min_unique_percent",
+        "Min unique (%)",
+        &format!("Share of pages whose {label} no other page uses"),
+        100.0,
+        0.0,
+        100.0,
+    )
+}
+
+fn default_fields() -> Vec<UniqueField> {
+    vec![
+        UniqueField {
+            rule: SiteRule {
+                id: "uniqueness.title",
+                name: "Title is unique across pages",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that no two pages use the same title",
+                default_severity: Severity::Warning,
+                category: RuleCategory::SEO,
+                passed_message: "Every title is unique across pages",
+                failed_message: "Some pages share the same title",
+                remediation: Some(
+                    Remediation::new(
+                        "The title is the headline of the search result. Pages with the same title look like duplicates, compete for the same searches, and searchers can't tell them apart.",
+                        "Give each page a title naming what is specific to it, e.g. the product, the category or the article's subject, before the site name.",
+                        Effort::Medium,
+                    )
+                    .with_reference("https://developers.google.com/search/docs/appearance/title-link"),
+                ),
+                params: vec![min_unique_percent("title")],
+            },
+            label: "title",
+            values: |page| Ok(page.extract_meta_tags().title.into_iter().collect()),
+        },
+        UniqueField {
+            rule: SiteRule {
+                id: "uniqueness.meta_description",
+                name: "Meta Description Uniqueness",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that no two pages use the same meta description",
+                default_severity: Severity::Warning,
+                category: RuleCategory::SEO,
+                passed_message: "Meta description is unique across pages",
+                failed_message: "Meta description is not unique across pages",
+                remediation: Some(
+                    Remediation::new(
+                        "Pages sharing a meta description look identical in search results, so searchers can't tell which one they need.",
+                        "Write a description for each page that summarizes what is specific to that page.",
+                        Effort::High,
+                    )
+                    .with_reference("https://developers.google.com/search/docs/appearance/snippet"),
+                ),
+                params: vec![min_unique_percent("meta description")],
+            },
+            label: "meta description",
+            values: |page| Ok(page.extract_meta_tags().description.into_iter().collect()),
+        },
+        UniqueField {
+            rule: SiteRule {
+                id: "uniqueness.h1",
+                name: "h1 is unique across pages",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that no two pages use the same h1 text",
+                default_severity: Severity::Warning,
+                category: RuleCategory::SEO,
+                passed_message: "Every h1 is unique across pages",
+                failed_message: "Some pages share the same h1",
+                remediation: Some(Remediation::new(
+                    "Pages with the same main heading look like duplicates and compete with each other for the same searches.",
+                    "Give each page an h1 that names what is specific to it, e.g. the product, the category or the article's subject.",
+                    Effort::Medium,
+                )),
+                params: vec![min_unique_percent("h1")],
+            },
+            label: "h1",
+            values: |page| {
+                Ok(page
+                    .extract_headings()?
+                    .into_iter()
+                    .filter(|heading| heading.level == 1)
+                    .map(|heading| heading.text)
+                    .collect())
+            },
+        },
+        UniqueField {
+            rule: SiteRule {
+                id: "uniqueness.og_title",
+                name: "og:title is unique across pages",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that no two pages use the same og:title",
+                default_severity: Severity::Info,
+                category: RuleCategory::SEO,
+                passed_message: "Every og:title is unique across pages",
+                failed_message: "Some pages share the same og:title",
+                remediation: Some(Remediation::new(
+                    "Shared links to different pages with the same og:title look identical in feeds, so people can't tell what they'll get.",
+                    "Give each page an og:title that names what is specific to it. A template that sets the site name as og:title is the usual cause.",
+                    Effort::Medium,
+                )),
+                params: vec![min_unique_percent("og:title")],
+            },
+            label: "og:title",
+            values: |page| {
+                Ok(page
+                    .extract_meta_tags()
+                    .og_tags
+                    .get("title")
+                    .cloned()
+                    .into_iter()
+                    .collect())
+            },
+        },
+        UniqueField {
+            rule: SiteRule {
+                id: "uniqueness.canonical",
+                name: "Canonical URL is unique across pages",
+                plugin_name: PLUGIN_NAME,
+                description: "Checks that no two pages declare the same canonical URL",
+                default_severity: Severity::Info,
+                category: RuleCategory::SEO,
+                passed_message: "Every canonical URL is declared by one page",
+                failed_message: "Some pages declare the same canonical URL",
+                remediation: Some(
+                    Remediation::new(
+                        "Only the canonical URL of a group is indexed. When distinct pages share one, e.g. because a template points every page at the home page, the others drop out of search results.",
+                        "Keep shared canonicals for true duplicates such as tracking or sorting variants, and give every other page a canonical to itself.",
+                        Effort::Low,
+                    )
+                    .with_reference("https://developers.google.com/search/docs/crawling-indexing/consolidate-duplicate-urls"),
+                ),
+                params: vec![min_unique_percent("canonical URL")],
+            },
+            label: "canonical URL",
+            values: |page| {
+                Ok(page
+                    .extract_meta_tags()
+                    .canonical
+                    .and_then(|canonical| page.get_url().join(canonical.trim()).ok())
+                    .map(|mut canonical| {
+                        canonical.set_fragment(None);
+                        canonical.to_string()
+                    })
+                    .into_iter()
+                    .collect())
+            },
+        },
+    ]
+}
+
+impl SitePlugin for UniquenessPlugin {
+    fn name(&self) -> &str {
+        PLUGIN_NAME
+    }
+
+    fn description(&self) -> &str {
+        "Checks that titles, descriptions, h1s and canonical URLs differ from page to page"
+    }
+
+    fn available_rules(&self) -> Vec<SiteRule> {
+        self.fields.iter().map(|field| field.rule.clone()).collect()
+    }
+
+    fn start_run(&self) -> Box<dyn SiteRun> {
+        Box::new(UniquenessRun {
+            fields: self.fields.clone(),
+            values: HashMap::new(),
+        })
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+// Values per rule and page seen so far in one crawl
+struct UniquenessRun {
+    fields: Vec<UniqueField>,
+    values: HashMap<&'static str, HashMap<String, Vec<String>>>,
+}
+
+impl SiteRun for UniquenessRun {
+    fn after_page(&mut self, page: &Page, _results: &[RuleResult]) -> Result<(), String> {
+        // The content belongs to the URL redirected to
+        if page.get_redirected() {
+            return Ok(());
+        }
+        // A field that can't be read doesn't keep the others from being recorded
+        let mut errors = vec![];
+        for field in &self.fields {
+            let values: Vec<String> = match (field.values)(page) {
+                Ok(values) => values
+                    .iter()
+                    .map(|value| collapse_whitespace(value))
+                    .filter(|value| !value.is_empty())
+                    .collect(),
+                Err(error) => {
+                    errors.push(format!("{}: {}", field.label, error));
+                    continue;
+                }
+            };
+            if !values.is_empty() {
+                self.values
+                    .entry(field.rule.id)
+                    .or_default()
+                    .insert(page.get_url().to_string(), values);
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join(", "))
+        }
+    }
+
+    fn check(
+        &self,
+        rule: &SiteRule,
+        _site: &SiteAnalyzer,
+        params: &RuleParams,
+    ) -> Result<SiteCheckResult, CheckError> {
+        let field = self
+            .fields
+            .iter()
+            .find(|field| field.rule.id == rule.id)
+            .ok_or(CheckError::UnknownRule(rule.id.to_string()))?;
+        let values = self
+            .values
+            .get(rule.id)
+            .ok_or(CheckError::NotApplicable(format!(
+                "No pages with a {}",
+                field.label
+            )))?;
+
+        let duplicates = duplicate_texts(values);
+        let duplicated_pages: HashSet<&String> = duplicates.values().flatten().collect();
+        let unique_percent =
+            (values.len() - duplicated_pages.len()) as f64 / values.len() as f64 * 100.0;
+        Ok(SiteCheckResult {
+            rule_id: rule.id.to_string(),
+            passed: unique_percent >= params.number("min_unique_percent")?,
+            message: if duplicates.is_empty() {
+                format!("Every {} is unique across pages", field.label)
+            } else {
+                format!(
+                    "{} pages share a {} with another page ({:.0}% unique)",
+                    duplicated_pages.len(),
+                    field.label,
+                    unique_percent
+                )
+            },
+            context: SiteCheckContext::Values(duplicates),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::config::RuleConfig;
+    use crate::utils::rule_param::RuleParamValue;
+
+    const BASE: &str = "https://example.com";
+
+    fn url(path: &str) -> String {
+        format!("{BASE}{path}")
+    }
+
+    fn run(plugin: &UniquenessPlugin, pages: &[(&str, &str)]) -> Box<dyn SiteRun> {
+        let mut run = plugin.start_run();
+        for (path, head) in pages {
+            let mut page = Page::from_html(format!("<html><head>{head}</head></html>"));
+            page.set_url(url(path).as_str());
+            run.after_page(&page, &[]).unwrap();
+        }
+        run
+    }
+
+    fn check(
+        plugin: &UniquenessPlugin,
+        run: &dyn SiteRun,
+        rule_id: &str,
+        config: &RuleConfig,
+    ) -> Result<SiteCheckResult, CheckError> {
+        let rule = plugin
+            .available_rules()
+            .into_iter()
+            .find(|rule| rule.id == rule_id)
+            .unwrap();
+        let site = SiteAnalyzer::new_with_default(BASE).unwrap();
+        let params = RuleParams::resolve(rule.id, &rule.params, config).unwrap();
+        run.check(&rule, &site, &params)
+    }
+
+    #[test]
+    fn test_duplicate_groups() {
+        let plugin = UniquenessPlugin::new();
+        let run = run(
+            &plugin,
+            &[
+                (
+                    "/",
+                    r#"<title>Example Store</title><link rel="canonical" href="/">"#,
+                ),
+                (
+                    "/shoes",
+                    r#"<title>Shoes |  Example Store</title><link rel="canonical" href="https://example.com/shoes">"#,
+                ),
+                (
+                    "/shoes?page=2",
+                    r#"<title>Shoes | Example Store</title><link rel="canonical" href="/shoes#top">"#,
+                ),
+                ("/about", "<title>About us</title>"),
+                ("/blank", "<title> </title>"),
+            ],
+        );
+        let config = RuleConfig::new();
+
+        let titles = check(&plugin, run.as_ref(), "uniqueness.title", &config).unwrap();
+        assert!(!titles.passed);
+        assert_eq!(
+            titles.message,
+            "2 pages share a title with another page (50% unique)"
+        );
+        let SiteCheckContext::Values(groups) = titles.context else {
+            panic!("expected values");
+        };
+        assert_eq!(
+            groups["Shoes | Example Store"],
+            vec![url("/shoes"), url("/shoes?page=2")]
+        );
+
+        let canonicals = check(&plugin, run.as_ref(), "uniqueness.canonical", &config).unwrap();
+        let SiteCheckContext::Values(groups) = canonicals.context else {
+            panic!("expected values");
+        };
+        assert_eq!(groups.len(), 1);
+        assert_eq!(
+            groups[&url("/shoes")],
+            vec![url("/shoes"), url("/shoes?page=2")]
+        );
+
+        assert!(matches!(
+            check(
+                &plugin,
+                run.as_ref(),
+                "uniqueness.meta_description",
+                &config
+            ),
+            Err(CheckError::NotApplicable(_))
+        ));
+    }
+
+    #[test]
+    fn test_min_unique_percent() {
+        let plugin = UniquenessPlugin::new();
+        let run = run(
+            &plugin,
+            &[
+                ("/", "<title>Home</title>"),
+                ("/blog", "<title>Blog</title>"),
+                ("/blog/2", "<title>Blog</title>"),
+                ("/about", "<title>About</title>"),
+            ],
+        );
+        let mut config = RuleConfig::new();
+        config.set_param(
+            "uniqueness.title",
+            "min_unique_percent",
+            RuleParamValue::Number(50.0),
+        );
+        let result = check(&plugin, run.as_ref(), "uniqueness.title", &config).unwrap();
+        assert!(result.passed);
+        assert!(matches!(result.context, SiteCheckContext::Values(groups) if groups.len() == 1));
+    }
+
+    #[test]
+    fn test_custom_field() {
+        let plugin = UniquenessPlugin::new().with_field(UniqueField {
+            rule: SiteRule {
+                id: "uniqueness.keywords",
+                params: vec![min_unique_percent("keywords")],
+                ..default_fields()[0].rule.clone()
+            },
+            label: "keywords",
+            values: |page| Ok(page.extract_meta_tags().keywords.into_iter().collect()),
+        });
+        let run = run(
+            &plugin,
+            &[
+                ("/", r#"<meta name="keywords" content="shoes">"#),
+                ("/shoes", r#"<meta name="keywords" content="Shoes">"#),
+            ],
+        );
+        let result = check(
+            &plugin,
+            run.as_ref(),
+            "uniqueness.keywords",
+            &RuleConfig::new(),
+        )
+        .unwrap();
+        assert!(!result.passed);
+    }
+
+    #[test]
+    fn test_field_error_keeps_other_fields() {
+        let plugin = UniquenessPlugin::new().with_field(UniqueField {
+            rule: SiteRule {
+                id: "uniqueness.broken",
+                ..default_fields()[0].rule.clone()
+            },
+            label: "broken",
+            values: |_| Err(PageError::ConfigNotSet),
+        });
+        let mut run = plugin.start_run();
+        let mut page = Page::from_html("<html><head><title>Home</title></head></html>".to_string());
+        page.set_url(url("/").as_str());
+        assert!(
+            run.after_page(&page, &[])
+                .unwrap_err()
+                .starts_with("broken: ")
+        );
+        let mut page = Page::from_html("<html><head><title>Home</title></head></html>".to_string());
+        page.set_url(url("/about").as_str());
+        let _ = run.after_page(&page, &[]);
+
+        let result = check(
+            &plugin,
+            run.as_ref(),
+            "uniqueness.title",
+            &RuleConfig::new(),
+        )
+        .unwrap();
+        assert!(!result.passed);
+    }
+}
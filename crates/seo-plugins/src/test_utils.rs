@@ -0,0 +1,101 @@
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Response, Server, StatusCode};
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use tokio::net::TcpListener;
+
+use crate::site_analyzer::{LinkSourceType, PageLink, PageLinkSource, PageResult};
+use crate::utils::config::{RuleConfig, RuleResult, RuleStatus};
+use crate::utils::link_parser::{LinkType, parse_link};
+use crate::utils::page_plugin::SeoPlugin;
+
+pub const BASE: &str = "https://example.com";
+
+pub fn url(path: &str) -> String {
+    format!("{BASE}{path}")
+}
+
+// A config with every rule of `plugin` enabled
+pub fn all_rules_config(plugin: &dyn SeoPlugin) -> RuleConfig {
+    let mut config = RuleConfig::new();
+    for rule in plugin.rule_displays() {
+        config.enable_rule(rule.id);
+    }
+    config
+}
+
+pub fn result<'a>(results: &'a [RuleResult], rule_id: &str) -> &'a RuleResult {
+    results
+        .iter()
+        .find(|result| result.rule_id == rule_id)
+        .unwrap()
+}
+
+pub fn status(results: &[RuleResult], rule_id: &str) -> RuleStatus {
+    result(results, rule_id).status
+}
+
+// An internal page of `BASE` found through `source`, crawled without errors
+pub fn page(path: &str, source: LinkSourceType, outlinks: &[&str]) -> (String, PageLink) {
+    let mut found_in = HashSet::new();
+    found_in.insert(PageLinkSource {
+        link_source_type: source.clone(),
+        url: url("/"),
+    });
+    let link = PageLink {
+        url: url(path),
+        link_type: LinkType::Internal,
+        found_in,
+        result: Some(PageResult {
+            error: false,
+            results: vec![],
+        }),
+        depth: None,
+        discovered_by: source,
+        metadata: None,
+        outlinks: outlinks
+            .iter()
+            .map(|path| parse_link(&url(path), BASE).unwrap())
+            .collect(),
+        link_metrics: None,
+    };
+    (url(path), link)
+}
+
+// Serves `handler`'s response for each request path on a random local port
+pub async fn start_test_server<F>(handler: F) -> SocketAddr
+where
+    F: Fn(&str) -> Response<Body> + Clone + Send + Sync + 'static,
+{
+    let addr = SocketAddr::from(([127, 0, 0, 1], 0));
+    let listener = TcpListener::bind(addr).await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let make_svc = make_service_fn(move |_conn| {
+        let handler = handler.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |req| {
+                let response = handler(req.uri().path());
+                async move { Ok::<_, Infallible>(response) }
+            }))
+        }
+    });
+
+    tokio::spawn(async move {
+        Server::from_tcp(listener.into_std().unwrap())
+            .unwrap()
+            .serve(make_svc)
+            .await
+            .unwrap();
+    });
+
+    addr
+}
+
+pub fn not_found() -> Response<Body> {
+    Response::builder()
+        .status(StatusCode::NOT_FOUND)
+        .body(Body::from("404"))
+        .unwrap()
+}
@@ -1,9 +1,14 @@
 // Core plugin traits
+use futures::future::BoxFuture;
+use scraper::error::SelectorErrorKind;
 use serde::{Deserialize, Serialize};
 use specta::Type;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use thiserror::Error;
 
-use super::page::Page;
+use super::fetcher::Fetcher;
+use super::page::{Page, PageError};
+use super::rule_param::{RuleParam, RuleParamError, RuleParamValue, RuleParams};
 
 #[derive(Debug, Serialize, Deserialize, specta::Type, Clone)]
 pub enum SiteCheckContext {
@@ -20,28 +25,115 @@ pub struct SiteCheckResult {
     // pub severity: Option<Severity>,
 }
 
+// Why a rule could not produce a passed/failed result
+#[derive(Debug, Error)]
+pub enum CheckError {
+    #[error("Not applicable: {0}")]
+    NotApplicable(String),
+    #[error("Page error: {0}")]
+    PageError(PageError),
+    #[error("Selector parse error: {0}")]
+    SelectorParseError(String),
+    #[error("Unknown rule: {0}")]
+    UnknownRule(String),
+    #[error("Invalid rule parameter: {0}")]
+    InvalidParam(String),
+    // A runtime-loaded plugin trapped, ran out of fuel or returned a malformed result,
+    // or a site plugin failed to record pages of the crawl
+    #[error("Plugin error: {0}")]
+    PluginError(String),
+}
+
+impl From<PageError> for CheckError {
+    fn from(error: PageError) -> Self {
+        match error {
+            // Nothing to check on responses without an HTML body
+            PageError::DocumentNotSet(message) => CheckError::NotApplicable(message),
+            error => CheckError::PageError(error),
+        }
+    }
+}
+
+impl From<RuleParamError> for CheckError {
+    fn from(error: RuleParamError) -> Self {
+        CheckError::InvalidParam(error.to_string())
+    }
+}
+
+impl From<SelectorErrorKind<'_>> for CheckError {
+    fn from(error: SelectorErrorKind<'_>) -> Self {
+        CheckError::SelectorParseError(error.to_string())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Type, Clone, Copy, PartialEq, Eq)]
+pub enum RuleStatus {
+    Passed,
+    Failed,
+    // The rule does not apply to the page, e.g. no HTML document
+    NotApplicable,
+    // The rule could not be evaluated
+    Errored,
+}
+
+impl RuleStatus {
+    pub fn from_passed(passed: bool) -> Self {
+        if passed {
+            RuleStatus::Passed
+        } else {
+            RuleStatus::Failed
+        }
+    }
+}
+
+impl From<&CheckError> for RuleStatus {
+    fn from(error: &CheckError) -> Self {
+        match error {
+            CheckError::NotApplicable(_) => RuleStatus::NotApplicable,
+            _ => RuleStatus::Errored,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, specta::Type)]
 pub struct CheckResult {
     pub rule_id: String,
     pub passed: bool,
     pub message: String,
+    pub findings: Vec<Finding>,
     // pub severity: Option<Severity>,
 }
 
+// A single offending element found by a rule
+#[derive(Debug, Serialize, Deserialize, Type, Clone, Default, PartialEq)]
+pub struct Finding {
+    // CSS selector path to the element, e.g. `html > body > img:nth-of-type(2)`
+    pub selector: String,
+    // Outer HTML of the element, truncated for large elements
+    pub html: String,
+    // 1-based position of the element's start tag in the page source
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+    // The attribute value that caused the failure, if any
+    pub value: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Type, Clone)]
 pub struct RuleResult {
     pub rule_id: String,
     pub name: String,
     pub plugin_name: String,
     pub passed: bool,
+    pub status: RuleStatus,
     pub message: String,
     pub severity: Severity,
     pub category: RuleCategory,
     pub context: SiteCheckContext,
+    pub findings: Vec<Finding>,
 }
 
 // Severity level of an SEO issue
-#[derive(Debug, Serialize, Deserialize, Type, Clone)]
+#[derive(Debug, Serialize, Deserialize, Type, Clone, PartialEq, Eq)]
 pub enum Severity {
     Info,
     Warning,
@@ -49,7 +141,7 @@ pub enum Severity {
     Critical,
 }
 
-#[derive(Debug, Serialize, Deserialize, Type, Clone)]
+#[derive(Debug, Serialize, Deserialize, Type, Clone, PartialEq, Eq)]
 pub enum RuleCategory {
     Accessibility,
     Performance,
@@ -57,6 +149,50 @@ pub enum RuleCategory {
     SEO,
 }
 
+// Rough amount of work to fix a failing rule
+#[derive(Debug, Serialize, Deserialize, Type, Clone, Copy, PartialEq, Eq)]
+pub enum Effort {
+    Low,
+    Medium,
+    High,
+}
+
+// How to fix a failing rule and why it is worth fixing
+#[derive(Debug, Serialize, Deserialize, Type, Clone, PartialEq)]
+pub struct Remediation {
+    pub why_it_matters: String,
+    pub how_to_fix: String,
+    // Markup or config showing the fix
+    #[serde(default)]
+    pub example: Option<String>,
+    // Documentation URLs
+    #[serde(default)]
+    pub references: Vec<String>,
+    pub effort: Effort,
+}
+
+impl Remediation {
+    pub fn new(why_it_matters: &str, how_to_fix: &str, effort: Effort) -> Self {
+        Self {
+            why_it_matters: why_it_matters.to_string(),
+            how_to_fix: how_to_fix.to_string(),
+            example: None,
+            references: vec![],
+            effort,
+        }
+    }
+
+    pub fn with_example(mut self, example: &str) -> Self {
+        self.example = Some(example.to_string());
+        self
+    }
+
+    pub fn with_reference(mut self, url: &str) -> Self {
+        self.references.push(url.to_string());
+        self
+    }
+}
+
 // Rule definition
 #[derive(Clone)]
 pub struct Rule {
@@ -67,9 +203,34 @@ pub struct Rule {
     pub passed_message: &'static str,
     pub failed_message: &'static str,
     pub default_severity: Severity,
-    // TODO: Change to Result<CheckResult, CheckError>
-    pub check: fn(&Page) -> CheckResult,
+    pub check: fn(&Page, &RuleParams) -> Result<CheckResult, CheckError>,
+    pub category: RuleCategory,
+    // Settings the check reads from `RuleParams`, empty for most rules
+    pub params: Vec<RuleParam>,
+    pub remediation: Option<Remediation>,
+}
+
+pub type AsyncCheck = for<'a> fn(
+    &'a Page,
+    &'a RuleParams,
+    &'a Fetcher,
+) -> BoxFuture<'a, Result<CheckResult, CheckError>>;
+
+// A page rule whose check awaits, e.g. to fetch resources the page links to.
+// Only `PluginRegistry::analyze_async` runs these.
+#[derive(Clone)]
+pub struct AsyncRule {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub plugin_name: &'static str,
+    pub description: &'static str,
+    pub passed_message: &'static str,
+    pub failed_message: &'static str,
+    pub default_severity: Severity,
+    pub check: AsyncCheck,
     pub category: RuleCategory,
+    pub params: Vec<RuleParam>,
+    pub remediation: Option<Remediation>,
 }
 
 // Rule definition
@@ -84,6 +245,8 @@ pub struct SiteRule {
     pub default_severity: Severity,
     // pub check: fn(&Site) -> CheckResult,
     pub category: RuleCategory,
+    pub params: Vec<RuleParam>,
+    pub remediation: Option<Remediation>,
 }
 #[derive(Debug, Serialize, Deserialize, Type, Clone)]
 pub enum RuleType {
@@ -102,18 +265,142 @@ pub struct RuleDisplay {
     pub rule_type: RuleType,
     pub passed_message: String,
     pub failed_message: String,
+    pub params: Vec<RuleParam>,
+    #[serde(default)]
+    pub remediation: Option<Remediation>,
 }
 
 impl Rule {
     pub fn to_display(&self) -> RuleDisplay {
         RuleDisplay::from(self)
     }
+
+    // Run the check with the parameters configured for this rule
+    pub fn run(&self, page: &Page, config: &RuleConfig) -> Result<CheckResult, CheckError> {
+        let params = RuleParams::resolve(self.id, &self.params, config)?;
+        (self.check)(page, &params)
+    }
+
+    pub fn to_result(
+        &self,
+        plugin_name: &str,
+        config: &RuleConfig,
+        result: Result<CheckResult, CheckError>,
+    ) -> RuleResult {
+        page_rule_result(
+            self.id,
+            self.name,
+            plugin_name,
+            config.get_severity(self.id, self.default_severity.clone()),
+            self.category.clone(),
+            result,
+        )
+    }
+}
+
+impl AsyncRule {
+    pub fn to_display(&self) -> RuleDisplay {
+        RuleDisplay::from(self)
+    }
+
+    pub async fn run(
+        &self,
+        page: &Page,
+        config: &RuleConfig,
+        fetcher: &Fetcher,
+    ) -> Result<CheckResult, CheckError> {
+        let params = RuleParams::resolve(self.id, &self.params, config)?;
+        (self.check)(page, &params, fetcher).await
+    }
+
+    pub fn to_result(
+        &self,
+        plugin_name: &str,
+        config: &RuleConfig,
+        result: Result<CheckResult, CheckError>,
+    ) -> RuleResult {
+        page_rule_result(
+            self.id,
+            self.name,
+            plugin_name,
+            config.get_severity(self.id, self.default_severity.clone()),
+            self.category.clone(),
+            result,
+        )
+    }
+}
+
+// Also used by plugins whose rules are only known at runtime
+pub(crate) fn page_rule_result(
+    rule_id: &str,
+    name: &str,
+    plugin_name: &str,
+    severity: Severity,
+    category: RuleCategory,
+    result: Result<CheckResult, CheckError>,
+) -> RuleResult {
+    let (status, message, findings) = match result {
+        Ok(result) => (
+            RuleStatus::from_passed(result.passed),
+            result.message,
+            result.findings,
+        ),
+        Err(error) => (RuleStatus::from(&error), error.to_string(), vec![]),
+    };
+    RuleResult {
+        rule_id: rule_id.to_string(),
+        name: name.to_string(),
+        plugin_name: plugin_name.to_string(),
+        passed: status == RuleStatus::Passed,
+        status,
+        message,
+        severity,
+        category,
+        context: SiteCheckContext::Empty,
+        findings,
+    }
 }
 
 impl SiteRule {
     pub fn to_display(&self) -> RuleDisplay {
         RuleDisplay::from(self)
     }
+
+    pub fn resolve_params(&self, config: &RuleConfig) -> Result<RuleParams, CheckError> {
+        Ok(RuleParams::resolve(self.id, &self.params, config)?)
+    }
+
+    pub fn to_result(
+        &self,
+        plugin_name: &str,
+        config: &RuleConfig,
+        result: Result<SiteCheckResult, CheckError>,
+    ) -> RuleResult {
+        let (status, message, context) = match result {
+            Ok(result) => (
+                RuleStatus::from_passed(result.passed),
+                result.message,
+                result.context,
+            ),
+            Err(error) => (
+                RuleStatus::from(&error),
+                error.to_string(),
+                SiteCheckContext::Empty,
+            ),
+        };
+        RuleResult {
+            rule_id: self.id.to_string(),
+            name: self.name.to_string(),
+            plugin_name: plugin_name.to_string(),
+            passed: status == RuleStatus::Passed,
+            status,
+            message,
+            severity: config.get_severity(self.id, self.default_severity.clone()),
+            category: self.category.clone(),
+            context,
+            findings: vec![],
+        }
+    }
 }
 
 impl From<Rule> for RuleDisplay {
@@ -128,6 +415,8 @@ impl From<Rule> for RuleDisplay {
             rule_type: RuleType::Page,
             passed_message: rule.passed_message.to_string(),
             failed_message: rule.failed_message.to_string(),
+            params: rule.params,
+            remediation: rule.remediation,
         }
     }
 }
@@ -138,6 +427,24 @@ impl From<&Rule> for RuleDisplay {
         RuleDisplay::from(rule)
     }
 }
+
+impl From<&AsyncRule> for RuleDisplay {
+    fn from(rule: &AsyncRule) -> Self {
+        RuleDisplay {
+            id: rule.id.to_string(),
+            name: rule.name.to_string(),
+            plugin_name: rule.plugin_name.to_string(),
+            description: rule.description.to_string(),
+            severity: rule.default_severity.clone(),
+            category: rule.category.clone(),
+            rule_type: RuleType::Page,
+            passed_message: rule.passed_message.to_string(),
+            failed_message: rule.failed_message.to_string(),
+            params: rule.params.clone(),
+            remediation: rule.remediation.clone(),
+        }
+    }
+}
 impl From<SiteRule> for RuleDisplay {
     fn from(rule: SiteRule) -> Self {
         RuleDisplay {
@@ -150,6 +457,8 @@ impl From<SiteRule> for RuleDisplay {
             rule_type: RuleType::Site,
             passed_message: rule.passed_message.to_string(),
             failed_message: rule.failed_message.to_string(),
+            params: rule.params,
+            remediation: rule.remediation,
         }
     }
 }
@@ -178,6 +487,20 @@ impl FromIterator<Rule> for Vec<RuleDisplay> {
 pub struct RuleConfig {
     enabled_rules: HashMap<String, bool>,
     rule_severities: HashMap<String, Severity>,
+    rule_params: HashMap<String, HashMap<String, RuleParamValue>>,
+    // Whether rules the config doesn't mention run, e.g. rules added after it was saved
+    #[serde(default)]
+    enable_unlisted: bool,
+}
+
+// Per-rule settings, the shape rules take in saved and exported profiles
+#[derive(Debug, Serialize, Deserialize, Type, Clone, PartialEq, Default)]
+pub struct RuleSettings {
+    pub enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, RuleParamValue>,
 }
 
 impl Default for RuleConfig {
@@ -191,9 +514,19 @@ impl RuleConfig {
         Self {
             enabled_rules: HashMap::new(),
             rule_severities: HashMap::new(),
+            rule_params: HashMap::new(),
+            enable_unlisted: false,
         }
     }
 
+    pub fn set_enable_unlisted(&mut self, enabled: bool) {
+        self.enable_unlisted = enabled;
+    }
+
+    pub fn enables_unlisted(&self) -> bool {
+        self.enable_unlisted
+    }
+
     pub fn enable_rule<S: AsRef<str>>(&mut self, rule_id: S) {
         self.enabled_rules
             .insert(rule_id.as_ref().to_string(), true);
@@ -207,8 +540,18 @@ impl RuleConfig {
         self.rule_severities.insert(rule_id.to_string(), severity);
     }
 
+    pub fn set_param(&mut self, rule_id: &str, name: &str, value: RuleParamValue) {
+        self.rule_params
+            .entry(rule_id.to_string())
+            .or_default()
+            .insert(name.to_string(), value);
+    }
+
     pub fn is_rule_enabled(&self, rule_id: &str) -> bool {
-        *self.enabled_rules.get(rule_id).unwrap_or(&false)
+        *self
+            .enabled_rules
+            .get(rule_id)
+            .unwrap_or(&self.enable_unlisted)
     }
 
     pub fn get_severity(&self, rule_id: &str, default: Severity) -> Severity {
@@ -217,4 +560,54 @@ impl RuleConfig {
             .cloned()
             .unwrap_or(default)
     }
+
+    pub fn get_param(&self, rule_id: &str, name: &str) -> Option<&RuleParamValue> {
+        self.rule_params
+            .get(rule_id)
+            .and_then(|params| params.get(name))
+    }
+
+    // Settings for every rule the config mentions, keyed by rule id
+    pub fn to_settings(&self) -> BTreeMap<String, RuleSettings> {
+        let rule_ids = self
+            .enabled_rules
+            .keys()
+            .chain(self.rule_severities.keys())
+            .chain(self.rule_params.keys());
+
+        rule_ids
+            .map(|rule_id| {
+                let settings = RuleSettings {
+                    enabled: self.is_rule_enabled(rule_id),
+                    severity: self.rule_severities.get(rule_id).cloned(),
+                    params: self
+                        .rule_params
+                        .get(rule_id)
+                        .map(|params| params.clone().into_iter().collect())
+                        .unwrap_or_default(),
+                };
+                (rule_id.clone(), settings)
+            })
+            .collect()
+    }
+
+    pub fn from_settings<'a>(
+        settings: impl IntoIterator<Item = (&'a String, &'a RuleSettings)>,
+    ) -> Self {
+        let mut config = Self::new();
+        for (rule_id, settings) in settings {
+            if settings.enabled {
+                config.enable_rule(rule_id);
+            } else {
+                config.disable_rule(rule_id);
+            }
+            if let Some(severity) = &settings.severity {
+                config.set_severity(rule_id, severity.clone());
+            }
+            for (name, value) in &settings.params {
+                config.set_param(rule_id, name, value.clone());
+            }
+        }
+        config
+    }
 }
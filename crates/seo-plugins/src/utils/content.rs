@@ -0,0 +1,430 @@
+use scraper::{ElementRef, Html, Node, Selector};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use super::page::Page;
+use super::text::collapse_whitespace;
+
+// Elements that never hold the main content of a page
+const BOILERPLATE_TAGS: &[&str] = &[
+    "nav", "header", "footer", "aside", "form", "button", "select", "script", "style", "noscript",
+    "template", "svg", "iframe",
+];
+
+const BOILERPLATE_ROLES: &[&str] = &[
+    "navigation",
+    "banner",
+    "contentinfo",
+    "complementary",
+    "search",
+];
+
+// Fragments of class names and ids sites use for boilerplate
+const BOILERPLATE_CLASSES: &[&str] = &["breadcrumb", "cookie", "newsletter", "sidebar", "share"];
+
+// Elements whose text is never shown
+const HIDDEN_TAGS: &[&str] = &["script", "style", "noscript", "template", "svg"];
+
+// Words per shingle the fingerprint is built from
+const SHINGLE_SIZE: usize = 2;
+
+// Letters counted as vowels when splitting words into syllables
+const VOWELS: &str = "aeiouyàáâãäåæèéêëìíîïòóôõöøœùúûüý";
+
+// The visible text of the main content: the <main> element when there is one, else
+// the body, without navigation, header, footer, sidebars and other boilerplate
+pub fn main_text(document: &Html) -> String {
+    let mut text = Vec::new();
+    if let Some(root) = main_root(document) {
+        visible_text(root, is_boilerplate, &mut text);
+    }
+    collapse_whitespace(&text.join(" "))
+}
+
+fn main_root(document: &Html) -> Option<ElementRef<'_>> {
+    ["main", "[role=main]", "body"].iter().find_map(|selector| {
+        let selector = Selector::parse(selector).ok()?;
+        document.select(&selector).next()
+    })
+}
+
+fn is_hidden(element: &ElementRef) -> bool {
+    HIDDEN_TAGS.contains(&element.value().name()) || element.value().attr("hidden").is_some()
+}
+
+fn is_boilerplate(element: &ElementRef) -> bool {
+    if is_hidden(element) {
+        return true;
+    }
+    let element = element.value();
+    if BOILERPLATE_TAGS.contains(&element.name()) {
+        return true;
+    }
+    if element
+        .attr("role")
+        .is_some_and(|role| BOILERPLATE_ROLES.contains(&role))
+    {
+        return true;
+    }
+    element
+        .classes()
+        .chain(element.id())
+        .map(|name| name.to_lowercase())
+        .any(|name| BOILERPLATE_CLASSES.iter().any(|class| name.contains(class)))
+}
+
+// Text under `element`, leaving out the elements `skip` matches
+fn visible_text<'a>(
+    element: ElementRef<'a>,
+    skip: fn(&ElementRef) -> bool,
+    text: &mut Vec<&'a str>,
+) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(value) => text.push(value),
+            Node::Element(_) => {
+                if let Some(child) = ElementRef::wrap(child).filter(|child| !skip(child)) {
+                    visible_text(child, skip, text);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// Paragraphs with text in the main content
+fn main_paragraphs(document: &Html) -> u32 {
+    let (Some(root), Ok(selector)) = (main_root(document), Selector::parse("p")) else {
+        return 0;
+    };
+    let count = root
+        .select(&selector)
+        .filter(|paragraph| {
+            !paragraph
+                .ancestors()
+                .take_while(|ancestor| ancestor.id() != root.id())
+                .filter_map(ElementRef::wrap)
+                .chain([*paragraph])
+                .any(|element| is_boilerplate(&element))
+        })
+        .filter(|paragraph| paragraph.text().any(|text| !text.trim().is_empty()))
+        .count();
+    u32::try_from(count).unwrap_or(u32::MAX)
+}
+
+// The main text of a page, see `main_text`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MainText(pub String);
+
+// How much a page says and how hard it is to read
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Default, PartialEq)]
+pub struct ContentMetrics {
+    // Words of the main text, see `main_text`
+    pub word_count: u32,
+    pub sentence_count: u32,
+    pub paragraph_count: u32,
+    // Visible text of the whole page relative to the size of the HTML, in %
+    pub text_html_ratio: f32,
+    // `None` for pages without text or in a language without a formula
+    pub readability: Option<Readability>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+pub struct Readability {
+    // Primary language subtag of the page, e.g. "de", English when the page has none
+    pub language: String,
+    // The Flesch reading ease variant calibrated for the language
+    pub formula: String,
+    // From about 0 (very hard) to 100 (very easy)
+    pub reading_ease: f32,
+    // Flesch–Kincaid US school grade. Calibrated on English, only a rough guide for
+    // other languages.
+    pub grade_level: f32,
+}
+
+impl ContentMetrics {
+    // `main_text` is the document's, see `main_text`
+    pub fn new(document: &Html, main_text: &str, html_length: usize) -> Self {
+        let words: Vec<&str> = words(main_text).collect();
+        let sentence_count = sentences(main_text).max(1);
+
+        let mut visible = Vec::new();
+        let body = Selector::parse("body")
+            .ok()
+            .and_then(|selector| document.select(&selector).next());
+        if let Some(body) = body {
+            visible_text(body, is_hidden, &mut visible);
+        }
+        let visible_length = collapse_whitespace(&visible.join(" ")).len();
+        let text_html_ratio = if html_length == 0 {
+            0.0
+        } else {
+            (visible_length as f32 / html_length as f32 * 100.0).min(100.0)
+        };
+
+        let language = document
+            .root_element()
+            .value()
+            .attr("lang")
+            .and_then(|lang| lang.split(['-', '_']).next())
+            .map(|lang| lang.trim().to_lowercase())
+            .filter(|lang| !lang.is_empty())
+            .unwrap_or("en".to_string());
+        let readability = (!words.is_empty())
+            .then(|| Readability::new(&language, &words, sentence_count))
+            .flatten();
+
+        let count = |value: usize| u32::try_from(value).unwrap_or(u32::MAX);
+        Self {
+            word_count: count(words.len()),
+            sentence_count: count(sentence_count),
+            paragraph_count: main_paragraphs(document),
+            text_html_ratio,
+            readability,
+        }
+    }
+}
+
+impl From<&Page> for ContentMetrics {
+    fn from(page: &Page) -> Self {
+        let html_length = page.get_html().map(|html| html.len()).unwrap_or_default();
+        // Published by the main text plugin when the page runs through a registry
+        let main_text = match page.outputs().get::<MainText>() {
+            Some(main_text) => Ok(main_text.0.clone()),
+            None => page.extract_main_text(),
+        };
+        match (page.get_document(), main_text) {
+            (Ok(document), Ok(main_text)) => Self::new(&document, &main_text, html_length),
+            _ => Self::default(),
+        }
+    }
+}
+
+impl Readability {
+    fn new(language: &str, words: &[&str], sentences: usize) -> Option<Self> {
+        // (formula, base, per word per sentence, per syllable per word)
+        let (formula, base, sentence_weight, syllable_weight) = match language {
+            "en" => ("Flesch", 206.835, 1.015, 84.6),
+            "de" => ("Flesch-Amstad", 180.0, 1.0, 58.5),
+            "fr" => ("Kandel-Moles", 207.0, 1.015, 73.6),
+            "es" => ("Szigriszt-Pazos", 206.835, 1.0, 62.3),
+            "it" => ("Flesch-Vacca", 217.0, 1.3, 60.0),
+            "nl" => ("Flesch-Douma", 206.835, 0.93, 77.0),
+            _ => return None,
+        };
+        let syllables: usize = words.iter().map(|word| syllables(word, language)).sum();
+        let words_per_sentence = words.len() as f32 / sentences as f32;
+        let syllables_per_word = syllables as f32 / words.len() as f32;
+        Some(Self {
+            language: language.to_string(),
+            formula: formula.to_string(),
+            reading_ease: base
+                - sentence_weight * words_per_sentence
+                - syllable_weight * syllables_per_word,
+            grade_level: 0.39 * words_per_sentence + 11.8 * syllables_per_word - 15.59,
+        })
+    }
+}
+
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .map(|word| word.trim_matches('\''))
+        .filter(|word| word.chars().any(char::is_alphabetic))
+}
+
+// Sentence ends: runs of . ! ? or … followed by a space or the end of the text
+fn sentences(text: &str) -> usize {
+    let mut sentences = 0;
+    let mut has_words = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_alphanumeric() {
+            has_words = true;
+        } else if matches!(c, '.' | '!' | '?' | '…')
+            && has_words
+            && chars.peek().is_none_or(|next| next.is_whitespace())
+        {
+            sentences += 1;
+            has_words = false;
+        }
+    }
+    sentences + usize::from(has_words)
+}
+
+// Groups of vowels, the usual estimate when there is no dictionary at hand
+fn syllables(word: &str, language: &str) -> usize {
+    let word = word.to_lowercase();
+    let mut groups = 0;
+    let mut in_vowels = false;
+    for c in word.chars() {
+        let vowel = VOWELS.contains(c);
+        if vowel && !in_vowels {
+            groups += 1;
+        }
+        in_vowels = vowel;
+    }
+    // A final e is silent in English, "make", but not in "table" or "the"
+    if language == "en" && groups > 1 && word.ends_with('e') && !word.ends_with("le") {
+        groups -= 1;
+    }
+    groups.max(1)
+}
+
+// What a page's main text looks like, to find pages with the same or almost the same
+// content without keeping the text around
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentFingerprint {
+    pub words: usize,
+    // Hash of the whole text, equal for identical texts
+    pub exact: u64,
+    // SimHash of the text's word shingles: similar texts differ in few bits
+    pub simhash: u64,
+}
+
+impl ContentFingerprint {
+    pub fn new(text: &str) -> Self {
+        let words: Vec<String> = text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_lowercase())
+            .collect();
+
+        let mut weights = [0i32; 64];
+        for shingle in words.windows(SHINGLE_SIZE.min(words.len()).max(1)) {
+            let hash = fnv1a(shingle.join(" ").as_bytes());
+            for (bit, weight) in weights.iter_mut().enumerate() {
+                if hash & (1 << bit) != 0 {
+                    *weight += 1;
+                } else {
+                    *weight -= 1;
+                }
+            }
+        }
+        let simhash = weights
+            .iter()
+            .enumerate()
+            .filter(|(_, weight)| **weight > 0)
+            .fold(0u64, |simhash, (bit, _)| simhash | (1 << bit));
+
+        Self {
+            words: words.len(),
+            exact: fnv1a(words.join(" ").as_bytes()),
+            simhash,
+        }
+    }
+
+    // Share of the fingerprint bits two texts have in common, from 0 to 1
+    pub fn similarity(&self, other: &Self) -> f64 {
+        1.0 - f64::from((self.simhash ^ other.simhash).count_ones()) / 64.0
+    }
+}
+
+// 64-bit FNV-1a, stable across runs and platforms unlike the std hasher
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARTICLE: &str = "Trail running shoes need a grippy outsole, a protective toe cap and enough \
+        cushioning for long descents. We tested twelve pairs on rocky and muddy trails over three \
+        months, measuring grip, comfort, weight and durability. The lightest pairs wore out after \
+        two hundred kilometres, while the heavier ones still had most of their tread left.";
+
+    #[test]
+    fn test_main_text() {
+        let document = Html::parse_document(
+            r#"<html><body>
+                <header><a href="/">Example Store</a></header>
+                <nav><a href="/shoes">Shoes</a></nav>
+                <div class="breadcrumbs">Home / Shoes</div>
+                <h1>Trail shoes</h1>
+                <p>Shoes for <b>every</b> trail.</p>
+                <script>var tracking = true;</script>
+                <div role="complementary">Related products</div>
+                <div hidden>Hidden text</div>
+                <footer>© Example Store</footer>
+            </body></html>"#,
+        );
+        assert_eq!(main_text(&document), "Trail shoes Shoes for every trail.");
+
+        let document = Html::parse_document(
+            "<html><body><p>Intro</p><main><p>Main content</p></main></body></html>",
+        );
+        assert_eq!(main_text(&document), "Main content");
+    }
+
+    #[test]
+    fn test_content_metrics() {
+        let html = format!(
+            r#"<html lang="en-GB"><head><style>body {{ color: red }}</style></head><body>
+                <nav><p>Shoes</p></nav>
+                <main><h1>Trail shoes</h1><p>{ARTICLE}</p><p>Free returns!</p><p> </p></main>
+            </body></html>"#
+        );
+        let document = Html::parse_document(&html);
+        let metrics = ContentMetrics::new(&document, &main_text(&document), html.len());
+        assert_eq!(metrics.word_count, 59);
+        assert_eq!(metrics.sentence_count, 4);
+        assert_eq!(metrics.paragraph_count, 2);
+        assert!(metrics.text_html_ratio > 60.0 && metrics.text_html_ratio < 90.0);
+
+        let readability = metrics.readability.unwrap();
+        assert_eq!(readability.language, "en");
+        assert_eq!(readability.formula, "Flesch");
+        assert!(readability.reading_ease > 40.0 && readability.reading_ease < 80.0);
+        assert!(readability.grade_level > 6.0 && readability.grade_level < 14.0);
+
+        let german = Html::parse_document(
+            r#"<html lang="de"><body><p>Der Schuh passt gut. Wir laufen im Wald.</p></body></html>"#,
+        );
+        let readability = ContentMetrics::new(&german, &main_text(&german), 100)
+            .readability
+            .unwrap();
+        assert_eq!(readability.formula, "Flesch-Amstad");
+        assert!(readability.reading_ease > 90.0);
+
+        let empty = Html::parse_document("<html><body></body></html>");
+        assert!(ContentMetrics::new(&empty, "", 0).readability.is_none());
+    }
+
+    #[test]
+    fn test_syllables_and_sentences() {
+        for (word, count) in [
+            ("shoe", 1),
+            ("make", 1),
+            ("table", 2),
+            ("the", 1),
+            ("running", 2),
+        ] {
+            assert_eq!(syllables(word, "en"), count, "{word}");
+        }
+        assert_eq!(syllables("Schuhe", "de"), 2);
+        assert_eq!(sentences("It costs 3.5 euros. Really?! Yes… ok"), 4);
+        assert_eq!(sentences(""), 0);
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let original = ContentFingerprint::new(ARTICLE);
+        assert_eq!(original.words, 55);
+
+        let reformatted = ContentFingerprint::new(&ARTICLE.to_uppercase().replace(',', " "));
+        assert_eq!(original.exact, reformatted.exact);
+
+        let edited = ContentFingerprint::new(&ARTICLE.replace("twelve", "ten"));
+        assert_ne!(original.exact, edited.exact);
+        assert!(original.similarity(&edited) >= 0.9);
+
+        let other = ContentFingerprint::new(
+            "Our return policy lets you send back unworn shoes within thirty days of delivery. \
+             Print the label from your account, pack the shoes in their original box and drop \
+             the parcel at any post office. Refunds reach your card within a week.",
+        );
+        assert!(original.similarity(&other) < 0.8);
+    }
+}
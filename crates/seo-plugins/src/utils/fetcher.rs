@@ -0,0 +1,266 @@
+use parking_lot::Mutex;
+use reqwest::Client;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::{OnceCell, Semaphore};
+use tokio::time::Instant;
+use url::Url;
+
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum FetchError {
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(String),
+    #[error("Failed to fetch {url}: {message}")]
+    RequestFailed { url: String, message: String },
+}
+
+// A fetched resource, shared by every rule that asks for the same URL
+#[derive(Debug, Clone)]
+pub struct FetchResponse {
+    // Where the request ended up after redirects
+    pub url: String,
+    pub status: u16,
+    // Response headers, names lowercased
+    pub headers: HashMap<String, String>,
+    pub body: Arc<str>,
+}
+
+impl FetchResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_lowercase())
+            .map(|value| value.as_str())
+    }
+}
+
+pub type FetchResult = Result<Arc<FetchResponse>, FetchError>;
+
+type CacheEntry = Arc<OnceCell<FetchResult>>;
+
+// Keeps the most recently used responses, a crawl points at far more URLs than it reuses
+struct ResponseCache {
+    capacity: usize,
+    entries: HashMap<String, CacheEntry>,
+    // Least recently used first
+    order: VecDeque<String>,
+}
+
+impl ResponseCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn entry(&mut self, url: &str) -> CacheEntry {
+        if let Some(entry) = self.entries.get(url) {
+            let entry = entry.clone();
+            if let Some(position) = self.order.iter().position(|cached| cached == url)
+                && let Some(cached) = self.order.remove(position)
+            {
+                self.order.push_back(cached);
+            }
+            return entry;
+        }
+
+        while self.entries.len() >= self.capacity {
+            let Some(oldest) = self.order.pop_front() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+        let entry = CacheEntry::default();
+        self.entries.insert(url.to_string(), entry.clone());
+        self.order.push_back(url.to_string());
+        entry
+    }
+}
+
+// HTTP client for async rules. Limits concurrent requests, spaces them out and caches
+// recent results, so rules on many pages pointing at one favicon fetch it once.
+#[derive(Clone)]
+pub struct Fetcher {
+    client: Client,
+    permits: Arc<Semaphore>,
+    request_delay: Duration,
+    next_request: Arc<Mutex<Instant>>,
+    cache: Arc<Mutex<ResponseCache>>,
+}
+
+impl Default for Fetcher {
+    fn default() -> Self {
+        Self::new(4, Duration::from_millis(100))
+    }
+}
+
+const CACHE_CAPACITY: usize = 256;
+
+impl Fetcher {
+    pub fn new(max_concurrent_requests: usize, request_delay: Duration) -> Self {
+        Self::with_cache_capacity(max_concurrent_requests, request_delay, CACHE_CAPACITY)
+    }
+
+    pub fn with_cache_capacity(
+        max_concurrent_requests: usize,
+        request_delay: Duration,
+        cache_capacity: usize,
+    ) -> Self {
+        let client = Client::builder()
+            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/58.0.3029.110 Safari/537.3")
+            .timeout(Duration::from_secs(10))
+            .build()
+            .unwrap_or_else(|error| {
+                eprintln!("Failed to build HTTP client, falling back to defaults: {}", error);
+                Client::new()
+            });
+        Self {
+            client,
+            permits: Arc::new(Semaphore::new(max_concurrent_requests.max(1))),
+            request_delay,
+            next_request: Arc::new(Mutex::new(Instant::now())),
+            cache: Arc::new(Mutex::new(ResponseCache::new(cache_capacity))),
+        }
+    }
+
+    // Fetches `url` once while it stays cached, concurrent calls for the same URL share the result
+    pub async fn fetch(&self, url: &str) -> FetchResult {
+        let cell = self.cache.lock().entry(url);
+        cell.get_or_init(|| self.request(url)).await.clone()
+    }
+
+    async fn request(&self, url: &str) -> FetchResult {
+        let parsed = Url::parse(url).map_err(|_| FetchError::InvalidUrl(url.to_string()))?;
+        let request_failed = |error: reqwest::Error| FetchError::RequestFailed {
+            url: url.to_string(),
+            message: error.to_string(),
+        };
+
+        let _permit = self
+            .permits
+            .acquire()
+            .await
+            .map_err(|e| FetchError::RequestFailed {
+                url: url.to_string(),
+                message: e.to_string(),
+            })?;
+        self.wait_for_turn().await;
+
+        let response = self
+            .client
+            .get(parsed)
+            .send()
+            .await
+            .map_err(request_failed)?;
+        let status = response.status().as_u16();
+        let final_url = response.url().to_string();
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.as_str().to_lowercase(), value.to_string()))
+            })
+            .collect();
+        let body = response.text().await.map_err(request_failed)?;
+
+        Ok(Arc::new(FetchResponse {
+            url: final_url,
+            status,
+            headers,
+            body: body.into(),
+        }))
+    }
+
+    // Starts requests at least `request_delay` apart
+    async fn wait_for_turn(&self) {
+        let start = {
+            let mut next_request = self.next_request.lock();
+            let start = (*next_request).max(Instant::now());
+            *next_request = start + self.request_delay;
+            start
+        };
+        tokio::time::sleep_until(start).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{not_found, start_test_server};
+    use hyper::{Body, Response};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    async fn start_server(hits: Arc<AtomicUsize>) -> String {
+        let addr = start_test_server(move |path| {
+            hits.fetch_add(1, Ordering::SeqCst);
+            match path {
+                "/icon.png" | "/logo.png" => Response::new(Body::from("png")),
+                _ => not_found(),
+            }
+        })
+        .await;
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_fetches_each_url_once() {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let base_url = start_server(hits.clone()).await;
+        let fetcher = Fetcher::new(2, Duration::from_millis(10));
+        let icon = format!("{}/icon.png", base_url);
+
+        // Clones share the cache
+        let clone = fetcher.clone();
+        let (first, second) = tokio::join!(fetcher.fetch(&icon), clone.fetch(&icon));
+        let first = first.unwrap();
+        assert!(first.is_success());
+        assert_eq!(&*first.body, "png");
+        assert_eq!(second.unwrap().status, 200);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+        let missing = fetcher
+            .fetch(&format!("{}/missing", base_url))
+            .await
+            .unwrap();
+        assert_eq!(missing.status, 404);
+        assert!(!missing.is_success());
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+
+        assert_eq!(
+            fetcher.fetch("not a url").await.unwrap_err(),
+            FetchError::InvalidUrl("not a url".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_evicts_least_recently_used() {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let base_url = start_server(hits.clone()).await;
+        let fetcher = Fetcher::with_cache_capacity(2, Duration::from_millis(10), 2);
+        let icon = format!("{}/icon.png", base_url);
+        let logo = format!("{}/logo.png", base_url);
+        let missing = format!("{}/missing", base_url);
+
+        fetcher.fetch(&icon).await.unwrap();
+        fetcher.fetch(&logo).await.unwrap();
+        // Using the icon again makes the logo the oldest entry
+        fetcher.fetch(&icon).await.unwrap();
+        fetcher.fetch(&missing).await.unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+
+        fetcher.fetch(&icon).await.unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+        fetcher.fetch(&logo).await.unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 4);
+    }
+}
@@ -0,0 +1,303 @@
+use std::collections::HashMap;
+
+use ego_tree::NodeId;
+use scraper::{ElementRef, Html, node::Node};
+
+use super::config::Finding;
+
+// Outer HTML longer than this is cut down to the start tag
+const MAX_SNIPPET_LENGTH: usize = 300;
+
+// Elements whose content is text, so tag-like text inside them isn't markup
+const RAW_TEXT_ELEMENTS: [&str; 8] = [
+    "script", "style", "textarea", "title", "xmp", "iframe", "noembed", "noframes",
+];
+
+impl Finding {
+    pub fn from_element(element: &ElementRef, positions: &SourcePositions) -> Self {
+        let (line, column) = positions
+            .get(element)
+            .map(|(line, column)| (Some(line), Some(column)))
+            .unwrap_or((None, None));
+
+        Finding {
+            selector: css_path(element),
+            html: snippet(element),
+            line,
+            column,
+            value: None,
+        }
+    }
+
+    pub fn with_value<S: Into<String>>(mut self, value: S) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+}
+
+// Builds a selector such as `html > body > div:nth-of-type(2) > img`,
+// anchored on the closest ancestor with an id when there is one.
+pub fn css_path(element: &ElementRef) -> String {
+    let mut parts = Vec::new();
+    let mut current = Some(*element);
+
+    while let Some(el) = current {
+        let name = el.value().name();
+        if let Some(id) = el.value().id() {
+            parts.push(format!("{}#{}", name, id));
+            break;
+        }
+
+        let same_tag_siblings = el
+            .parent()
+            .map(|parent| {
+                parent
+                    .children()
+                    .filter_map(ElementRef::wrap)
+                    .filter(|sibling| sibling.value().name() == name)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        if same_tag_siblings.len() > 1 {
+            let index = same_tag_siblings
+                .iter()
+                .position(|sibling| sibling.id() == el.id())
+                .unwrap_or(0);
+            parts.push(format!("{}:nth-of-type({})", name, index + 1));
+        } else {
+            parts.push(name.to_string());
+        }
+
+        current = el.parent().and_then(ElementRef::wrap);
+    }
+
+    parts.reverse();
+    parts.join(" > ")
+}
+
+fn snippet(element: &ElementRef) -> String {
+    let html = element.html();
+    if html.len() <= MAX_SNIPPET_LENGTH {
+        return html;
+    }
+
+    let start_tag_end = html.find('>').map(|i| i + 1).unwrap_or(html.len());
+    let mut end = start_tag_end.min(MAX_SNIPPET_LENGTH);
+    while !html.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}…", &html[..end])
+}
+
+// Line and column (1-based, in characters) of the start tag of each element of a
+// document. The parser does not keep source positions, so an element is matched
+// to the nth `<tag` of the raw source, where n is its index among elements with
+// the same tag name in document order. Elements the parser inserts implicitly
+// (e.g. a missing `<body>`) have no position.
+#[derive(Debug, Default)]
+pub struct SourcePositions {
+    positions: HashMap<NodeId, (u32, u32)>,
+}
+
+impl SourcePositions {
+    pub fn new(document: &Html, source: &str) -> Self {
+        let tags = start_tags(source);
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        let mut positions = HashMap::new();
+        for node in document.tree.root().descendants() {
+            let Node::Element(element) = node.value() else {
+                continue;
+            };
+            let name = element.name().to_ascii_lowercase();
+            let nth = seen.entry(name.clone()).or_default();
+            if let Some(position) = tags.get(&name).and_then(|tags| tags.get(*nth)) {
+                positions.insert(node.id(), *position);
+            }
+            *nth += 1;
+        }
+        Self { positions }
+    }
+
+    pub fn get(&self, element: &ElementRef) -> Option<(u32, u32)> {
+        self.positions.get(&element.id()).copied()
+    }
+}
+
+// Positions of the start tags per lowercased tag name, in source order, from one
+// pass over the source. Comments, CDATA sections and the content of raw text
+// elements are skipped so tag-like text in them doesn't shift the count.
+fn start_tags(source: &str) -> HashMap<String, Vec<(u32, u32)>> {
+    let lower = source.to_ascii_lowercase();
+    let mut tags: HashMap<String, Vec<(u32, u32)>> = HashMap::new();
+    let mut position = SourceCursor::new(lower.as_bytes());
+    let mut cursor = 0;
+
+    while let Some(found) = lower[cursor..].find('<') {
+        let start = cursor + found;
+        let rest = &lower[start..];
+
+        let skip_to = |end: &str| rest.find(end).map(|index| start + index + end.len());
+        let next = if rest.starts_with("<!--") {
+            skip_to("-->")
+        } else if rest.starts_with("<![cdata[") {
+            skip_to("]]>")
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            skip_to(">")
+        } else if rest[1..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+            let name_end = rest[1..]
+                .find(|c: char| c.is_ascii_whitespace() || c == '/' || c == '>')
+                .map_or(rest.len(), |index| index + 1);
+            let name = &rest[1..name_end];
+            position.advance_to(start);
+            tags.entry(name.to_string())
+                .or_default()
+                .push((position.line, position.column));
+
+            tag_end(&lower, start + name_end).map(|end| {
+                if RAW_TEXT_ELEMENTS.contains(&name) {
+                    lower[end..]
+                        .find(&format!("</{name}"))
+                        .map_or(lower.len(), |index| end + index)
+                } else {
+                    end
+                }
+            })
+        } else {
+            Some(start + 1)
+        };
+        let Some(next) = next else {
+            break;
+        };
+        cursor = next;
+    }
+
+    tags
+}
+
+// Offset just past the `>` closing the tag whose attributes start at `from`,
+// skipping `>` inside quoted attribute values
+fn tag_end(source: &str, from: usize) -> Option<usize> {
+    let mut quote = None;
+    let mut after_equals = false;
+    for (index, byte) in source.bytes().enumerate().skip(from) {
+        match quote {
+            Some(open) if byte == open => quote = None,
+            Some(_) => {}
+            None if byte == b'>' => return Some(index + 1),
+            None if after_equals && (byte == b'"' || byte == b'\'') => quote = Some(byte),
+            None => {}
+        }
+        if !byte.is_ascii_whitespace() {
+            after_equals = byte == b'=';
+        }
+    }
+    None
+}
+
+// Line and column of an offset, moving forward through the source only
+struct SourceCursor<'a> {
+    source: &'a [u8],
+    offset: usize,
+    line: u32,
+    column: u32,
+}
+
+impl<'a> SourceCursor<'a> {
+    fn new(source: &'a [u8]) -> Self {
+        Self {
+            source,
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn advance_to(&mut self, offset: usize) {
+        for &byte in &self.source[self.offset..offset] {
+            if byte == b'\n' {
+                self.line += 1;
+                self.column = 1;
+            } else if byte & 0xC0 != 0x80 {
+                // Counts characters, not UTF-8 continuation bytes
+                self.column += 1;
+            }
+        }
+        self.offset = offset;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scraper::{Html, Selector};
+
+    const SOURCE: &str = r#"<!DOCTYPE html>
+<html>
+    <body>
+        <!-- <img src="/commented.jpg"> -->
+        <div id="gallery">
+            <img src="/a.jpg" alt="A">
+            <img src="/b.jpg">
+        </div>
+        <p><img src="/c.jpg"></p>
+    </body>
+</html>"#;
+
+    fn select<'a>(document: &'a Html, selector: &str) -> Vec<ElementRef<'a>> {
+        let selector = Selector::parse(selector).unwrap();
+        document.select(&selector).collect()
+    }
+
+    #[test]
+    fn test_css_path() {
+        let document = Html::parse_document(SOURCE);
+        let images = select(&document, "img");
+        assert_eq!(css_path(&images[0]), "div#gallery > img:nth-of-type(1)");
+        assert_eq!(css_path(&images[1]), "div#gallery > img:nth-of-type(2)");
+        assert_eq!(css_path(&images[2]), "html > body > p > img");
+    }
+
+    #[test]
+    fn test_source_position_skips_comments() {
+        let document = Html::parse_document(SOURCE);
+        let positions = SourcePositions::new(&document, SOURCE);
+        let images = select(&document, "img");
+        assert_eq!(positions.get(&images[0]), Some((6, 13)));
+        assert_eq!(positions.get(&images[1]), Some((7, 13)));
+        assert_eq!(positions.get(&images[2]), Some((9, 12)));
+    }
+
+    #[test]
+    fn test_source_position_skips_raw_text() {
+        let source = r#"<html><head><title>A <img> tag</title>
+<script>document.write("<img src='/x.jpg'>")</script>
+<![CDATA[<img>]]></head>
+<body><p title="a > b">Ünïcode <img src="/a.jpg"></p></body></html>"#;
+        let document = Html::parse_document(source);
+        let positions = SourcePositions::new(&document, source);
+        let images = select(&document, "img");
+        assert_eq!(images.len(), 1);
+        assert_eq!(positions.get(&images[0]), Some((4, 32)));
+        assert_eq!(positions.get(&select(&document, "p")[0]), Some((4, 7)));
+    }
+
+    #[test]
+    fn test_finding_from_element() {
+        let document = Html::parse_document(SOURCE);
+        let positions = SourcePositions::new(&document, SOURCE);
+        let images = select(&document, "img");
+        let finding = Finding::from_element(&images[1], &positions).with_value("/b.jpg");
+        assert_eq!(finding.html, r#"<img src="/b.jpg">"#);
+        assert_eq!(finding.line, Some(7));
+        assert_eq!(finding.value, Some("/b.jpg".to_string()));
+    }
+
+    #[test]
+    fn test_implicit_elements_have_no_position() {
+        let source = "<p>Hello</p>";
+        let document = Html::parse_document(source);
+        let body = select(&document, "body");
+        assert_eq!(SourcePositions::new(&document, source).get(&body[0]), None);
+    }
+}
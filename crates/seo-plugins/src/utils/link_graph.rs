@@ -0,0 +1,222 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use super::link_parser::LinkType;
+use crate::site_analyzer::{LinkSourceType, PageLink};
+
+const DAMPING: f64 = 0.85;
+const MAX_ITERATIONS: usize = 100;
+const TOLERANCE: f64 = 1e-9;
+
+// Where a page sits in the site's internal link graph
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Default, PartialEq)]
+pub struct PageLinkMetrics {
+    // Distinct internal pages linking to the page
+    pub inlinks: u32,
+    // Distinct internal pages the page links to
+    pub outlinks: u32,
+    // Internal PageRank scaled so the strongest page of the site gets 100
+    pub link_score: f64,
+}
+
+#[derive(Debug)]
+struct GraphPage {
+    depth: Option<u32>,
+    metrics: PageLinkMetrics,
+}
+
+// The internal pages of a crawl and the links between them. A page linking to another
+// several times counts once, links from a page to itself don't count.
+#[derive(Debug, Default)]
+pub struct LinkGraph {
+    pages: BTreeMap<String, GraphPage>,
+}
+
+impl LinkGraph {
+    pub fn new(links: &HashMap<String, PageLink>) -> Self {
+        let urls: Vec<&String> = links
+            .iter()
+            .filter(|(_, link)| link.link_type == LinkType::Internal)
+            .map(|(url, _)| url)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let index: HashMap<&str, usize> = urls
+            .iter()
+            .enumerate()
+            .map(|(i, url)| (url.as_str(), i))
+            .collect();
+
+        let mut outlinks: Vec<Vec<usize>> = vec![vec![]; urls.len()];
+        let mut inlinks: Vec<Vec<usize>> = vec![vec![]; urls.len()];
+        let mut roots = vec![];
+        for (i, url) in urls.iter().enumerate() {
+            let Some(link) = links.get(*url) else {
+                continue;
+            };
+            if link
+                .found_in
+                .iter()
+                .any(|source| source.link_source_type == LinkSourceType::Root)
+            {
+                roots.push(i);
+            }
+            let targets: BTreeSet<usize> = link
+                .outlinks
+                .iter()
+                .filter_map(|outlink| index.get(outlink.href.as_str()).copied())
+                .filter(|target| *target != i)
+                .collect();
+            for target in &targets {
+                inlinks[*target].push(i);
+            }
+            outlinks[i] = targets.into_iter().collect();
+        }
+
+        let depths = Self::depths(&outlinks, &roots);
+        let ranks = Self::page_ranks(&outlinks, &inlinks);
+        let max_rank = ranks.iter().copied().fold(0.0, f64::max);
+
+        let pages = urls
+            .iter()
+            .enumerate()
+            .map(|(i, url)| {
+                let link_score = if max_rank > 0.0 {
+                    ranks[i] / max_rank * 100.0
+                } else {
+                    0.0
+                };
+                let page = GraphPage {
+                    depth: depths[i],
+                    metrics: PageLinkMetrics {
+                        inlinks: inlinks[i].len() as u32,
+                        outlinks: outlinks[i].len() as u32,
+                        link_score,
+                    },
+                };
+                (url.to_string(), page)
+            })
+            .collect();
+        Self { pages }
+    }
+
+    // Breadth-first from the root pages
+    fn depths(outlinks: &[Vec<usize>], roots: &[usize]) -> Vec<Option<u32>> {
+        let mut depths = vec![None; outlinks.len()];
+        let mut queue: VecDeque<(usize, u32)> = roots.iter().map(|root| (*root, 0)).collect();
+        while let Some((page, depth)) = queue.pop_front() {
+            if depths[page].is_some() {
+                continue;
+            }
+            depths[page] = Some(depth);
+            for target in &outlinks[page] {
+                queue.push_back((*target, depth + 1));
+            }
+        }
+        depths
+    }
+
+    // Power iteration, pages without outlinks spread their rank over every page
+    fn page_ranks(outlinks: &[Vec<usize>], inlinks: &[Vec<usize>]) -> Vec<f64> {
+        let count = outlinks.len();
+        if count == 0 {
+            return vec![];
+        }
+        let n = count as f64;
+        let mut ranks = vec![1.0 / n; count];
+        for _ in 0..MAX_ITERATIONS {
+            let dangling: f64 = (0..count)
+                .filter(|page| outlinks[*page].is_empty())
+                .map(|page| ranks[page])
+                .sum();
+            let next: Vec<f64> = (0..count)
+                .map(|page| {
+                    let linked: f64 = inlinks[page]
+                        .iter()
+                        .map(|source| ranks[*source] / outlinks[*source].len() as f64)
+                        .sum();
+                    (1.0 - DAMPING) / n + DAMPING * (linked + dangling / n)
+                })
+                .collect();
+            let change: f64 = next
+                .iter()
+                .zip(&ranks)
+                .map(|(next, rank)| (next - rank).abs())
+                .sum();
+            ranks = next;
+            if change < TOLERANCE {
+                break;
+            }
+        }
+        ranks
+    }
+
+    // Sets the depth and link metrics of every internal page of `links`
+    pub fn assign_metrics(links: &mut HashMap<String, PageLink>) {
+        let graph = Self::new(links);
+        for (url, link) in links.iter_mut() {
+            link.depth = graph.depth(url);
+            link.link_metrics = graph.metrics(url).cloned();
+        }
+    }
+
+    pub fn depth(&self, url: &str) -> Option<u32> {
+        self.pages.get(url).and_then(|page| page.depth)
+    }
+
+    pub fn metrics(&self, url: &str) -> Option<&PageLinkMetrics> {
+        self.pages.get(url).map(|page| &page.metrics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{page, url};
+
+    #[test]
+    fn test_link_graph() {
+        use LinkSourceType::*;
+
+        let mut links: HashMap<String, PageLink> = [
+            // `/a` twice and a self link, each counted once or not at all
+            page("/", Root, &["/", "/a", "/a", "/b", "/external"]),
+            page("/a", Link, &["/", "/b"]),
+            page("/b", Link, &["/", "/a", "/c"]),
+            page("/c", Link, &["/d"]),
+            page("/d", Link, &[]),
+            page("/sitemap-only", Sitemap, &[]),
+        ]
+        .into_iter()
+        .collect();
+        let (external, mut link) = page("/external", Link, &[]);
+        link.link_type = LinkType::External;
+        links.insert(external.clone(), link);
+
+        let graph = LinkGraph::new(&links);
+        assert_eq!(graph.depth(&url("/")), Some(0));
+        assert_eq!(graph.depth(&url("/b")), Some(1));
+        assert_eq!(graph.depth(&url("/d")), Some(3));
+        assert_eq!(graph.depth(&url("/sitemap-only")), None);
+        assert!(graph.metrics(&external).is_none());
+
+        let root = graph.metrics(&url("/")).unwrap();
+        assert_eq!((root.inlinks, root.outlinks), (2, 2));
+        let d = graph.metrics(&url("/d")).unwrap();
+        assert_eq!((d.inlinks, d.outlinks), (1, 0));
+        assert!(d.link_score < root.link_score);
+        // The strongest page scores 100
+        let max_score = ["/", "/a", "/b", "/c", "/d", "/sitemap-only"]
+            .iter()
+            .map(|path| graph.metrics(&url(path)).unwrap().link_score)
+            .fold(0.0, f64::max);
+        assert_eq!(max_score, 100.0);
+
+        LinkGraph::assign_metrics(&mut links);
+        assert_eq!(links[&url("/d")].depth, Some(3));
+        assert_eq!(links[&url("/d")].link_metrics.as_ref(), Some(d));
+        assert_eq!(links[&external].link_metrics, None);
+    }
+}
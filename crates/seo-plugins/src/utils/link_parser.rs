@@ -12,14 +12,66 @@ pub enum LinkType {
     Unknown,
 }
 
+// The part of the page a link sits in, from its closest landmark element
+#[derive(Debug, Serialize, Deserialize, Type, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LinkPosition {
+    Header,
+    Nav,
+    Main,
+    Aside,
+    Footer,
+    #[default]
+    Body,
+}
+
+impl LinkPosition {
+    pub fn from_element_name(name: &str) -> Option<Self> {
+        match name {
+            "header" => Some(LinkPosition::Header),
+            "nav" => Some(LinkPosition::Nav),
+            "main" => Some(LinkPosition::Main),
+            "aside" => Some(LinkPosition::Aside),
+            "footer" => Some(LinkPosition::Footer),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Type, Clone)]
 pub struct Link {
     pub href: String,
     pub path: String,
     pub link_type: LinkType,
+    // Set for links extracted from a page
+    #[serde(default)]
+    pub anchor_text: String,
+    #[serde(default)]
+    pub rel: Vec<String>,
+    #[serde(default)]
+    pub position: LinkPosition,
+    #[serde(default)]
+    pub title: Option<String>,
+    // Alt text of the images inside the link, `None` when it has no image
+    #[serde(default)]
+    pub image_alt: Option<String>,
+}
+
+impl Link {
+    // What the link is called for visitors and search engines: its text, falling back
+    // to the alt text of its images and its title
+    pub fn text(&self) -> Option<&str> {
+        [
+            Some(self.anchor_text.as_str()),
+            self.image_alt.as_deref(),
+            self.title.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .find(|text| !text.is_empty())
+    }
 }
 
-#[derive(Debug, Error)]
+#[derive(Debug, Error, Clone)]
 pub enum LinkParseError {
     #[error("Failed to parse link: {0}")]
     LinkParseError(String),
@@ -51,6 +103,11 @@ pub fn parse_link<W: FromUrl>(link: &str, base_url: W) -> Result<Link, LinkParse
         href: link.to_string(),
         path: link.path().to_string(),
         link_type,
+        anchor_text: String::new(),
+        rel: vec![],
+        position: LinkPosition::default(),
+        title: None,
+        image_alt: None,
     })
 }
 
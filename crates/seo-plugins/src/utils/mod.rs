@@ -1,9 +1,20 @@
 pub mod config;
+pub mod content;
 pub mod crawl_config;
+pub mod fetcher;
+pub mod finding;
+pub mod link_graph;
 pub mod link_parser;
 pub mod page;
 pub mod page_plugin;
+pub mod plugin_outputs;
+pub mod profile;
 pub mod registry;
+pub mod rule_param;
+pub mod share_preview;
 
 pub mod site_plugin;
 pub mod sitemap_parser;
+pub mod structured_data;
+pub mod text;
+pub mod text_width;
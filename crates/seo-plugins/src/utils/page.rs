@@ -1,10 +1,12 @@
 use reqwest::{Client, redirect};
 use scraper::{
-    Html, Selector,
+    ElementRef, Html, Selector,
     node::Element,
 };
+use parking_lot::{MappedReentrantMutexGuard, ReentrantMutex, ReentrantMutexGuard};
 use serde::{Deserialize, Serialize};
 use specta::Type;
+use std::cell::OnceCell;
 use std::sync::atomic::{AtomicU16, Ordering};
 use std::sync::{Arc, Mutex as StdMutex};
 use std::{collections::HashMap, num::NonZeroU16, time::Duration};
@@ -12,9 +14,19 @@ use thiserror::Error;
 use tokio::time::Instant;
 use url::Url;
 
-use super::link_parser::{parse_link, FromUrl, Link, LinkParseError};
+use super::config::Finding;
+use super::content::{self, ContentMetrics};
+use super::finding::SourcePositions;
+use super::link_parser::{parse_link, FromUrl, Link, LinkParseError, LinkPosition};
+use super::plugin_outputs::PluginOutputs;
+use super::share_preview::SharePreview;
+use super::structured_data::StructuredData;
+use super::text::collapse_whitespace;
 
-#[derive(Debug, Error)]
+// Every heading level, `select` returns them in document order
+pub const HEADING_SELECTOR: &str = "h1, h2, h3, h4, h5, h6";
+
+#[derive(Debug, Error, Clone)]
 pub enum PageError {
     #[error("Failed to parse URL: {0}")]
     UrlParseError(#[from] LinkParseError),
@@ -34,15 +46,34 @@ pub enum PageError {
     MutexError(String),
 }
 
+// The parsed document, shared by every rule that runs against the page
+pub type Document<'a> = MappedReentrantMutexGuard<'a, Html>;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Page {
     url: Option<Url>,
-    html: Option<String>,
+    html: Option<Arc<str>>,
+    // Parsed lazily on first access. `Html` is not `Sync`, so it sits behind a
+    // lock; the lock is reentrant so rules can call other page helpers while
+    // holding the document.
+    #[serde(skip)]
+    document: Arc<ReentrantMutex<OnceCell<Html>>>,
+    // Source positions of the document's elements, built on the first finding
+    #[serde(skip)]
+    source_positions: Arc<StdMutex<Option<Arc<SourcePositions>>>>,
     meta_tags: Arc<StdMutex<Option<MetaTagInfo>>>,
     images: Arc<StdMutex<Option<Vec<Image>>>>,
+    structured_data: Arc<StdMutex<Option<StructuredData>>>,
+    main_text: Arc<StdMutex<Option<String>>>,
     content_length: Option<u64>,
     elapsed: Option<f32>,
     status_code: Option<NonZeroU16>,
+    // Response headers, names lowercased and repeated values joined with ", "
+    #[serde(default)]
+    headers: HashMap<String, String>,
+    // Data plugins derived from this page, see `SeoPlugin::derive_outputs`
+    #[serde(skip)]
+    outputs: PluginOutputs,
 }
 
 const FALLBACK_URL: &str = "https://example.com";
@@ -51,13 +82,19 @@ impl Page {
     pub fn from_html(html: String) -> Self {
         Self {
             url: None,
-            html: Some(html),
+            html: Some(html.into()),
+            document: Default::default(),
+            source_positions: Default::default(),
             meta_tags: Arc::new(StdMutex::new(None)),
             images: Arc::new(StdMutex::new(None)),
+            structured_data: Arc::new(StdMutex::new(None)),
+            main_text: Arc::new(StdMutex::new(None)),
             content_length: None,
             
             elapsed: None,
             status_code: None,
+            headers: HashMap::new(),
+            outputs: PluginOutputs::default(),
         }
     }
 
@@ -76,8 +113,8 @@ impl Page {
             .unwrap_or(Url::parse(FALLBACK_URL).unwrap())
     }
 
-    pub fn get_html(&self) -> Option<String> {
-        self.html.clone()
+    pub fn get_html(&self) -> Option<&str> {
+        self.html.as_deref()
     }
 
     pub fn get_content_length(&self) -> Option<u64> {
@@ -94,7 +131,14 @@ impl Page {
     }
 
     pub fn set_content(&mut self, html: String) {
-        self.html = Some(html);
+        self.html = Some(html.into());
+        self.document = Default::default();
+        self.source_positions = Default::default();
+        self.meta_tags = Default::default();
+        self.images = Default::default();
+        self.structured_data = Default::default();
+        self.main_text = Default::default();
+        self.outputs = Default::default();
     }
 
     pub fn get_elapsed(&self) -> Option<f32> {
@@ -105,6 +149,18 @@ impl Page {
         self.status_code
     }
 
+    pub fn get_header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_lowercase()).map(|value| value.as_str())
+    }
+
+    pub fn get_headers(&self) -> &HashMap<String, String> {
+        &self.headers
+    }
+
+    pub fn outputs(&self) -> &PluginOutputs {
+        &self.outputs
+    }
+
     pub async fn from_url<T: FromUrl>(url: T) -> Result<Self, PageError> {
         let url = url.to_url().map_err(PageError::UrlParseError)?;
         let redirect_status_code = Arc::new(AtomicU16::new(0));
@@ -137,6 +193,21 @@ impl Page {
             .headers()
             .get("content-length")
             .and_then(|value| value.to_str().ok().and_then(|s| s.parse::<u64>().ok()));
+        // A header sent several times, e.g. one Link header per link, is joined the
+        // way HTTP allows combining them
+        let mut headers: HashMap<String, String> = HashMap::new();
+        for (name, value) in response.headers() {
+            let Ok(value) = value.to_str() else {
+                continue;
+            };
+            headers
+                .entry(name.as_str().to_lowercase())
+                .and_modify(|joined| {
+                    joined.push_str(", ");
+                    joined.push_str(value);
+                })
+                .or_insert_with(|| value.to_string());
+        }
 
         if !response.status().is_success() {
             return Err(PageError::FetchError(format!(
@@ -152,21 +223,30 @@ impl Page {
 
         Ok(Self {
             url: Some(url),
-            html: Some(body),
+            html: Some(body.into()),
+            document: Default::default(),
+            source_positions: Default::default(),
             meta_tags: Arc::new(StdMutex::new(None)),
             images: Arc::new(StdMutex::new(None)),
+            structured_data: Arc::new(StdMutex::new(None)),
+            main_text: Arc::new(StdMutex::new(None)),
             content_length,
             elapsed: Some(elapsed),
             status_code: NonZeroU16::new(status_code),
+            headers,
+            outputs: PluginOutputs::default(),
         })
     }
 
-    pub fn get_document(&self) -> Result<Html, PageError> {
+    pub fn get_document(&self) -> Result<Document<'_>, PageError> {
         let html = self
             .html
-            .as_ref()
+            .as_deref()
             .ok_or(PageError::DocumentNotSet("Document is not set".to_string()))?;
-        Ok(Html::parse_document(html))
+        let document = self.document.lock();
+        Ok(ReentrantMutexGuard::map(document, |document| {
+            document.get_or_init(|| Html::parse_document(html))
+        }))
     }
 
     pub fn get_element(&self, selector: &str) -> Result<Element, PageError> {
@@ -179,6 +259,27 @@ impl Page {
             .ok_or(PageError::ElementNotFound)?;
         Ok(element.value().clone())
     }
+
+    // Describe an element of this page's document for a rule finding
+    pub fn finding(&self, element: &ElementRef) -> Finding {
+        Finding::from_element(element, &self.source_positions())
+    }
+
+    // Locks the document first, so a rule holding it can still ask for findings
+    fn source_positions(&self) -> Arc<SourcePositions> {
+        let Ok(document) = self.get_document() else {
+            return Default::default();
+        };
+        let Ok(mut cached) = self.source_positions.lock() else {
+            return Default::default();
+        };
+        cached
+            .get_or_insert_with(|| {
+                let source = self.html.as_deref().unwrap_or_default();
+                Arc::new(SourcePositions::new(&document, source))
+            })
+            .clone()
+    }
 }
 
 impl Page {
@@ -275,6 +376,12 @@ impl Page {
                             meta_tags.generators.push(content.to_string());
                         }
                     }
+                    // Twitter documents `name`, `property` is handled below with the og tags
+                    name if name.starts_with("twitter:") => {
+                        if let Some(value) = meta.value().attr("content") {
+                            meta_tags.twitter_tags.insert(name.trim_start_matches("twitter:").to_string(), value.to_string());
+                        }
+                    }
 
                     _ => {}
                 }
@@ -318,6 +425,7 @@ impl Page {
     pub fn extract_links(&self) -> Result<Vec<Link>, PageError> {
         let document = self.get_document()?;
         let link_selector = Selector::parse("a").map_err(|e| PageError::SelectorParseError(e.to_string()))?;
+        let image_selector = Selector::parse("img").map_err(|e| PageError::SelectorParseError(e.to_string()))?;
         let mut links = Vec::new();
 
         for link in document.select(&link_selector) {
@@ -326,35 +434,131 @@ impl Page {
                     .url
                     .clone()
                     .unwrap_or(Url::parse(FALLBACK_URL).map_err(|e| PageError::LinkParseError(e.to_string()))?);
-                let link = parse_link(href, base_url)
+                let mut parsed = parse_link(href, base_url)
                     .map_err(|e| PageError::LinkParseError(e.to_string()))?;
-                links.push(link);
+                parsed.anchor_text = link
+                    .text()
+                    .collect::<String>()
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                parsed.rel = link
+                    .value()
+                    .attr("rel")
+                    .map(|rel| rel.split_whitespace().map(|value| value.to_lowercase()).collect())
+                    .unwrap_or_default();
+                parsed.position = link
+                    .ancestors()
+                    .filter_map(ElementRef::wrap)
+                    .find_map(|ancestor| LinkPosition::from_element_name(ancestor.value().name()))
+                    .unwrap_or_default();
+                parsed.title = link
+                    .value()
+                    .attr("title")
+                    .map(|title| title.trim().to_string())
+                    .filter(|title| !title.is_empty());
+                let image_alts: Vec<&str> = link
+                    .select(&image_selector)
+                    .map(|image| image.value().attr("alt").unwrap_or_default().trim())
+                    .collect();
+                parsed.image_alt = (!image_alts.is_empty()).then(|| image_alts.join(" ").trim().to_string());
+                links.push(parsed);
             }
         }
 
         Ok(links)
     }
-    pub fn extract_headings(&self) -> Result<Vec<Heading>, PageError> {
+    // JSON-LD, Microdata and RDFa items
+    pub fn extract_structured_data(&self) -> Result<StructuredData, PageError> {
+        let mut cached = self.structured_data.lock().map_err(|e| PageError::MutexError(e.to_string()))?;
+        if let Some(structured_data) = cached.as_ref() {
+            return Ok(structured_data.clone());
+        }
         let document = self.get_document()?;
-        let mut headings: Vec<Heading> = Vec::new();
-
-        for i in 1..=6 {
-            let selector = format!("h{}", i);
-            let heading_selector = Selector::parse(&selector).map_err(|e| PageError::SelectorParseError(e.to_string()))?;
-            let res = document.select(&heading_selector);
-            for heading in res {
-                let text = heading.inner_html();
-                headings.push(Heading {
-                    tag: selector.clone(),
-                    text,
-                });
-            }
+        let structured_data = StructuredData::extract(&document, |element| self.finding(element));
+        Ok(cached.insert(structured_data).clone())
+    }
+
+    // Visible text of the main content, without navigation and other boilerplate
+    pub fn extract_main_text(&self) -> Result<String, PageError> {
+        let mut cached = self.main_text.lock().map_err(|e| PageError::MutexError(e.to_string()))?;
+        if let Some(main_text) = cached.as_ref() {
+            return Ok(main_text.clone());
         }
+        let document = self.get_document()?;
+        Ok(cached.insert(content::main_text(&document)).clone())
+    }
+
+    // Headings in document order, with their text content
+    pub fn extract_headings(&self) -> Result<Vec<Heading>, PageError> {
+        self.map_headings(|heading, _| heading)
+    }
+
+    // Like `extract_headings`, also passing each heading's element to `map`
+    pub fn map_headings<T>(
+        &self,
+        map: impl Fn(Heading, &ElementRef) -> T,
+    ) -> Result<Vec<T>, PageError> {
+        let document = self.get_document()?;
+        let heading_selector = Selector::parse(HEADING_SELECTOR).map_err(|e| PageError::SelectorParseError(e.to_string()))?;
+
+        let headings = document
+            .select(&heading_selector)
+            .map(|element| {
+                let tag = element.value().name().to_string();
+                let heading = Heading {
+                    level: tag[1..].parse().unwrap_or_default(),
+                    text: collapse_whitespace(&element.text().collect::<String>()),
+                    tag,
+                };
+                map(heading, &element)
+            })
+            .collect();
 
         Ok(headings)
     }
 }
 
+// What the crawler learned about a fetched page, besides its rule results
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Default, PartialEq)]
+pub struct PageMetadata {
+    pub status_code: Option<u16>,
+    pub response_time_ms: Option<u32>,
+    // From the content-length header, or the body size when it is missing
+    pub content_length: Option<u32>,
+    pub redirected: bool,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub canonical: Option<String>,
+    pub share_preview: SharePreview,
+    // Published by the content quality plugin, `None` when it didn't run
+    pub content_metrics: Option<ContentMetrics>,
+}
+
+impl From<&Page> for PageMetadata {
+    fn from(page: &Page) -> Self {
+        let meta_tags = page.extract_meta_tags();
+        let content_length = page
+            .get_content_length()
+            .or_else(|| page.get_html().map(|html| html.len() as u64));
+        Self {
+            status_code: page.get_status_code().map(u16::from),
+            response_time_ms: page.get_elapsed().map(|elapsed| elapsed.round() as u32),
+            content_length: content_length
+                .map(|length| u32::try_from(length).unwrap_or(u32::MAX)),
+            redirected: page.get_redirected(),
+            title: meta_tags.title,
+            description: meta_tags.description,
+            canonical: meta_tags.canonical,
+            share_preview: SharePreview::from(page),
+            content_metrics: page
+                .outputs()
+                .get::<ContentMetrics>()
+                .map(|metrics| (*metrics).clone()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Type, Default)]
 pub struct MetaTagInfo {
     pub title: Option<String>,
@@ -384,6 +588,8 @@ pub struct Image {
 #[derive(Debug, Serialize, Deserialize, Type, Clone)]
 pub struct Heading {
     pub tag: String,
+    // 1 for h1 through 6 for h6
+    pub level: u8,
     pub text: String,
 }
 
@@ -399,6 +605,22 @@ mod tests {
     use std::net::SocketAddr;
     use tokio::net::TcpListener;
 
+    #[test]
+    fn test_document_is_parsed_once() {
+        let mut page = Page::from_html("<html><body><p>One</p></body></html>".to_string());
+        let clone = page.clone();
+
+        let document = page.get_document().unwrap();
+        // Clones share the parsed document, and it can be re-borrowed while held
+        assert!(std::ptr::eq(&*document, &*clone.get_document().unwrap()));
+        assert_eq!(page.extract_headings().unwrap().len(), 0);
+        drop(document);
+
+        page.set_content("<html><body><h1>Two</h1></body></html>".to_string());
+        assert_eq!(page.extract_headings().unwrap().len(), 1);
+        assert_eq!(clone.extract_headings().unwrap().len(), 0);
+    }
+
     #[test]
     fn test_document_extract_links_no_base_url() {
         let page = Page::from_html(
@@ -422,6 +644,42 @@ mod tests {
         assert_eq!(links[1].path, "/test");
         assert_eq!(links[1].link_type, LinkType::External);
     }
+
+    #[test]
+    fn test_document_extract_link_details() {
+        let page = Page::from_html(
+            r#"
+            <html>
+                <body>
+                    <nav><ul><li><a href="/about">About <b>us</b></a></li></ul></nav>
+                    <p><a href="/partner" rel="NoFollow sponsored">
+                        Our   partner
+                    </a></p>
+                    <footer><a href="/contact"></a></footer>
+                    <a href="/" title=" Home "><img src="/logo.png" alt="Example"></a>
+                    <a href="/cart"><img src="/cart.png"></a>
+                </body>
+            </html>
+            "#
+            .to_string(),
+        );
+        let links = page.extract_links().unwrap();
+        assert_eq!(links[0].anchor_text, "About us");
+        assert_eq!(links[0].position, LinkPosition::Nav);
+        assert_eq!(links[1].anchor_text, "Our partner");
+        assert_eq!(links[1].rel, vec!["nofollow", "sponsored"]);
+        assert_eq!(links[1].position, LinkPosition::Body);
+        assert_eq!(links[2].anchor_text, "");
+        assert_eq!(links[2].position, LinkPosition::Footer);
+        assert_eq!(links[2].image_alt, None);
+        assert_eq!(links[2].text(), None);
+        assert_eq!(links[3].title.as_deref(), Some("Home"));
+        assert_eq!(links[3].image_alt.as_deref(), Some("Example"));
+        assert_eq!(links[3].text(), Some("Example"));
+        assert_eq!(links[4].image_alt.as_deref(), Some(""));
+        assert_eq!(links[4].text(), None);
+    }
+
     #[test]
     fn test_document_extract_links_with_base_url() {
         let mut page = Page::from_html(
@@ -458,6 +716,7 @@ mod tests {
                         <meta name="robots" content="index, follow">
                         <meta property="og:title" content="Test OG Title">
                         <meta property="twitter:card" content="summary">
+                        <meta name="twitter:site" content="@example">
                         <link rel="sitemap" href="https://example.com/sitemap.xml">
                     </head>
                 </html>
@@ -482,6 +741,10 @@ mod tests {
             meta_tags.twitter_tags.get("card"),
             Some(&"summary".to_string())
         );
+        assert_eq!(
+            meta_tags.twitter_tags.get("site"),
+            Some(&"@example".to_string())
+        );
         assert_eq!(
             meta_tags.sitemap,
             Some("https://example.com/sitemap.xml".to_string())
@@ -517,8 +780,9 @@ mod tests {
                 <html>
                     <body>
                         <h1>Main Heading</h1>
-                        <h2>Subheading</h2>
                         <h3>Another Subheading</h3>
+                        <h2>Sub<em>heading</em>
+                        </h2>
                     </body>
                 </html>
             "#;
@@ -531,10 +795,12 @@ mod tests {
         assert_eq!(headings.len(), 3);
         assert_eq!(headings[0].tag, "h1");
         assert_eq!(headings[0].text, "Main Heading");
-        assert_eq!(headings[1].tag, "h2");
-        assert_eq!(headings[1].text, "Subheading");
-        assert_eq!(headings[2].tag, "h3");
-        assert_eq!(headings[2].text, "Another Subheading");
+        assert_eq!(headings[0].level, 1);
+        assert_eq!(headings[1].tag, "h3");
+        assert_eq!(headings[1].level, 3);
+        assert_eq!(headings[1].text, "Another Subheading");
+        assert_eq!(headings[2].tag, "h2");
+        assert_eq!(headings[2].text, "Subheading");
     }
 
     #[tokio::test]
@@ -559,6 +825,27 @@ mod tests {
         );
         assert_eq!(tags.twitter_tags.get("card"), Some(&"summary".to_string()));
         assert_eq!(tags.canonical, Some("https://example.com".to_string()));
+        assert!(parser.get_header("Content-Length").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_repeated_headers_are_joined() {
+        let addr = crate::test_utils::start_test_server(|_| {
+            Response::builder()
+                .header("Link", r#"<https://example.com/>; rel="canonical""#)
+                .header("Link", r#"<https://example.com/feed>; rel="alternate""#)
+                .body(Body::from("<html></html>"))
+                .unwrap()
+        })
+        .await;
+        let page = Page::from_url(format!("http://{}", addr)).await.unwrap();
+
+        assert_eq!(
+            page.get_header("link"),
+            Some(
+                r#"<https://example.com/>; rel="canonical", <https://example.com/feed>; rel="alternate""#
+            )
+        );
     }
 
     async fn start_test_server() -> SocketAddr {
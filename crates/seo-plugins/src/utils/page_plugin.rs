@@ -1,8 +1,8 @@
 use futures::stream::{self, StreamExt};
 use std::any::{Any, TypeId};
 
-use super::config::{Rule, RuleConfig, RuleResult, SiteCheckContext};
-use super::page::Page;
+use super::config::{AsyncRule, Rule, RuleConfig, RuleDisplay, RuleResult};
+use super::page::{Page, PageError};
 use super::registry::PluginRegistry;
 
 // Main plugin trait
@@ -12,11 +12,17 @@ pub trait SeoPlugin: Send + Sync + 'static {
     fn description(&self) -> &str;
     fn as_any(&self) -> &dyn Any;
 
-    // What other plugins this one depends on
+    // What other plugins this one depends on, they derive their outputs first
     fn dependencies(&self) -> Vec<TypeId> {
         vec![]
     }
 
+    // Publish data derived from the page to `page.outputs()` for dependent plugins
+    // and site plugins. Runs for every plugin, in dependency order, before any rules.
+    fn derive_outputs(&self, _page: &Page) -> Result<(), PageError> {
+        Ok(())
+    }
+
     // Initialize the plugin with access to its dependencies
     fn initialize(&self, _registry: &PluginRegistry) -> Result<(), String> {
         Ok(())
@@ -25,6 +31,18 @@ pub trait SeoPlugin: Send + Sync + 'static {
     // Get available rules this plugin can check
     fn available_rules(&self) -> Vec<Rule>;
 
+    // Rules that await, e.g. to fetch URLs; the registry schedules them alongside `analyze_async`
+    fn async_rules(&self) -> Vec<AsyncRule> {
+        vec![]
+    }
+
+    // Rule metadata for display and seeding, override when rules aren't known at compile time
+    fn rule_displays(&self) -> Vec<RuleDisplay> {
+        let rules: Vec<RuleDisplay> = self.available_rules().into_iter().collect();
+        let async_rules = self.async_rules().iter().map(RuleDisplay::from).collect();
+        [rules, async_rules].concat()
+    }
+
     async fn analyze_async(&self, page: &Page, config: &RuleConfig) -> Vec<RuleResult> {
         let available_rules = self.available_rules();
         let rules: Vec<&Rule> = available_rules
@@ -34,17 +52,8 @@ pub trait SeoPlugin: Send + Sync + 'static {
 
         stream::iter(rules)
             .map(|rule| {
-                let result = (rule.check)(page);
-                RuleResult {
-                    rule_id: rule.id.to_string(),
-                    name: rule.name.to_string(),
-                    plugin_name: self.name().to_string(),
-                    passed: result.passed,
-                    message: result.message,
-                    severity: rule.default_severity.clone(),
-                    category: rule.category.clone(),
-                    context: SiteCheckContext::Empty,
-                }
+                let result = rule.run(page, config);
+                rule.to_result(self.name(), config, result)
             })
             .collect::<Vec<_>>()
             .await
@@ -56,17 +65,8 @@ pub trait SeoPlugin: Send + Sync + 'static {
             .iter()
             .filter(|rule| config.is_rule_enabled(rule.id))
             .map(|rule| {
-                let result = (rule.check)(page);
-                RuleResult {
-                    rule_id: rule.id.to_string(),
-                    name: rule.name.to_string(),
-                    plugin_name: self.name().to_string(),
-                    passed: result.passed,
-                    message: result.message,
-                    severity: rule.default_severity.clone(),
-                    category: rule.category.clone(),
-                    context: SiteCheckContext::Empty,
-                }
+                let result = rule.run(page, config);
+                rule.to_result(self.name(), config, result)
             })
             .collect()
     }
@@ -0,0 +1,60 @@
+use parking_lot::RwLock;
+use std::any::{Any, TypeId, type_name};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+type Output = Arc<dyn Any + Send + Sync>;
+
+// Typed data plugins derive from a page, keyed by type. A plugin publishes e.g. a word
+// count once and plugins that depend on it read it instead of recomputing it.
+#[derive(Clone, Default)]
+pub struct PluginOutputs {
+    values: Arc<RwLock<HashMap<TypeId, (&'static str, Output)>>>,
+}
+
+impl PluginOutputs {
+    // Replaces any earlier output of the same type
+    pub fn insert<T: Any + Send + Sync>(&self, value: T) {
+        self.values
+            .write()
+            .insert(TypeId::of::<T>(), (type_name::<T>(), Arc::new(value)));
+    }
+
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<Arc<T>> {
+        let (_, value) = self.values.read().get(&TypeId::of::<T>())?.clone();
+        value.downcast::<T>().ok()
+    }
+
+    pub fn contains<T: Any + Send + Sync>(&self) -> bool {
+        self.values.read().contains_key(&TypeId::of::<T>())
+    }
+}
+
+impl fmt::Debug for PluginOutputs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let values = self.values.read();
+        f.debug_list()
+            .entries(values.values().map(|(name, _)| name))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct WordCount(usize);
+
+    #[test]
+    fn test_outputs_are_typed_and_shared_by_clones() {
+        let outputs = PluginOutputs::default();
+        assert!(outputs.get::<WordCount>().is_none());
+
+        let clone = outputs.clone();
+        clone.insert(WordCount(120));
+        assert_eq!(*outputs.get::<WordCount>().unwrap(), WordCount(120));
+        assert!(!outputs.contains::<String>());
+    }
+}
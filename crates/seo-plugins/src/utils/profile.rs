@@ -0,0 +1,233 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+use super::config::{RuleCategory, RuleConfig, RuleDisplay, RuleSettings};
+
+#[derive(Debug, Error)]
+pub enum ProfileError {
+    #[error("Invalid TOML profile: {0}")]
+    TomlParseError(#[from] toml::de::Error),
+    #[error("Failed to write TOML profile: {0}")]
+    TomlWriteError(#[from] toml::ser::Error),
+    #[error("Invalid JSON profile: {0}")]
+    JsonError(#[from] serde_json::Error),
+    #[error("Profile name is empty")]
+    EmptyName,
+}
+
+#[derive(Debug, Serialize, Deserialize, Type, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileFormat {
+    Toml,
+    Json,
+}
+
+// A named, shareable set of rule settings, e.g. "Accessibility only"
+#[derive(Debug, Serialize, Deserialize, Type, Clone, PartialEq)]
+pub struct RuleProfile {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    // Rules missing from the profile are disabled, unless `enable_unlisted` is set
+    #[serde(default)]
+    pub rules: BTreeMap<String, RuleSettings>,
+    #[serde(default)]
+    pub enable_unlisted: bool,
+}
+
+impl RuleProfile {
+    pub fn new(name: &str, description: &str, config: &RuleConfig) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            rules: config.to_settings(),
+            enable_unlisted: config.enables_unlisted(),
+        }
+    }
+
+    // Every rule enabled at its default severity, including custom and WASM rules
+    // and rules added after the profile was saved
+    pub fn full_audit() -> Self {
+        Self {
+            name: "Full audit".to_string(),
+            description: "Runs every available rule".to_string(),
+            rules: BTreeMap::new(),
+            enable_unlisted: true,
+        }
+    }
+
+    pub fn for_categories(
+        name: &str,
+        description: &str,
+        rules: &[RuleDisplay],
+        categories: &[RuleCategory],
+    ) -> Self {
+        Self::from_rules(name, description, rules, |rule| {
+            categories.contains(&rule.category)
+        })
+    }
+
+    // Profiles seeded into storage on first run
+    pub fn presets(rules: &[RuleDisplay]) -> Vec<Self> {
+        vec![
+            Self::full_audit(),
+            Self::for_categories(
+                "Accessibility only",
+                "Runs the accessibility rules",
+                rules,
+                &[RuleCategory::Accessibility],
+            ),
+        ]
+    }
+
+    fn from_rules(
+        name: &str,
+        description: &str,
+        rules: &[RuleDisplay],
+        enabled: impl Fn(&RuleDisplay) -> bool,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            rules: rules
+                .iter()
+                .map(|rule| {
+                    let settings = RuleSettings {
+                        enabled: enabled(rule),
+                        ..Default::default()
+                    };
+                    (rule.id.clone(), settings)
+                })
+                .collect(),
+            enable_unlisted: false,
+        }
+    }
+
+    pub fn to_config(&self) -> RuleConfig {
+        let mut config = RuleConfig::from_settings(&self.rules);
+        config.set_enable_unlisted(self.enable_unlisted);
+        config
+    }
+
+    pub fn import(contents: &str, format: ProfileFormat) -> Result<Self, ProfileError> {
+        let profile: Self = match format {
+            ProfileFormat::Toml => toml::from_str(contents)?,
+            ProfileFormat::Json => serde_json::from_str(contents)?,
+        };
+        if profile.name.trim().is_empty() {
+            return Err(ProfileError::EmptyName);
+        }
+        Ok(profile)
+    }
+
+    pub fn export(&self, format: ProfileFormat) -> Result<String, ProfileError> {
+        let contents = match format {
+            ProfileFormat::Toml => toml::to_string_pretty(self)?,
+            ProfileFormat::Json => serde_json::to_string_pretty(self)?,
+        };
+        Ok(contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::config::Severity;
+    use crate::utils::registry::PluginRegistry;
+    use crate::utils::rule_param::RuleParamValue;
+
+    fn client_profile() -> RuleProfile {
+        let mut config = RuleConfig::new();
+        config.enable_rule("title.has_title");
+        config.disable_rule("image.alt_text");
+        config.set_severity("title.has_title", Severity::Critical);
+        config.set_param(
+            "title.title_length",
+            "max_length",
+            RuleParamValue::Number(65.0),
+        );
+        RuleProfile::new("Client X", "Stricter titles", &config)
+    }
+
+    #[test]
+    fn test_profile_round_trips_through_toml_and_json() {
+        let profile = client_profile();
+
+        for format in [ProfileFormat::Toml, ProfileFormat::Json] {
+            let exported = profile.export(format).unwrap();
+            let imported = RuleProfile::import(&exported, format).unwrap();
+            assert_eq!(imported, profile);
+        }
+
+        let config = profile.to_config();
+        assert!(config.is_rule_enabled("title.has_title"));
+        assert!(!config.is_rule_enabled("image.alt_text"));
+        assert_eq!(
+            config.get_severity("title.has_title", Severity::Warning),
+            Severity::Critical
+        );
+        assert_eq!(
+            config.get_param("title.title_length", "max_length"),
+            Some(&RuleParamValue::Number(65.0))
+        );
+    }
+
+    #[test]
+    fn test_import_hand_written_toml() {
+        let contents = r#"
+            name = "Pre-launch"
+
+            [rules."title.has_title"]
+            enabled = true
+            severity = "Error"
+        "#;
+        let profile = RuleProfile::import(contents, ProfileFormat::Toml).unwrap();
+        assert_eq!(profile.description, "");
+
+        let config = profile.to_config();
+        assert!(config.is_rule_enabled("title.has_title"));
+        assert!(!config.is_rule_enabled("image.alt_text"));
+
+        assert!(matches!(
+            RuleProfile::import("name = \"\"", ProfileFormat::Toml),
+            Err(ProfileError::EmptyName)
+        ));
+    }
+
+    #[test]
+    fn test_full_audit_enables_unlisted_rules() {
+        let mut profile = RuleProfile::full_audit();
+        profile.rules.insert(
+            "image.alt_text".to_string(),
+            RuleSettings {
+                enabled: false,
+                ..Default::default()
+            },
+        );
+        let exported = profile.export(ProfileFormat::Toml).unwrap();
+        let config = RuleProfile::import(&exported, ProfileFormat::Toml)
+            .unwrap()
+            .to_config();
+        assert!(config.is_rule_enabled("custom.added_later"));
+        assert!(!config.is_rule_enabled("image.alt_text"));
+    }
+
+    #[test]
+    fn test_category_preset_only_enables_category() {
+        let rules = PluginRegistry::default().get_available_rules();
+        let profile = RuleProfile::for_categories(
+            "Accessibility only",
+            "",
+            &rules,
+            &[RuleCategory::Accessibility],
+        );
+        let config = profile.to_config();
+        for rule in &rules {
+            assert_eq!(
+                config.is_rule_enabled(&rule.id),
+                rule.category == RuleCategory::Accessibility
+            );
+        }
+    }
+}
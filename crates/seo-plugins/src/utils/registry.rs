@@ -1,27 +1,83 @@
 use crate::plugins::axe::AxePlugin;
+use crate::plugins::content_quality::ContentQualityPlugin;
+use crate::plugins::headings::HeadingsPlugin;
 use crate::plugins::image::ImagePlugin;
+use crate::plugins::main_text::MainTextPlugin;
 use crate::plugins::request::RequestPlugin;
+use crate::plugins::resources::ResourcesPlugin;
 use crate::plugins::seo_basic::SeoBasicPlugin;
+use crate::plugins::social::SocialPlugin;
+use crate::plugins::structured_data::StructuredDataPlugin;
 use crate::plugins::title::TitlePlugin;
 use crate::site_analyzer::SiteAnalyzer;
-use crate::site_plugins::MetaDescriptionSitePlugin;
 use crate::site_plugins::orphaned_page::OrphanedPagePlugin;
+use crate::site_plugins::{
+    AnchorTextPlugin, CanonicalPlugin, DuplicateContentPlugin, LinkGraphPlugin, UniquenessPlugin,
+};
 use parking_lot::RwLock;
 use std::any::TypeId;
 use std::collections::HashMap;
 use std::fmt;
-use std::sync::{Arc, Mutex as StdMutex};
+use std::sync::Arc;
+use thiserror::Error;
 
-use super::config::{RuleConfig, RuleDisplay, RuleResult};
+use super::config::{CheckError, RuleConfig, RuleDisplay, RuleResult};
+use super::fetcher::Fetcher;
 use super::page::{Page, PageError};
 use super::page_plugin::SeoPlugin;
-use super::site_plugin::SitePlugin;
+use super::rule_param::{RuleParamError, RuleParamValue, validate_param};
+use super::site_plugin::{SitePlugin, SiteRuns};
+
+#[derive(Debug, Error, PartialEq)]
+pub enum RegistryError {
+    #[error("Plugin {0} depends on a plugin that is not registered")]
+    MissingDependency(String),
+    #[error("Plugins {0:?} depend on each other")]
+    DependencyCycle(Vec<String>),
+    #[error("Failed to initialize plugin {plugin}: {message}")]
+    InitializeError { plugin: String, message: String },
+}
+
+type Plugins = HashMap<TypeId, Arc<dyn SeoPlugin>>;
+// In registration order, keyed so each plugin finds its run of a crawl
+type SitePlugins = Vec<(TypeId, Box<dyn SitePlugin>)>;
+
+// Orders plugins so each comes after its dependencies, otherwise keeping registration order
+fn resolve_order(plugins: &Plugins, registered: &[TypeId]) -> Result<Vec<TypeId>, RegistryError> {
+    let mut order: Vec<TypeId> = Vec::with_capacity(registered.len());
+    let mut pending = registered.to_vec();
+    while !pending.is_empty() {
+        let ready = pending.iter().position(|type_id| {
+            plugins.get(type_id).is_some_and(|plugin| {
+                plugin
+                    .dependencies()
+                    .iter()
+                    .all(|dependency| order.contains(dependency))
+            })
+        });
+        let Some(ready) = ready else {
+            return Err(RegistryError::DependencyCycle(
+                pending
+                    .iter()
+                    .filter_map(|type_id| plugins.get(type_id))
+                    .map(|plugin| plugin.name().to_string())
+                    .collect(),
+            ));
+        };
+        order.push(pending.remove(ready));
+    }
+    Ok(order)
+}
 
 #[derive(Clone)]
 pub struct PluginRegistry {
-    plugins: Arc<RwLock<HashMap<TypeId, Box<dyn SeoPlugin>>>>,
-    site_plugins: Arc<RwLock<Vec<Box<dyn SitePlugin>>>>,
+    plugins: Arc<RwLock<Plugins>>,
+    // Page plugins in dependency order
+    order: Arc<RwLock<Vec<TypeId>>>,
+    site_plugins: Arc<RwLock<SitePlugins>>,
     config: Option<RuleConfig>,
+    // Shared by async rules, so clones of a registry share its rate limit and cache
+    fetcher: Fetcher,
 }
 
 impl fmt::Debug for PluginRegistry {
@@ -34,8 +90,10 @@ impl PluginRegistry {
     pub fn new() -> Self {
         Self {
             plugins: Arc::new(RwLock::new(HashMap::new())),
+            order: Arc::new(RwLock::new(Vec::new())),
             site_plugins: Arc::new(RwLock::new(Vec::new())),
             config: None,
+            fetcher: Fetcher::default(),
         }
     }
 
@@ -43,92 +101,260 @@ impl PluginRegistry {
         self.config = Some(config);
     }
 
+    pub fn set_fetcher(&mut self, fetcher: Fetcher) {
+        self.fetcher = fetcher;
+    }
+
     pub fn get_config(&self) -> Result<&RuleConfig, PageError> {
         self.config.as_ref().ok_or(PageError::ConfigNotSet)
     }
 
-    pub async fn register<P: SeoPlugin + 'static>(&self, plugin: P) -> Result<(), String> {
-        let type_id = TypeId::of::<P>();
-        plugin.initialize(self)?;
-        self.plugins.write().insert(type_id, Box::new(plugin));
+    fn check_dependencies(
+        &self,
+        plugin: &str,
+        type_id: Option<TypeId>,
+        dependencies: Vec<TypeId>,
+    ) -> Result<(), RegistryError> {
+        let plugins = self.plugins.read();
+        // Depending on itself is left for `resolve_order` to report as a cycle
+        let missing = dependencies
+            .iter()
+            .any(|dependency| Some(*dependency) != type_id && !plugins.contains_key(dependency));
+        if missing {
+            return Err(RegistryError::MissingDependency(plugin.to_string()));
+        }
         Ok(())
     }
 
+    // Dependencies must be registered first. Registering a plugin again replaces it.
+    pub async fn register<P: SeoPlugin + 'static>(&self, plugin: P) -> Result<(), RegistryError> {
+        let type_id = TypeId::of::<P>();
+        let name = plugin.name().to_string();
+        self.check_dependencies(&name, Some(type_id), plugin.dependencies())?;
+        plugin
+            .initialize(self)
+            .map_err(|message| RegistryError::InitializeError {
+                plugin: name,
+                message,
+            })?;
+
+        let mut plugins = self.plugins.write();
+        let mut order = self.order.write();
+        let previous = plugins.insert(type_id, Arc::new(plugin));
+        let mut registered = order.clone();
+        if !registered.contains(&type_id) {
+            registered.push(type_id);
+        }
+        match resolve_order(&plugins, &registered) {
+            Ok(resolved) => {
+                *order = resolved;
+                Ok(())
+            }
+            Err(error) => {
+                match previous {
+                    Some(previous) => plugins.insert(type_id, previous),
+                    None => plugins.remove(&type_id),
+                };
+                Err(error)
+            }
+        }
+    }
+
+    // Registering a site plugin again replaces it
     pub async fn register_site_plugin<P: SitePlugin + 'static>(
         &self,
         plugin: P,
-    ) -> Result<(), String> {
-        plugin.initialize(self)?;
-        self.site_plugins.write().push(Box::new(plugin));
+    ) -> Result<(), RegistryError> {
+        let type_id = TypeId::of::<P>();
+        let name = plugin.name().to_string();
+        self.check_dependencies(&name, None, plugin.dependencies())?;
+        plugin
+            .initialize(self)
+            .map_err(|message| RegistryError::InitializeError {
+                plugin: name,
+                message,
+            })?;
+        let mut site_plugins = self.site_plugins.write();
+        match site_plugins.iter_mut().find(|(id, _)| *id == type_id) {
+            Some((_, registered)) => *registered = Box::new(plugin),
+            None => site_plugins.push((type_id, Box::new(plugin))),
+        }
         Ok(())
     }
 
-    pub fn get_available_rules(&self) -> Vec<RuleDisplay> {
+    // Page plugins in dependency order, cloned out so no lock is held while rules run
+    fn ordered_plugins(&self) -> Vec<Arc<dyn SeoPlugin>> {
         let plugins = self.plugins.read();
-        let page_rules: Vec<RuleDisplay> = plugins
-            .values()
-            .flat_map(|plugin| plugin.available_rules())
+        self.order
+            .read()
+            .iter()
+            .filter_map(|type_id| plugins.get(type_id).cloned())
+            .collect()
+    }
+
+    // Lets each page plugin publish its outputs, dependencies first. A plugin whose
+    // outputs can't be derived reports the error on its enabled rules instead of
+    // running them, so it's left out of the returned plugins.
+    fn derive_outputs(
+        plugins: Vec<Arc<dyn SeoPlugin>>,
+        page: &Page,
+        config: &RuleConfig,
+    ) -> (Vec<Arc<dyn SeoPlugin>>, Vec<RuleResult>) {
+        let mut derived = Vec::with_capacity(plugins.len());
+        let mut errored = vec![];
+        for plugin in plugins {
+            let Err(error) = plugin.derive_outputs(page) else {
+                derived.push(plugin);
+                continue;
+            };
+            let rules = plugin
+                .available_rules()
+                .into_iter()
+                .filter(|rule| config.is_rule_enabled(rule.id))
+                .map(|rule| {
+                    rule.to_result(plugin.name(), config, Err(CheckError::from(error.clone())))
+                });
+            let async_rules = plugin
+                .async_rules()
+                .into_iter()
+                .filter(|rule| config.is_rule_enabled(rule.id))
+                .map(|rule| {
+                    rule.to_result(plugin.name(), config, Err(CheckError::from(error.clone())))
+                });
+            errored.extend(rules.chain(async_rules));
+        }
+        (derived, errored)
+    }
+
+    pub fn get_available_rules(&self) -> Vec<RuleDisplay> {
+        let page_rules: Vec<RuleDisplay> = self
+            .ordered_plugins()
+            .iter()
+            .flat_map(|plugin| plugin.rule_displays())
             .collect();
         let site_rules = self
             .site_plugins
             .read()
             .iter()
-            .flat_map(|plugin| plugin.available_rules())
+            .flat_map(|(_, plugin)| plugin.available_rules())
             .collect();
 
         [page_rules, site_rules].concat()
     }
 
+    // Checks a parameter value against the rule that declares it
+    pub fn validate_param(
+        &self,
+        rule_id: &str,
+        name: &str,
+        value: &RuleParamValue,
+    ) -> Result<(), RuleParamError> {
+        let params = self
+            .get_available_rules()
+            .into_iter()
+            .find(|rule| rule.id == rule_id)
+            .map(|rule| rule.params)
+            .unwrap_or_default();
+        validate_param(rule_id, &params, name, value)
+    }
+
+    // Checks every parameter in the config, skipping rules this registry doesn't have
+    pub fn validate_config(&self, config: &RuleConfig) -> Result<(), RuleParamError> {
+        let rules = self.get_available_rules();
+        for (rule_id, settings) in config.to_settings() {
+            let Some(rule) = rules.iter().find(|rule| rule.id == rule_id) else {
+                continue;
+            };
+            for (name, value) in &settings.params {
+                validate_param(&rule_id, &rule.params, name, value)?;
+            }
+        }
+        Ok(())
+    }
+
     pub async fn analyze_async(&self, page: &Page) -> Result<Vec<RuleResult>, PageError> {
         let config = self.get_config()?;
-        let plugins = self.plugins.read();
+        let (plugins, errored) = Self::derive_outputs(self.ordered_plugins(), page, config);
+
         let futures = plugins
-            .values()
+            .iter()
             .map(|plugin| plugin.analyze_async(page, config))
             .collect::<Vec<_>>();
-        // drop(plugins); // Drop the lock before awaiting
+        let async_rules = plugins
+            .iter()
+            .flat_map(|plugin| {
+                plugin
+                    .async_rules()
+                    .into_iter()
+                    .filter(|rule| config.is_rule_enabled(rule.id))
+                    .map(|rule| (plugin.name(), rule))
+            })
+            .collect::<Vec<_>>();
+        let async_futures = async_rules.iter().map(|(plugin_name, rule)| async move {
+            let result = rule.run(page, config, &self.fetcher).await;
+            rule.to_result(plugin_name, config, result)
+        });
 
-        let results = futures::future::join_all(futures)
-            .await
+        let (results, async_results) = futures::join!(
+            futures::future::join_all(futures),
+            futures::future::join_all(async_futures)
+        );
+        let results = errored
             .into_iter()
-            .flatten()
+            .chain(results.into_iter().flatten())
+            .chain(async_results)
             .collect();
 
-        // Run site plugins
-        for plugin in self.site_plugins.read().iter() {
-            let _ = plugin.after_page_hook(Arc::new(StdMutex::new(page.clone())), &results);
-        }
         Ok(results)
     }
 
+    // Runs the sync rules only, async rules need `analyze_async`
     pub fn analyze(&self, page: &Page) -> Result<Vec<RuleResult>, PageError> {
         let config = self.get_config()?;
-        let results = futures::executor::block_on(async {
-            let r = self
-                .plugins
-                .read()
-                .values()
-                .flat_map(|plugin| plugin.analyze(page, config))
-                .collect();
-
-            // Run site plugins
-            for plugin in self.site_plugins.read().iter() {
-                let _ = plugin.after_page_hook(Arc::new(StdMutex::new(page.clone())), &r);
-            }
-
-            r
-        });
+        let (plugins, errored) = Self::derive_outputs(self.ordered_plugins(), page, config);
+        let results = errored
+            .into_iter()
+            .chain(
+                plugins
+                    .iter()
+                    .flat_map(|plugin| plugin.analyze(page, config)),
+            )
+            .collect();
 
         Ok(results)
     }
 
-    pub async fn analyze_site(&self, site: &SiteAnalyzer) -> Result<Vec<RuleResult>, PageError> {
+    // Fresh state for every site plugin, one per crawl
+    pub fn start_site_runs(&self) -> SiteRuns {
+        SiteRuns::new(
+            self.site_plugins
+                .read()
+                .iter()
+                .map(|(type_id, plugin)| (*type_id, plugin.start_run())),
+        )
+    }
+
+    // Site plugins registered after `runs` were started saw none of the pages and are skipped
+
+    pub async fn analyze_site(
+        &self,
+        site: &SiteAnalyzer,
+        runs: &SiteRuns,
+    ) -> Result<Vec<RuleResult>, PageError> {
         let config = self.get_config()?;
         let results = self
             .site_plugins
             .read()
             .iter()
-            .flat_map(|plugin| plugin.analyze(site, config))
+            .flat_map(|(type_id, plugin)| {
+                let Some(run) = runs.get(*type_id) else {
+                    return vec![];
+                };
+                match runs.errors(*type_id) {
+                    [] => plugin.analyze(run, site, config),
+                    errors => plugin.errored(errors, config),
+                }
+            })
             .collect();
 
         Ok(results)
@@ -158,14 +384,24 @@ impl Default for PluginRegistry {
             let _ = registry.register(TitlePlugin::new()).await;
             let _ = registry.register(AxePlugin::new()).await;
             let _ = registry.register(RequestPlugin::new()).await;
+            let _ = registry.register(ResourcesPlugin::new()).await;
+            let _ = registry.register(HeadingsPlugin::new()).await;
+            let _ = registry.register(StructuredDataPlugin::new()).await;
+            let _ = registry.register(SocialPlugin::new()).await;
             let _ = registry
                 .register(crate::plugins::meta_description::MetaDescriptionPlugin::new())
                 .await;
+            let _ = registry.register(MainTextPlugin::new()).await;
+            let _ = registry.register(ContentQualityPlugin::new()).await;
+            let _ = registry.register_site_plugin(UniquenessPlugin::new()).await;
             let _ = registry
-                .register_site_plugin(MetaDescriptionSitePlugin::new())
+                .register_site_plugin(OrphanedPagePlugin::new())
                 .await;
+            let _ = registry.register_site_plugin(LinkGraphPlugin::new()).await;
+            let _ = registry.register_site_plugin(AnchorTextPlugin::new()).await;
+            let _ = registry.register_site_plugin(CanonicalPlugin::new()).await;
             let _ = registry
-                .register_site_plugin(OrphanedPagePlugin::new())
+                .register_site_plugin(DuplicateContentPlugin::new())
                 .await;
         });
 
@@ -176,6 +412,336 @@ impl Default for PluginRegistry {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::config::{
+        CheckResult, Rule, RuleCategory, RuleStatus, Severity, SiteCheckContext, SiteCheckResult,
+        SiteRule,
+    };
+    use crate::utils::rule_param::RuleParams;
+    use crate::utils::site_plugin::SiteRun;
+    use std::any::Any;
+
+    #[derive(Debug, PartialEq)]
+    struct WordCount(usize);
+
+    // Publishes the page's word count
+    struct WordCountPlugin;
+
+    impl SeoPlugin for WordCountPlugin {
+        fn name(&self) -> &str {
+            "Word Count"
+        }
+        fn description(&self) -> &str {
+            ""
+        }
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn derive_outputs(&self, page: &Page) -> Result<(), PageError> {
+            let document = page.get_document()?;
+            let words = document
+                .root_element()
+                .text()
+                .flat_map(str::split_whitespace)
+                .count();
+            page.outputs().insert(WordCount(words));
+            Ok(())
+        }
+        fn available_rules(&self) -> Vec<Rule> {
+            vec![]
+        }
+    }
+
+    // Reads the word count published by `WordCountPlugin`
+    struct ThinContentPlugin {
+        dependencies: Vec<TypeId>,
+    }
+
+    impl SeoPlugin for ThinContentPlugin {
+        fn name(&self) -> &str {
+            "Thin Content"
+        }
+        fn description(&self) -> &str {
+            ""
+        }
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn dependencies(&self) -> Vec<TypeId> {
+            self.dependencies.clone()
+        }
+        fn available_rules(&self) -> Vec<Rule> {
+            vec![Rule {
+                id: "test.thin_content",
+                name: "Enough words",
+                plugin_name: "Thin Content",
+                description: "",
+                passed_message: "",
+                failed_message: "",
+                default_severity: Severity::Warning,
+                check: |page, _params| {
+                    let words = page
+                        .outputs()
+                        .get::<WordCount>()
+                        .ok_or(CheckError::NotApplicable("No word count".to_string()))?;
+                    Ok(CheckResult {
+                        rule_id: "test.thin_content".to_string(),
+                        passed: words.0 >= 3,
+                        message: format!("{} words", words.0),
+                        findings: vec![],
+                    })
+                },
+                category: RuleCategory::SEO,
+                params: vec![],
+                remediation: None,
+            }]
+        }
+    }
+
+    // Can't derive its outputs on any page
+    struct BrokenOutputsPlugin;
+
+    impl SeoPlugin for BrokenOutputsPlugin {
+        fn name(&self) -> &str {
+            "Broken Outputs"
+        }
+        fn description(&self) -> &str {
+            ""
+        }
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn derive_outputs(&self, _page: &Page) -> Result<(), PageError> {
+            Err(PageError::ElementNotFound)
+        }
+        fn available_rules(&self) -> Vec<Rule> {
+            vec![Rule {
+                id: "test.broken",
+                name: "Broken",
+                plugin_name: "Broken Outputs",
+                description: "",
+                passed_message: "",
+                failed_message: "",
+                default_severity: Severity::Warning,
+                check: |_page, _params| {
+                    Ok(CheckResult {
+                        rule_id: "test.broken".to_string(),
+                        passed: true,
+                        message: String::new(),
+                        findings: vec![],
+                    })
+                },
+                category: RuleCategory::SEO,
+                params: vec![],
+                remediation: None,
+            }]
+        }
+    }
+
+    // Totals the word counts of the pages in a crawl
+    struct SiteWordCountPlugin;
+
+    #[derive(Default)]
+    struct SiteWordCountRun {
+        words: usize,
+    }
+
+    impl SitePlugin for SiteWordCountPlugin {
+        fn name(&self) -> &str {
+            "Site Word Count"
+        }
+        fn description(&self) -> &str {
+            ""
+        }
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn dependencies(&self) -> Vec<TypeId> {
+            vec![TypeId::of::<WordCountPlugin>()]
+        }
+        fn available_rules(&self) -> Vec<SiteRule> {
+            vec![SiteRule {
+                id: "test.site_words",
+                name: "Site words",
+                plugin_name: "Site Word Count",
+                description: "",
+                passed_message: "",
+                failed_message: "",
+                default_severity: Severity::Info,
+                category: RuleCategory::SEO,
+                params: vec![],
+                remediation: None,
+            }]
+        }
+        fn start_run(&self) -> Box<dyn SiteRun> {
+            Box::new(SiteWordCountRun::default())
+        }
+    }
+
+    impl SiteRun for SiteWordCountRun {
+        fn after_page(&mut self, page: &Page, _results: &[RuleResult]) -> Result<(), String> {
+            let words = page
+                .outputs()
+                .get::<WordCount>()
+                .ok_or("No word count".to_string())?;
+            self.words += words.0;
+            Ok(())
+        }
+        fn check(
+            &self,
+            rule: &SiteRule,
+            _site: &SiteAnalyzer,
+            _params: &RuleParams,
+        ) -> Result<SiteCheckResult, CheckError> {
+            Ok(SiteCheckResult {
+                rule_id: rule.id.to_string(),
+                passed: true,
+                message: format!("{} words", self.words),
+                context: SiteCheckContext::Empty,
+            })
+        }
+    }
+
+    #[test]
+    fn dependencies_must_be_registered_first() {
+        futures::executor::block_on(async {
+            let registry = PluginRegistry::new();
+            let thin_content = || ThinContentPlugin {
+                dependencies: vec![TypeId::of::<WordCountPlugin>()],
+            };
+            assert_eq!(
+                registry.register(thin_content()).await,
+                Err(RegistryError::MissingDependency("Thin Content".to_string()))
+            );
+            assert_eq!(
+                registry.register_site_plugin(SiteWordCountPlugin).await,
+                Err(RegistryError::MissingDependency(
+                    "Site Word Count".to_string()
+                ))
+            );
+
+            registry.register(WordCountPlugin).await.unwrap();
+            registry.register(thin_content()).await.unwrap();
+            // Depending on itself can never be satisfied
+            assert!(matches!(
+                registry
+                    .register(ThinContentPlugin {
+                        dependencies: vec![TypeId::of::<ThinContentPlugin>()],
+                    })
+                    .await,
+                Err(RegistryError::DependencyCycle(_))
+            ));
+            // The failed registration left the previous plugin in place
+            assert_eq!(registry.get_available_rules().len(), 1);
+        });
+    }
+
+    #[tokio::test]
+    async fn dependents_read_published_outputs() {
+        let mut registry = PluginRegistry::new();
+        registry.register(WordCountPlugin).await.unwrap();
+        registry
+            .register(ThinContentPlugin {
+                dependencies: vec![TypeId::of::<WordCountPlugin>()],
+            })
+            .await
+            .unwrap();
+        registry
+            .register_site_plugin(SiteWordCountPlugin)
+            .await
+            .unwrap();
+
+        let mut config = RuleConfig::new();
+        config.enable_rule("test.thin_content");
+        config.enable_rule("test.site_words");
+        registry.set_config(config);
+
+        let page =
+            Page::from_html("<html><body><p>One two three four</p></body></html>".to_string());
+        let results = registry.analyze_async(&page).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].passed);
+        assert_eq!(results[0].message, "4 words");
+
+        // Each crawl accumulates into its own run
+        let site = SiteAnalyzer::new("https://example.com", registry.clone()).unwrap();
+        let mut first = registry.start_site_runs();
+        let mut second = registry.start_site_runs();
+        first.after_page(&page, &results);
+        first.after_page(&page, &results);
+        second.after_page(&page, &results);
+        let first = registry.analyze_site(&site, &first).await.unwrap();
+        let second = registry.analyze_site(&site, &second).await.unwrap();
+        assert_eq!(first[0].message, "8 words");
+        assert_eq!(second[0].message, "4 words");
+    }
+
+    #[tokio::test]
+    async fn site_runs_are_matched_to_their_plugins() {
+        let mut registry = PluginRegistry::new();
+        registry.register(WordCountPlugin).await.unwrap();
+        registry
+            .register_site_plugin(SiteWordCountPlugin)
+            .await
+            .unwrap();
+        let mut config = RuleConfig::new();
+        config.enable_rule("test.site_words");
+        config.enable_rule("orphaned_page.check");
+        registry.set_config(config);
+        let site = SiteAnalyzer::new("https://example.com", registry.clone()).unwrap();
+
+        let mut runs = registry.start_site_runs();
+        // Registered mid-crawl, so it has no run and is skipped
+        registry
+            .register_site_plugin(OrphanedPagePlugin::new())
+            .await
+            .unwrap();
+        let page = Page::from_html("<html><body><p>One two</p></body></html>".to_string());
+        registry.analyze(&page).unwrap();
+        runs.after_page(&page, &[]);
+        let results = registry.analyze_site(&site, &runs).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].message, "2 words");
+
+        // A page the run failed to record errors the plugin's rules
+        runs.after_page(&Page::from_html("<p>Unanalyzed</p>".to_string()), &[]);
+        let results = registry.analyze_site(&site, &runs).await.unwrap();
+        assert_eq!(results[0].status, RuleStatus::Errored);
+        assert!(results[0].message.contains("No word count"));
+    }
+
+    #[tokio::test]
+    async fn output_errors_are_reported_on_the_plugins_rules() {
+        let mut registry = PluginRegistry::new();
+        registry.register(BrokenOutputsPlugin).await.unwrap();
+        registry.register(WordCountPlugin).await.unwrap();
+        registry
+            .register(ThinContentPlugin {
+                dependencies: vec![TypeId::of::<WordCountPlugin>()],
+            })
+            .await
+            .unwrap();
+        let mut config = RuleConfig::new();
+        config.enable_rule("test.broken");
+        config.enable_rule("test.thin_content");
+        registry.set_config(config);
+
+        let page =
+            Page::from_html("<html><body><p>One two three four</p></body></html>".to_string());
+        for results in [
+            registry.analyze(&page).unwrap(),
+            registry.analyze_async(&page).await.unwrap(),
+        ] {
+            assert_eq!(results.len(), 2);
+            let broken = results
+                .iter()
+                .find(|result| result.rule_id == "test.broken")
+                .unwrap();
+            assert_eq!(broken.status, RuleStatus::Errored);
+            assert!(broken.message.contains("Element not found"));
+            // The other plugins still run
+            assert!(results.iter().any(|result| result.passed));
+        }
+    }
 
     #[test]
     fn all_ids_are_unique() {
@@ -190,4 +756,55 @@ mod tests {
         unique_ids.dedup();
         assert_eq!(ids.len(), unique_ids.len());
     }
+
+    #[test]
+    fn config_controls_enabled_rules_and_severity() {
+        let mut registry = PluginRegistry::default();
+        let mut config = RuleConfig::new();
+        config.enable_rule("title.has_title");
+        config.enable_rule("title.title_length");
+        config.disable_rule("title.title_length");
+        config.set_severity("title.has_title", Severity::Info);
+        registry.set_config(config);
+
+        let page = Page::from_html("<html><head></head><body></body></html>".to_string());
+        let results = registry.analyze(&page).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].rule_id, "title.has_title");
+        assert!(matches!(results[0].severity, Severity::Info));
+    }
+
+    #[test]
+    fn validates_params_against_declaring_rule() {
+        let registry = PluginRegistry::default();
+        assert!(
+            registry
+                .validate_param(
+                    "uniqueness.meta_description",
+                    "min_unique_percent",
+                    &RuleParamValue::Number(80.0)
+                )
+                .is_ok()
+        );
+        assert!(
+            registry
+                .validate_param(
+                    "uniqueness.meta_description",
+                    "min_unique_percent",
+                    &RuleParamValue::Number(120.0)
+                )
+                .is_err()
+        );
+
+        let mut config = RuleConfig::new();
+        config.set_param("unknown.rule", "anything", RuleParamValue::Boolean(true));
+        assert!(registry.validate_config(&config).is_ok());
+        config.set_param(
+            "title.title_length",
+            "measure",
+            RuleParamValue::Text("words".to_string()),
+        );
+        assert!(registry.validate_config(&config).is_err());
+    }
 }
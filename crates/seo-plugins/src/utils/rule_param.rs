@@ -0,0 +1,286 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+use thiserror::Error;
+
+use super::config::{CheckError, RuleConfig};
+
+#[derive(Debug, Error, PartialEq)]
+pub enum RuleParamError {
+    #[error("Rule {rule_id} has no parameter {name}")]
+    UnknownParam { rule_id: String, name: String },
+    #[error("Parameter {name} expects {expected}")]
+    WrongType { name: String, expected: String },
+    #[error("Parameter {name} must be between {min} and {max}, got {value}")]
+    OutOfRange {
+        name: String,
+        min: f64,
+        max: f64,
+        value: f64,
+    },
+    #[error("Parameter {name} must be one of {options:?}, got {value}")]
+    InvalidChoice {
+        name: String,
+        options: Vec<String>,
+        value: String,
+    },
+}
+
+// Untagged so profiles can write `max_length = 60` rather than `max_length = { Number = 60 }`
+#[derive(Debug, Serialize, Deserialize, Type, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum RuleParamValue {
+    Boolean(bool),
+    Number(f64),
+    Text(String),
+}
+
+#[derive(Debug, Serialize, Deserialize, Type, Clone, PartialEq)]
+pub enum RuleParamKind {
+    Number { min: f64, max: f64 },
+    Choice { options: Vec<String> },
+    Boolean,
+}
+
+// A setting a rule reads at check time, with its default and allowed values
+#[derive(Debug, Serialize, Deserialize, Type, Clone, PartialEq)]
+pub struct RuleParam {
+    pub name: String,
+    pub label: String,
+    pub description: String,
+    pub kind: RuleParamKind,
+    pub default: RuleParamValue,
+}
+
+impl RuleParam {
+    pub fn number(
+        name: &str,
+        label: &str,
+        description: &str,
+        default: f64,
+        min: f64,
+        max: f64,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            label: label.to_string(),
+            description: description.to_string(),
+            kind: RuleParamKind::Number { min, max },
+            default: RuleParamValue::Number(default),
+        }
+    }
+
+    pub fn choice(
+        name: &str,
+        label: &str,
+        description: &str,
+        default: &str,
+        options: &[&str],
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            label: label.to_string(),
+            description: description.to_string(),
+            kind: RuleParamKind::Choice {
+                options: options.iter().map(|option| option.to_string()).collect(),
+            },
+            default: RuleParamValue::Text(default.to_string()),
+        }
+    }
+
+    pub fn boolean(name: &str, label: &str, description: &str, default: bool) -> Self {
+        Self {
+            name: name.to_string(),
+            label: label.to_string(),
+            description: description.to_string(),
+            kind: RuleParamKind::Boolean,
+            default: RuleParamValue::Boolean(default),
+        }
+    }
+
+    pub fn validate(&self, value: &RuleParamValue) -> Result<(), RuleParamError> {
+        match (&self.kind, value) {
+            (RuleParamKind::Number { min, max }, RuleParamValue::Number(value)) => {
+                if value < min || value > max || value.is_nan() {
+                    return Err(RuleParamError::OutOfRange {
+                        name: self.name.clone(),
+                        min: *min,
+                        max: *max,
+                        value: *value,
+                    });
+                }
+                Ok(())
+            }
+            (RuleParamKind::Choice { options }, RuleParamValue::Text(value)) => {
+                if !options.contains(value) {
+                    return Err(RuleParamError::InvalidChoice {
+                        name: self.name.clone(),
+                        options: options.clone(),
+                        value: value.clone(),
+                    });
+                }
+                Ok(())
+            }
+            (RuleParamKind::Boolean, RuleParamValue::Boolean(_)) => Ok(()),
+            (kind, _) => Err(RuleParamError::WrongType {
+                name: self.name.clone(),
+                expected: match kind {
+                    RuleParamKind::Number { .. } => "a number",
+                    RuleParamKind::Choice { .. } => "one of the listed options",
+                    RuleParamKind::Boolean => "true or false",
+                }
+                .to_string(),
+            }),
+        }
+    }
+}
+
+// Checks `value` against the parameter `name` declared in `params`
+pub fn validate_param(
+    rule_id: &str,
+    params: &[RuleParam],
+    name: &str,
+    value: &RuleParamValue,
+) -> Result<(), RuleParamError> {
+    params
+        .iter()
+        .find(|param| param.name == name)
+        .ok_or(RuleParamError::UnknownParam {
+            rule_id: rule_id.to_string(),
+            name: name.to_string(),
+        })?
+        .validate(value)
+}
+
+// Parameter values a rule is checked with, configured values over defaults
+#[derive(Debug, Clone, Default)]
+pub struct RuleParams {
+    values: HashMap<String, RuleParamValue>,
+}
+
+impl RuleParams {
+    pub fn resolve(
+        rule_id: &str,
+        params: &[RuleParam],
+        config: &RuleConfig,
+    ) -> Result<Self, RuleParamError> {
+        let mut values = HashMap::new();
+        for param in params {
+            let value = config
+                .get_param(rule_id, &param.name)
+                .cloned()
+                .unwrap_or_else(|| param.default.clone());
+            param.validate(&value)?;
+            values.insert(param.name.clone(), value);
+        }
+        Ok(Self { values })
+    }
+
+    pub fn number(&self, name: &str) -> Result<f64, CheckError> {
+        match self.values.get(name) {
+            Some(RuleParamValue::Number(value)) => Ok(*value),
+            _ => Err(CheckError::InvalidParam(name.to_string())),
+        }
+    }
+
+    pub fn text(&self, name: &str) -> Result<&str, CheckError> {
+        match self.values.get(name) {
+            Some(RuleParamValue::Text(value)) => Ok(value),
+            _ => Err(CheckError::InvalidParam(name.to_string())),
+        }
+    }
+
+    pub fn boolean(&self, name: &str) -> Result<bool, CheckError> {
+        match self.values.get(name) {
+            Some(RuleParamValue::Boolean(value)) => Ok(*value),
+            _ => Err(CheckError::InvalidParam(name.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> Vec<RuleParam> {
+        vec![
+            RuleParam::number("max_length", "Max length", "", 60.0, 1.0, 200.0),
+            RuleParam::choice(
+                "measure",
+                "Measure",
+                "",
+                "characters",
+                &["characters", "pixels"],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_resolve_uses_defaults_and_config() {
+        let mut config = RuleConfig::new();
+        let resolved = RuleParams::resolve("title.title_length", &params(), &config).unwrap();
+        assert_eq!(resolved.number("max_length").unwrap(), 60.0);
+        assert_eq!(resolved.text("measure").unwrap(), "characters");
+
+        config.set_param(
+            "title.title_length",
+            "max_length",
+            RuleParamValue::Number(70.0),
+        );
+        let resolved = RuleParams::resolve("title.title_length", &params(), &config).unwrap();
+        assert_eq!(resolved.number("max_length").unwrap(), 70.0);
+        assert!(matches!(
+            resolved.number("measure"),
+            Err(CheckError::InvalidParam(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_values_are_rejected() {
+        let params = params();
+        assert!(matches!(
+            validate_param(
+                "title.title_length",
+                &params,
+                "max_length",
+                &RuleParamValue::Number(0.0)
+            ),
+            Err(RuleParamError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            validate_param(
+                "title.title_length",
+                &params,
+                "measure",
+                &RuleParamValue::Text("words".to_string())
+            ),
+            Err(RuleParamError::InvalidChoice { .. })
+        ));
+        assert!(matches!(
+            validate_param(
+                "title.title_length",
+                &params,
+                "max_length",
+                &RuleParamValue::Boolean(true)
+            ),
+            Err(RuleParamError::WrongType { .. })
+        ));
+        assert!(matches!(
+            validate_param(
+                "title.title_length",
+                &params,
+                "min_length",
+                &RuleParamValue::Number(1.0)
+            ),
+            Err(RuleParamError::UnknownParam { .. })
+        ));
+
+        let mut config = RuleConfig::new();
+        config.set_param(
+            "title.title_length",
+            "max_length",
+            RuleParamValue::Number(500.0),
+        );
+        assert!(RuleParams::resolve("title.title_length", &params, &config).is_err());
+    }
+}
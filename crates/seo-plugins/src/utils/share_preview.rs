@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use super::page::Page;
+use super::text::collapse_whitespace;
+
+// What a shared link to the page looks like, with the fallbacks social networks
+// use when Open Graph or Twitter tags are missing
+#[derive(Debug, Clone, Serialize, Deserialize, Type, Default, PartialEq)]
+pub struct SharePreview {
+    // og:url, else the canonical URL, else the page URL
+    pub url: String,
+    // og:site_name, else the host
+    pub site_name: String,
+    // As Facebook, LinkedIn and chat apps show it: og tags, else the title tag and
+    // meta description
+    pub title: Option<String>,
+    pub description: Option<String>,
+    // Absolute URL of og:image
+    pub image: Option<String>,
+    // As X shows it: twitter tags, else the preview above
+    pub twitter_card: Option<String>,
+    pub twitter_title: Option<String>,
+    pub twitter_description: Option<String>,
+    pub twitter_image: Option<String>,
+}
+
+impl From<&Page> for SharePreview {
+    fn from(page: &Page) -> Self {
+        let meta_tags = page.extract_meta_tags();
+        let page_url = page.get_url();
+        let text = |value: Option<&String>| {
+            value
+                .map(|value| collapse_whitespace(value))
+                .filter(|value| !value.is_empty())
+        };
+        let absolute = |value: Option<&String>| {
+            text(value).map(|value| {
+                page_url
+                    .join(&value)
+                    .map(|url| url.to_string())
+                    .unwrap_or(value)
+            })
+        };
+
+        let title = text(meta_tags.og_tags.get("title")).or(text(meta_tags.title.as_ref()));
+        let description =
+            text(meta_tags.og_tags.get("description")).or(text(meta_tags.description.as_ref()));
+        let image = absolute(meta_tags.og_tags.get("image"));
+        Self {
+            url: absolute(meta_tags.og_tags.get("url"))
+                .or(absolute(meta_tags.canonical.as_ref()))
+                .unwrap_or(page_url.to_string()),
+            site_name: text(meta_tags.og_tags.get("site_name"))
+                .unwrap_or(page_url.host_str().unwrap_or_default().to_string()),
+            twitter_card: text(meta_tags.twitter_tags.get("card")),
+            twitter_title: text(meta_tags.twitter_tags.get("title")).or(title.clone()),
+            twitter_description: text(meta_tags.twitter_tags.get("description"))
+                .or(description.clone()),
+            twitter_image: absolute(meta_tags.twitter_tags.get("image")).or(image.clone()),
+            title,
+            description,
+            image,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_share_preview() {
+        let mut page = Page::from_html(
+            r#"<html><head>
+                <title>Trail Shoes | Example</title>
+                <meta name="description" content="Shoes for every trail">
+                <link rel="canonical" href="/shoes/trail">
+                <meta property="og:image" content="/images/trail.jpg">
+                <meta name="twitter:card" content="summary_large_image">
+                <meta name="twitter:title" content="Trail Shoes">
+            </head></html>"#
+                .to_string(),
+        );
+        page.set_url("https://example.com/shoes/trail?ref=nav");
+
+        let preview = SharePreview::from(&page);
+        assert_eq!(preview.url, "https://example.com/shoes/trail");
+        assert_eq!(preview.site_name, "example.com");
+        assert_eq!(preview.title.as_deref(), Some("Trail Shoes | Example"));
+        assert_eq!(
+            preview.description.as_deref(),
+            Some("Shoes for every trail")
+        );
+        assert_eq!(
+            preview.image.as_deref(),
+            Some("https://example.com/images/trail.jpg")
+        );
+        assert_eq!(preview.twitter_card.as_deref(), Some("summary_large_image"));
+        assert_eq!(preview.twitter_title.as_deref(), Some("Trail Shoes"));
+        assert_eq!(preview.twitter_image, preview.image);
+    }
+}
@@ -1,49 +1,91 @@
-use std::any::Any;
-use std::sync::{Arc, Mutex as StdMutex};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
 
 use futures::stream::{self, StreamExt};
 
-use super::config::{RuleConfig, RuleResult, SiteCheckResult, SiteRule};
+use super::config::{CheckError, RuleConfig, RuleResult, SiteCheckResult, SiteRule};
 use super::page::Page;
+use super::rule_param::RuleParams;
 
 use crate::site_analyzer::SiteAnalyzer;
+
+// What a site plugin accumulates during one crawl. Each crawl starts a fresh run,
+// so nothing is shared between crawls or between registry clones.
+pub trait SiteRun: Send + Sync {
+    // Called once per analyzed page with that page's rule results
+    fn after_page(&mut self, _page: &Page, _results: &[RuleResult]) -> Result<(), String> {
+        Ok(())
+    }
+    fn check(
+        &self,
+        rule: &SiteRule,
+        site: &SiteAnalyzer,
+        params: &RuleParams,
+    ) -> Result<SiteCheckResult, CheckError>;
+}
+
 #[async_trait::async_trait]
 pub trait SitePlugin: Send + Sync + 'static {
     fn name(&self) -> &str;
     fn description(&self) -> &str;
     fn as_any(&self) -> &dyn Any;
+    // Page plugins whose outputs this plugin's runs read in `SiteRun::after_page`
+    fn dependencies(&self) -> Vec<TypeId> {
+        vec![]
+    }
     fn initialize(&self, _registry: &super::registry::PluginRegistry) -> Result<(), String> {
         Ok(())
     }
     fn available_rules(&self) -> Vec<SiteRule>;
-    fn after_page_hook(
-        &self,
-        _page: Arc<StdMutex<Page>>,
-        _results: &Vec<RuleResult>,
-    ) -> Result<(), String> {
-        Ok(())
+    // Fresh state for a new crawl
+    fn start_run(&self) -> Box<dyn SiteRun>;
+    // Reports the pages a run failed to record on every enabled rule, since checking
+    // the incomplete run could pass rules that should fail
+    fn errored(&self, errors: &[String], config: &RuleConfig) -> Vec<RuleResult> {
+        // The first few errors are enough to tell what went wrong
+        let message = format!(
+            "Failed to record {} {}: {}",
+            errors.len(),
+            if errors.len() == 1 { "page" } else { "pages" },
+            errors
+                .iter()
+                .take(3)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join("; ")
+        );
+        self.available_rules()
+            .iter()
+            .filter(|rule| config.is_rule_enabled(rule.id))
+            .map(|rule| {
+                let error = CheckError::PluginError(message.clone());
+                rule.to_result(self.name(), config, Err(error))
+            })
+            .collect()
     }
-    fn check(&self, rule: &SiteRule, site: &SiteAnalyzer) -> SiteCheckResult;
-    fn analyze(&self, site: &SiteAnalyzer, config: &RuleConfig) -> Vec<RuleResult> {
+    fn analyze(
+        &self,
+        run: &dyn SiteRun,
+        site: &SiteAnalyzer,
+        config: &RuleConfig,
+    ) -> Vec<RuleResult> {
         self.available_rules()
             .iter()
             .filter(|rule| config.is_rule_enabled(rule.id))
             .map(|rule| {
-                let result = self.check(rule, site);
-                RuleResult {
-                    rule_id: rule.id.to_string(),
-                    name: rule.name.to_string(),
-                    plugin_name: self.name().to_string(),
-                    passed: result.passed,
-                    message: result.message,
-                    severity: rule.default_severity.clone(),
-                    category: rule.category.clone(),
-                    context: result.context,
-                }
+                let result = rule
+                    .resolve_params(config)
+                    .and_then(|params| run.check(rule, site, &params));
+                rule.to_result(self.name(), config, result)
             })
             .collect()
     }
-    async fn async_analyze(&self, site: &SiteAnalyzer, config: &RuleConfig) -> Vec<RuleResult> {
+    async fn async_analyze(
+        &self,
+        run: &dyn SiteRun,
+        site: &SiteAnalyzer,
+        config: &RuleConfig,
+    ) -> Vec<RuleResult> {
         let available_rules = self.available_rules();
         let rules: Vec<&SiteRule> = available_rules
             .iter()
@@ -52,19 +94,63 @@ pub trait SitePlugin: Send + Sync + 'static {
 
         stream::iter(rules)
             .map(|rule| {
-                let result = self.check(rule, site);
-                RuleResult {
-                    rule_id: rule.id.to_string(),
-                    name: rule.name.to_string(),
-                    plugin_name: self.name().to_string(),
-                    passed: result.passed,
-                    message: result.message,
-                    severity: rule.default_severity.clone(),
-                    category: rule.category.clone(),
-                    context: result.context,
-                }
+                let result = rule
+                    .resolve_params(config)
+                    .and_then(|params| run.check(rule, site, &params));
+                rule.to_result(self.name(), config, result)
             })
             .collect::<Vec<_>>()
             .await
     }
 }
+
+struct RecordedRun {
+    run: Box<dyn SiteRun>,
+    // Pages `after_page` failed on, with the error
+    errors: Vec<String>,
+}
+
+// The runs of the site plugins registered when the crawl started, keyed by plugin type
+#[derive(Default)]
+pub struct SiteRuns {
+    runs: HashMap<TypeId, RecordedRun>,
+}
+
+impl SiteRuns {
+    pub fn new(runs: impl IntoIterator<Item = (TypeId, Box<dyn SiteRun>)>) -> Self {
+        let runs = runs
+            .into_iter()
+            .map(|(plugin, run)| {
+                (
+                    plugin,
+                    RecordedRun {
+                        run,
+                        errors: vec![],
+                    },
+                )
+            })
+            .collect();
+        Self { runs }
+    }
+
+    pub fn after_page(&mut self, page: &Page, results: &[RuleResult]) {
+        for recorded in self.runs.values_mut() {
+            if let Err(error) = recorded.run.after_page(page, results) {
+                recorded
+                    .errors
+                    .push(format!("{}: {}", page.get_url(), error));
+            }
+        }
+    }
+
+    pub fn get(&self, plugin: TypeId) -> Option<&dyn SiteRun> {
+        self.runs.get(&plugin).map(|recorded| recorded.run.as_ref())
+    }
+
+    pub fn errors(&self, plugin: TypeId) -> &[String] {
+        self.runs
+            .get(&plugin)
+            .map(|recorded| recorded.errors.as_slice())
+            .unwrap_or_default()
+    }
+}
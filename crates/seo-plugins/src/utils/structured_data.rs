@@ -0,0 +1,447 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use scraper::{ElementRef, Html, Selector, node::Element};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::config::Finding;
+use super::text::collapse_whitespace;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum StructuredDataFormat {
+    JsonLd,
+    Microdata,
+    Rdfa,
+}
+
+impl fmt::Display for StructuredDataFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StructuredDataFormat::JsonLd => write!(f, "JSON-LD"),
+            StructuredDataFormat::Microdata => write!(f, "Microdata"),
+            StructuredDataFormat::Rdfa => write!(f, "RDFa"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum PropertyValue {
+    Text(String),
+    Entity(Box<Entity>),
+}
+
+// One schema.org item, whatever syntax it was written in. Types and property
+// names are shortened to their schema.org name, e.g. `https://schema.org/Product`
+// becomes `Product`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Entity {
+    pub format: StructuredDataFormat,
+    pub types: Vec<String>,
+    pub properties: BTreeMap<String, Vec<PropertyValue>>,
+    // The element the item was read from, the script tag for JSON-LD
+    pub finding: Finding,
+}
+
+impl Entity {
+    pub fn is_a(&self, type_name: &str) -> bool {
+        self.types.iter().any(|t| t == type_name)
+    }
+
+    pub fn values(&self, property: &str) -> &[PropertyValue] {
+        self.properties
+            .get(property)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    // Whether the property has a value other than empty text
+    pub fn has(&self, property: &str) -> bool {
+        self.values(property).iter().any(|value| match value {
+            PropertyValue::Text(text) => !text.trim().is_empty(),
+            PropertyValue::Entity(_) => true,
+        })
+    }
+
+    // This entity and every entity nested in its properties
+    pub fn descendants(&self) -> Vec<&Entity> {
+        let mut entities = vec![self];
+        for values in self.properties.values() {
+            for value in values {
+                if let PropertyValue::Entity(entity) = value {
+                    entities.extend(entity.descendants());
+                }
+            }
+        }
+        entities
+    }
+}
+
+// A JSON-LD block that isn't valid JSON
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StructuredDataError {
+    pub message: String,
+    pub finding: Finding,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct StructuredData {
+    pub entities: Vec<Entity>,
+    pub errors: Vec<StructuredDataError>,
+    pub json_ld_blocks: u32,
+}
+
+impl StructuredData {
+    pub fn extract(document: &Html, finding: impl Fn(&ElementRef) -> Finding) -> Self {
+        let mut data = StructuredData::default();
+
+        let Ok(script_selector) = Selector::parse("script[type]") else {
+            return data;
+        };
+        for script in document.select(&script_selector) {
+            let is_json_ld = script.value().attr("type").is_some_and(|script_type| {
+                script_type
+                    .trim()
+                    .to_ascii_lowercase()
+                    .starts_with("application/ld+json")
+            });
+            if !is_json_ld {
+                continue;
+            }
+            data.json_ld_blocks += 1;
+            let source = script.text().collect::<String>();
+            match serde_json::from_str::<Value>(&source) {
+                Ok(value) => data
+                    .entities
+                    .extend(from_json_ld(&value, &finding(&script), true)),
+                Err(error) => data.errors.push(json_ld_error(finding(&script), &error)),
+            }
+        }
+
+        for syntax in [MICRODATA, RDFA] {
+            data.entities
+                .extend(from_markup(document, &syntax, &finding));
+        }
+        data
+    }
+}
+
+// `https://schema.org/Product`, `schema:Product` and `Product` are all `Product`
+fn schema_name(name: &str) -> String {
+    name.trim()
+        .trim_end_matches('/')
+        .rsplit(['/', ':', '#'])
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+// The error position is relative to the script's content, which starts on the
+// line of the script tag
+fn json_ld_error(mut finding: Finding, error: &serde_json::Error) -> StructuredDataError {
+    let line = error.line() as u32;
+    finding.line = finding
+        .line
+        .map(|script_line| script_line + line.saturating_sub(1));
+    if line > 1 {
+        finding.column = Some(error.column() as u32);
+    }
+    let message = format!("Invalid JSON-LD: {}", error);
+    StructuredDataError {
+        finding: finding.with_value(error.to_string()),
+        message,
+    }
+}
+
+// Top-level objects, arrays of them and `@graph` lists all become entities
+fn from_json_ld(value: &Value, finding: &Finding, top_level: bool) -> Vec<Entity> {
+    match value {
+        Value::Array(values) => values
+            .iter()
+            .flat_map(|value| from_json_ld(value, finding, top_level))
+            .collect(),
+        Value::Object(object) if top_level && object.contains_key("@graph") => {
+            let mut entities = from_json_ld(&object["@graph"], finding, true);
+            if object.contains_key("@type") {
+                entities.push(json_ld_entity(object, finding));
+            }
+            entities
+        }
+        Value::Object(object) => vec![json_ld_entity(object, finding)],
+        _ => vec![],
+    }
+}
+
+fn json_ld_entity(object: &serde_json::Map<String, Value>, finding: &Finding) -> Entity {
+    let types = match object.get("@type") {
+        Some(Value::String(name)) => vec![schema_name(name)],
+        Some(Value::Array(names)) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .map(schema_name)
+            .collect(),
+        _ => vec![],
+    };
+    let properties = object
+        .iter()
+        .filter(|(key, _)| !key.starts_with('@'))
+        .map(|(key, value)| (schema_name(key), json_ld_values(value, finding)))
+        .filter(|(_, values)| !values.is_empty())
+        .collect();
+    Entity {
+        format: StructuredDataFormat::JsonLd,
+        types,
+        properties,
+        finding: finding.clone(),
+    }
+}
+
+fn json_ld_values(value: &Value, finding: &Finding) -> Vec<PropertyValue> {
+    match value {
+        Value::Null => vec![],
+        Value::String(text) => vec![PropertyValue::Text(text.clone())],
+        Value::Number(_) | Value::Bool(_) => vec![PropertyValue::Text(value.to_string())],
+        Value::Array(values) => values
+            .iter()
+            .flat_map(|value| json_ld_values(value, finding))
+            .collect(),
+        Value::Object(object) => vec![PropertyValue::Entity(Box::new(json_ld_entity(
+            object, finding,
+        )))],
+    }
+}
+
+// Microdata and RDFa Lite work the same way: an element starts an item, nested
+// elements name its properties
+struct MarkupSyntax {
+    format: StructuredDataFormat,
+    selector: &'static str,
+    type_attr: &'static str,
+    property_attr: &'static str,
+    is_item: fn(&Element) -> bool,
+}
+
+const MICRODATA: MarkupSyntax = MarkupSyntax {
+    format: StructuredDataFormat::Microdata,
+    selector: "[itemscope]",
+    type_attr: "itemtype",
+    property_attr: "itemprop",
+    is_item: |element| element.attr("itemscope").is_some(),
+};
+
+const RDFA: MarkupSyntax = MarkupSyntax {
+    format: StructuredDataFormat::Rdfa,
+    selector: "[typeof]",
+    type_attr: "typeof",
+    property_attr: "property",
+    is_item: |element| element.attr("typeof").is_some(),
+};
+
+// Items that aren't the value of another item's property
+fn from_markup(
+    document: &Html,
+    syntax: &MarkupSyntax,
+    finding: &impl Fn(&ElementRef) -> Finding,
+) -> Vec<Entity> {
+    let Ok(selector) = Selector::parse(syntax.selector) else {
+        return vec![];
+    };
+    document
+        .select(&selector)
+        .filter(|element| element.value().attr(syntax.property_attr).is_none())
+        .map(|element| markup_entity(element, syntax, finding))
+        .collect()
+}
+
+fn markup_entity(
+    element: ElementRef,
+    syntax: &MarkupSyntax,
+    finding: &impl Fn(&ElementRef) -> Finding,
+) -> Entity {
+    let mut properties = BTreeMap::new();
+    markup_properties(element, syntax, finding, &mut properties);
+    Entity {
+        format: syntax.format,
+        types: element
+            .value()
+            .attr(syntax.type_attr)
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(schema_name)
+            .collect(),
+        properties,
+        finding: finding(&element),
+    }
+}
+
+// Properties below `element`, without descending into nested items
+fn markup_properties(
+    element: ElementRef,
+    syntax: &MarkupSyntax,
+    finding: &impl Fn(&ElementRef) -> Finding,
+    properties: &mut BTreeMap<String, Vec<PropertyValue>>,
+) {
+    for child in element.children().filter_map(ElementRef::wrap) {
+        let is_item = (syntax.is_item)(child.value());
+        if let Some(names) = child.value().attr(syntax.property_attr) {
+            let value = if is_item {
+                PropertyValue::Entity(Box::new(markup_entity(child, syntax, finding)))
+            } else {
+                PropertyValue::Text(markup_value(child))
+            };
+            for name in names.split_whitespace() {
+                properties
+                    .entry(schema_name(name))
+                    .or_default()
+                    .push(value.clone());
+            }
+        }
+        if !is_item {
+            markup_properties(child, syntax, finding, properties);
+        }
+    }
+}
+
+fn markup_value(element: ElementRef) -> String {
+    let attr = |name: &str| {
+        element
+            .value()
+            .attr(name)
+            .map(|value| value.trim().to_string())
+    };
+    let value = match element.value().name() {
+        _ if element.value().attr("content").is_some() => attr("content"),
+        "a" | "area" | "link" => attr("href"),
+        "img" | "audio" | "video" | "source" | "iframe" | "embed" | "track" => attr("src"),
+        "object" => attr("data"),
+        "time" => attr("datetime"),
+        "data" | "meter" => attr("value"),
+        _ => None,
+    };
+    value.unwrap_or_else(|| collapse_whitespace(&element.text().collect::<String>()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::finding::SourcePositions;
+
+    fn extract(html: &str) -> StructuredData {
+        let document = Html::parse_document(html);
+        let positions = SourcePositions::new(&document, html);
+        StructuredData::extract(&document, |element| {
+            Finding::from_element(element, &positions)
+        })
+    }
+
+    fn text<'a>(entity: &'a Entity, property: &str) -> &'a str {
+        match entity.values(property).first() {
+            Some(PropertyValue::Text(text)) => text,
+            _ => "",
+        }
+    }
+
+    fn entity<'a>(entity: &'a Entity, property: &str) -> &'a Entity {
+        match entity.values(property).first() {
+            Some(PropertyValue::Entity(entity)) => entity,
+            _ => panic!("{property} is not an entity"),
+        }
+    }
+
+    #[test]
+    fn test_json_ld() {
+        let data = extract(
+            r#"<html><head>
+            <script type="application/ld+json">
+            {"@context": "https://schema.org", "@graph": [
+                {"@type": "Organization", "name": "Example", "sameAs": ["https://a.example", "https://b.example"]},
+                {"@type": ["Product", "https://schema.org/IndividualProduct"], "name": "Shoe",
+                 "offers": {"@type": "Offer", "price": 89.5, "priceCurrency": "EUR"}}
+            ]}
+            </script>
+            <script type="text/javascript">{"@type": "Ignored"}</script>
+            </head></html>"#,
+        );
+        assert_eq!(data.json_ld_blocks, 1);
+        assert!(data.errors.is_empty());
+        assert_eq!(data.entities.len(), 2);
+
+        let organization = &data.entities[0];
+        assert_eq!(organization.format, StructuredDataFormat::JsonLd);
+        assert_eq!(organization.values("sameAs").len(), 2);
+
+        let product = &data.entities[1];
+        assert_eq!(product.types, vec!["Product", "IndividualProduct"]);
+        let offer = entity(product, "offers");
+        assert!(offer.is_a("Offer"));
+        assert_eq!(text(offer, "price"), "89.5");
+        assert_eq!(product.descendants().len(), 2);
+    }
+
+    #[test]
+    fn test_invalid_json_ld_reports_position() {
+        let html = "<html><head>\n<script type=\"application/ld+json\">\n{\n  \"@type\": \"Article\"\n  \"headline\": \"Hi\"\n}\n</script>\n</head></html>";
+        let data = extract(html);
+        assert_eq!(data.json_ld_blocks, 1);
+        assert!(data.entities.is_empty());
+        let error = &data.errors[0];
+        // The script tag is on line 2, the missing comma on line 4 of the script
+        assert_eq!(error.finding.line, Some(5));
+        assert_eq!(error.finding.column, Some(3));
+        assert!(
+            error
+                .message
+                .starts_with("Invalid JSON-LD: expected `,` or `}`")
+        );
+    }
+
+    #[test]
+    fn test_microdata() {
+        let data = extract(
+            r#"<html><body>
+            <div itemscope itemtype="https://schema.org/Product">
+                <h1 itemprop="name">Trail <em>Shoe</em></h1>
+                <img itemprop="image" src="/shoe.jpg">
+                <div itemprop="offers" itemscope itemtype="https://schema.org/Offer">
+                    <meta itemprop="priceCurrency" content="EUR">
+                    <span itemprop="price">89.50</span>
+                    <span itemprop="name">Not the product's name</span>
+                </div>
+            </div>
+            </body></html>"#,
+        );
+        assert_eq!(data.entities.len(), 1);
+        let product = &data.entities[0];
+        assert_eq!(product.format, StructuredDataFormat::Microdata);
+        assert!(product.is_a("Product"));
+        assert_eq!(text(product, "name"), "Trail Shoe");
+        assert_eq!(text(product, "image"), "/shoe.jpg");
+        assert_eq!(product.values("name").len(), 1);
+        let offer = entity(product, "offers");
+        assert_eq!(text(offer, "priceCurrency"), "EUR");
+        assert_eq!(text(offer, "price"), "89.50");
+        assert_eq!(product.finding.line, Some(2));
+    }
+
+    #[test]
+    fn test_rdfa() {
+        let data = extract(
+            r#"<html><body vocab="https://schema.org/">
+            <ol typeof="BreadcrumbList">
+                <li property="itemListElement" typeof="ListItem">
+                    <a property="item" href="/shoes"><span property="name">Shoes</span></a>
+                    <meta property="position" content="1">
+                </li>
+            </ol>
+            </body></html>"#,
+        );
+        assert_eq!(data.entities.len(), 1);
+        let breadcrumbs = &data.entities[0];
+        assert_eq!(breadcrumbs.format, StructuredDataFormat::Rdfa);
+        assert!(breadcrumbs.is_a("BreadcrumbList"));
+        let item = entity(breadcrumbs, "itemListElement");
+        assert_eq!(text(item, "item"), "/shoes");
+        assert_eq!(text(item, "name"), "Shoes");
+        assert_eq!(text(item, "position"), "1");
+    }
+}
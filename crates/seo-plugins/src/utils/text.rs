@@ -0,0 +1,99 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+// Words too common to tell whether two texts are about the same thing
+const STOP_WORDS: &[&str] = &[
+    "and", "are", "for", "from", "how", "our", "the", "this", "what", "with", "you", "your",
+];
+
+// Collapses whitespace runs into single spaces and trims the ends
+pub fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// The lowercased words of at least three letters that say what a text is about
+pub fn topic_words(text: &str) -> BTreeSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(|word| word.to_lowercase())
+        .filter(|word| word.chars().count() >= 3 && !STOP_WORDS.contains(&word.as_str()))
+        // Crude singular, so "shoe" matches "shoes"
+        .map(|word| word.strip_suffix('s').map(str::to_string).unwrap_or(word))
+        .collect()
+}
+
+// Whether two texts share a topic word
+pub fn shares_topic(a: &str, b: &str) -> bool {
+    !topic_words(a).is_disjoint(&topic_words(b))
+}
+
+// Texts used by more than one URL, compared case-insensitively, with the URLs using
+// each. The same text twice on one URL isn't a duplicate.
+pub fn duplicate_texts(
+    texts_by_url: &HashMap<String, Vec<String>>,
+) -> HashMap<String, Vec<String>> {
+    let mut urls_by_text: BTreeMap<String, (String, Vec<String>)> = BTreeMap::new();
+    for (url, texts) in texts_by_url {
+        for text in texts {
+            let (_, urls) = urls_by_text
+                .entry(text.to_lowercase())
+                .or_insert_with(|| (text.clone(), vec![]));
+            if !urls.contains(url) {
+                urls.push(url.clone());
+            }
+        }
+    }
+    urls_by_text
+        .into_values()
+        .filter(|(_, urls)| urls.len() > 1)
+        .map(|(text, mut urls)| {
+            urls.sort();
+            (text, urls)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topic_words() {
+        assert_eq!(collapse_whitespace("  Trail \n running  "), "Trail running");
+        assert_eq!(
+            topic_words("The Trail Shoes | Example"),
+            ["example", "shoe", "trail"]
+                .into_iter()
+                .map(str::to_string)
+                .collect()
+        );
+        assert!(shares_topic("Running shoes", "Shoes for running | Example"));
+        assert!(!shares_topic("About us", "Read more"));
+    }
+
+    #[test]
+    fn test_duplicate_texts() {
+        let texts_by_url: HashMap<String, Vec<String>> = [
+            ("/a", vec!["Trail Shoes"]),
+            ("/b", vec!["trail shoes", "Sale"]),
+            ("/c", vec!["Sale"]),
+            ("/d", vec!["About us"]),
+            ("/e", vec!["Contact", "Contact"]),
+        ]
+        .into_iter()
+        .map(|(url, texts)| {
+            (
+                url.to_string(),
+                texts.into_iter().map(String::from).collect(),
+            )
+        })
+        .collect();
+
+        let duplicates = duplicate_texts(&texts_by_url);
+        assert_eq!(duplicates.len(), 2);
+        assert_eq!(duplicates["Sale"], vec!["/b", "/c"]);
+        let trail_shoes = duplicates
+            .iter()
+            .find(|(text, _)| text.to_lowercase() == "trail shoes")
+            .unwrap();
+        assert_eq!(trail_shoes.1, &vec!["/a".to_string(), "/b".to_string()]);
+    }
+}
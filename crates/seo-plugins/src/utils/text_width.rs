@@ -0,0 +1,45 @@
+// Rough rendered width of a line of text, used to predict where search results truncate titles.
+// Widths are Arial advance widths in 1/1000 em; Google renders titles in Arial at 20px.
+
+const TITLE_FONT_SIZE_PX: f64 = 20.0;
+
+fn advance_width(c: char) -> u32 {
+    match c {
+        'i' | 'j' | 'l' => 222,
+        ' ' | 'f' | 't' | 'I' | '.' | ',' | ':' | ';' | '!' | '/' | '\\' => 278,
+        '\'' => 191,
+        '|' => 260,
+        'r' | '-' | '(' | ')' | '[' | ']' => 333,
+        '"' => 355,
+        'c' | 'k' | 's' | 'v' | 'x' | 'y' | 'z' | 'J' => 500,
+        'L' => 556,
+        'F' | 'T' | 'Z' => 611,
+        'A' | 'B' | 'E' | 'K' | 'P' | 'S' | 'V' | 'X' | 'Y' | '&' => 667,
+        'w' | 'C' | 'D' | 'H' | 'N' | 'R' | 'U' => 722,
+        'G' | 'O' | 'Q' => 778,
+        'm' | 'M' => 833,
+        'W' => 944,
+        '%' => 889,
+        '@' => 1015,
+        // CJK and other full-width characters take a whole em
+        c if c as u32 >= 0x2E80 => 1000,
+        _ => 556,
+    }
+}
+
+pub fn title_pixel_width(text: &str) -> f64 {
+    let units: u32 = text.chars().map(advance_width).sum();
+    units as f64 * TITLE_FONT_SIZE_PX / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_narrow_characters_fit_more_per_pixel() {
+        assert_eq!(title_pixel_width(""), 0.0);
+        assert!(title_pixel_width(&"i".repeat(60)) < title_pixel_width(&"W".repeat(60)));
+        assert_eq!(title_pixel_width("W"), 18.88);
+    }
+}
@@ -11,6 +11,21 @@ impl MigratorTrait for Migrator {
             Box::new(m20250514_211317_create_site_page_table::Migration),
             Box::new(m20250516_171758_create_plugin_rule_table::Migration),
             Box::new(m20250516_193257_update_page_rule_result::Migration),
+            Box::new(m20250520_093012_create_rule_finding_table::Migration),
+            Box::new(m20250521_081500_add_status_to_page_rule_result::Migration),
+            Box::new(m20250522_140210_add_severity_override_to_plugin_rule::Migration),
+            Box::new(m20250523_091500_create_rule_profile_table::Migration),
+            Box::new(m20250523_093000_add_rule_profile_to_site_and_site_run::Migration),
+            Box::new(m20250524_101500_add_params_to_plugin_rule::Migration),
+            Box::new(m20250526_090000_add_health_scores::Migration),
+            Box::new(m20250527_090000_add_remediation_to_plugin_rule::Migration),
+            Box::new(m20250528_090000_add_message_to_page_rule_result::Migration),
+            Box::new(m20250529_090000_add_metadata_to_site_page::Migration),
+            Box::new(m20250530_090000_create_site_page_link_table::Migration),
+            Box::new(m20250531_090000_add_link_metrics_to_site_page::Migration),
+            Box::new(m20250601_090000_add_share_preview_to_site_page::Migration),
+            Box::new(m20250602_090000_add_content_metrics_to_site_page::Migration),
+            Box::new(m20250603_090000_create_score_weights_table::Migration),
         ]
     }
 }
@@ -19,3 +34,18 @@ mod m20250514_171121_create_site_run_table;
 mod m20250514_211317_create_site_page_table;
 mod m20250516_171758_create_plugin_rule_table;
 mod m20250516_193257_update_page_rule_result;
+mod m20250520_093012_create_rule_finding_table;
+mod m20250521_081500_add_status_to_page_rule_result;
+mod m20250522_140210_add_severity_override_to_plugin_rule;
+mod m20250523_091500_create_rule_profile_table;
+mod m20250523_093000_add_rule_profile_to_site_and_site_run;
+mod m20250524_101500_add_params_to_plugin_rule;
+mod m20250526_090000_add_health_scores;
+mod m20250527_090000_add_remediation_to_plugin_rule;
+mod m20250528_090000_add_message_to_page_rule_result;
+mod m20250529_090000_add_metadata_to_site_page;
+mod m20250530_090000_create_site_page_link_table;
+mod m20250531_090000_add_link_metrics_to_site_page;
+mod m20250601_090000_add_share_preview_to_site_page;
+mod m20250602_090000_add_content_metrics_to_site_page;
+mod m20250603_090000_create_score_weights_table;
@@ -0,0 +1,70 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20250514_171121_create_site_run_table::SiteRun,
+    m20250516_193257_update_page_rule_result::PageRuleResult,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RuleFinding::Table)
+                    .if_not_exists()
+                    .col(pk_auto(RuleFinding::Id))
+                    .col(integer(RuleFinding::PageRuleResultId))
+                    .col(integer(RuleFinding::SiteRunId))
+                    .col(string(RuleFinding::Selector))
+                    .col(text(RuleFinding::Html))
+                    .col(integer_null(RuleFinding::SourceLine))
+                    .col(integer_null(RuleFinding::SourceColumn))
+                    .col(string_null(RuleFinding::Value))
+                    .col(
+                        ColumnDef::new(RuleFinding::CreatedAt)
+                            .timestamp()
+                            .default(Expr::current_timestamp())
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_rule_finding_page_rule_result_id")
+                            .from(RuleFinding::Table, RuleFinding::PageRuleResultId)
+                            .to(PageRuleResult::Table, PageRuleResult::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_rule_finding_site_run_id")
+                            .from(RuleFinding::Table, RuleFinding::SiteRunId)
+                            .to(SiteRun::Table, SiteRun::Id),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RuleFinding::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum RuleFinding {
+    Table,
+    Id,
+    PageRuleResultId,
+    SiteRunId,
+    Selector,
+    Html,
+    SourceLine,
+    SourceColumn,
+    Value,
+    CreatedAt,
+}
@@ -0,0 +1,47 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20250516_193257_update_page_rule_result::PageRuleResult;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PageRuleResult::Table)
+                    .add_column(string(PageRuleResultStatus::Status).default("Passed"))
+                    .to_owned(),
+            )
+            .await?;
+
+        // Results stored before statuses existed were either passed or failed
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(PageRuleResult::Table)
+                    .value(PageRuleResultStatus::Status, "Failed")
+                    .and_where(Expr::col(PageRuleResult::Passed).eq(false))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PageRuleResult::Table)
+                    .drop_column(PageRuleResultStatus::Status)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PageRuleResultStatus {
+    Status,
+}
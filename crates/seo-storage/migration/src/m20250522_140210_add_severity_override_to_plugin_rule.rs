@@ -0,0 +1,36 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20250516_171758_create_plugin_rule_table::PluginRule;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PluginRule::Table)
+                    .add_column(string_null(PluginRuleSeverityOverride::SeverityOverride))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PluginRule::Table)
+                    .drop_column(PluginRuleSeverityOverride::SeverityOverride)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PluginRuleSeverityOverride {
+    SeverityOverride,
+}
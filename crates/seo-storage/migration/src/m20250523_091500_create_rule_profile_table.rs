@@ -0,0 +1,38 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let create_table = Table::create()
+            .table(RuleProfile::Table)
+            .if_not_exists()
+            .col(pk_auto(RuleProfile::Id))
+            .col(string_uniq(RuleProfile::Name))
+            .col(text(RuleProfile::Description).default(""))
+            .col(text(RuleProfile::Rules))
+            .col(boolean(RuleProfile::EnableUnlisted).default(false))
+            .to_owned();
+        let create_table = timestamps(create_table);
+
+        manager.create_table(create_table.to_owned()).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RuleProfile::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum RuleProfile {
+    Table,
+    Id,
+    Name,
+    Description,
+    Rules,
+    EnableUnlisted,
+}
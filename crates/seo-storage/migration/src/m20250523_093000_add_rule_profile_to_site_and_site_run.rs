@@ -0,0 +1,56 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20250514_154203_create_site_table::Site, m20250514_171121_create_site_run_table::SiteRun,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// SQLite can't add foreign keys to existing tables, so these are plain columns
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Site::Table)
+                    .add_column(integer_null(RuleProfileRef::DefaultProfileId))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SiteRun::Table)
+                    .add_column(integer_null(RuleProfileRef::RuleProfileId))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SiteRun::Table)
+                    .drop_column(RuleProfileRef::RuleProfileId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Site::Table)
+                    .drop_column(RuleProfileRef::DefaultProfileId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum RuleProfileRef {
+    DefaultProfileId,
+    RuleProfileId,
+}
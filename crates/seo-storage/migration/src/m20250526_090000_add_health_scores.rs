@@ -0,0 +1,74 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20250514_171121_create_site_run_table::SiteRun,
+    m20250516_171758_create_plugin_rule_table::PluginRule,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// SQLite only adds one column per ALTER TABLE statement
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PluginRule::Table)
+                    .add_column(integer_null(HealthScore::Weight))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SiteRun::Table)
+                    .add_column(integer_null(HealthScore::Score))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SiteRun::Table)
+                    .add_column(text_null(HealthScore::CategoryScores))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SiteRun::Table)
+                    .drop_column(HealthScore::CategoryScores)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SiteRun::Table)
+                    .drop_column(HealthScore::Score)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PluginRule::Table)
+                    .drop_column(HealthScore::Weight)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum HealthScore {
+    Weight,
+    Score,
+    CategoryScores,
+}
@@ -0,0 +1,53 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20250516_193257_update_page_rule_result::PageRuleResult;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// Results stored before these columns existed fall back to the rule's messages and severity
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            text_null(PageRuleResultMessage::Message),
+            string_null(PageRuleResultMessage::Severity),
+            text_null(PageRuleResultMessage::Context),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(PageRuleResult::Table)
+                        .add_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            PageRuleResultMessage::Context,
+            PageRuleResultMessage::Severity,
+            PageRuleResultMessage::Message,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(PageRuleResult::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum PageRuleResultMessage {
+    Message,
+    Severity,
+    Context,
+}
@@ -0,0 +1,71 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20250514_211317_create_site_page_table::SitePage;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// Pages stored before these columns existed have no crawl metadata
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            integer_null(SitePageMetadata::StatusCode),
+            integer_null(SitePageMetadata::ResponseTimeMs),
+            integer_null(SitePageMetadata::ContentLength),
+            boolean_null(SitePageMetadata::Redirected),
+            text_null(SitePageMetadata::Title),
+            text_null(SitePageMetadata::Description),
+            text_null(SitePageMetadata::Canonical),
+            integer_null(SitePageMetadata::Depth),
+            string_null(SitePageMetadata::DiscoverySource),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(SitePage::Table)
+                        .add_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            SitePageMetadata::DiscoverySource,
+            SitePageMetadata::Depth,
+            SitePageMetadata::Canonical,
+            SitePageMetadata::Description,
+            SitePageMetadata::Title,
+            SitePageMetadata::Redirected,
+            SitePageMetadata::ContentLength,
+            SitePageMetadata::ResponseTimeMs,
+            SitePageMetadata::StatusCode,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(SitePage::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum SitePageMetadata {
+    StatusCode,
+    ResponseTimeMs,
+    ContentLength,
+    Redirected,
+    Title,
+    Description,
+    Canonical,
+    Depth,
+    DiscoverySource,
+}
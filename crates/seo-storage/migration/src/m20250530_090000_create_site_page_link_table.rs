@@ -0,0 +1,97 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20250514_171121_create_site_run_table::SiteRun,
+    m20250514_211317_create_site_page_table::SitePage,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// One row per link found on a crawled page, from the page to the page it points at
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SitePageLink::Table)
+                    .if_not_exists()
+                    .col(pk_auto(SitePageLink::Id))
+                    .col(integer(SitePageLink::SiteRunId))
+                    .col(integer(SitePageLink::SourcePageId))
+                    .col(integer(SitePageLink::TargetPageId))
+                    .col(text(SitePageLink::AnchorText))
+                    .col(string_null(SitePageLink::Rel))
+                    .col(string(SitePageLink::Position))
+                    .col(
+                        ColumnDef::new(SitePageLink::CreatedAt)
+                            .timestamp()
+                            .default(Expr::current_timestamp())
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_site_page_link_site_run_id")
+                            .from(SitePageLink::Table, SitePageLink::SiteRunId)
+                            .to(SiteRun::Table, SiteRun::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_site_page_link_source_page_id")
+                            .from(SitePageLink::Table, SitePageLink::SourcePageId)
+                            .to(SitePage::Table, SitePage::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_site_page_link_target_page_id")
+                            .from(SitePageLink::Table, SitePageLink::TargetPageId)
+                            .to(SitePage::Table, SitePage::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        for (name, column) in [
+            (
+                "idx_site_page_link_source_page_id",
+                SitePageLink::SourcePageId,
+            ),
+            (
+                "idx_site_page_link_target_page_id",
+                SitePageLink::TargetPageId,
+            ),
+        ] {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(name)
+                        .table(SitePageLink::Table)
+                        .col(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SitePageLink::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SitePageLink {
+    Table,
+    Id,
+    SiteRunId,
+    SourcePageId,
+    TargetPageId,
+    AnchorText,
+    Rel,
+    Position,
+    CreatedAt,
+}
@@ -0,0 +1,52 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20250514_211317_create_site_page_table::SitePage;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            integer_null(SitePageLinkMetrics::Inlinks),
+            integer_null(SitePageLinkMetrics::Outlinks),
+            double_null(SitePageLinkMetrics::LinkScore),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(SitePage::Table)
+                        .add_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            SitePageLinkMetrics::LinkScore,
+            SitePageLinkMetrics::Outlinks,
+            SitePageLinkMetrics::Inlinks,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(SitePage::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum SitePageLinkMetrics {
+    Inlinks,
+    Outlinks,
+    LinkScore,
+}
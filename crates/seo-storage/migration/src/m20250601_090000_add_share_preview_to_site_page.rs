@@ -0,0 +1,36 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20250514_211317_create_site_page_table::SitePage;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SitePage::Table)
+                    .add_column(text_null(SitePageSharePreview::SharePreview))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SitePage::Table)
+                    .drop_column(SitePageSharePreview::SharePreview)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SitePageSharePreview {
+    SharePreview,
+}
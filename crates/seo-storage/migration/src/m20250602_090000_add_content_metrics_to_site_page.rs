@@ -0,0 +1,58 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20250514_211317_create_site_page_table::SitePage;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            integer_null(SitePageContentMetrics::WordCount),
+            integer_null(SitePageContentMetrics::ParagraphCount),
+            float_null(SitePageContentMetrics::TextHtmlRatio),
+            float_null(SitePageContentMetrics::ReadingEase),
+            float_null(SitePageContentMetrics::GradeLevel),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(SitePage::Table)
+                        .add_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            SitePageContentMetrics::GradeLevel,
+            SitePageContentMetrics::ReadingEase,
+            SitePageContentMetrics::TextHtmlRatio,
+            SitePageContentMetrics::ParagraphCount,
+            SitePageContentMetrics::WordCount,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(SitePage::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum SitePageContentMetrics {
+    WordCount,
+    ParagraphCount,
+    TextHtmlRatio,
+    ReadingEase,
+    GradeLevel,
+}
@@ -0,0 +1,40 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// Holds a single row, the severity weights used for health scores
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ScoreWeights::Table)
+                    .if_not_exists()
+                    .col(pk_auto(ScoreWeights::Id))
+                    .col(integer(ScoreWeights::Info))
+                    .col(integer(ScoreWeights::Warning))
+                    .col(integer(ScoreWeights::Error))
+                    .col(integer(ScoreWeights::Critical))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ScoreWeights::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ScoreWeights {
+    Table,
+    Id,
+    Info,
+    Warning,
+    Error,
+    Critical,
+}
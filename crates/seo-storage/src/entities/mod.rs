@@ -4,6 +4,10 @@ pub mod prelude;
 
 pub mod page_rule_result;
 pub mod plugin_rule;
+pub mod rule_finding;
+pub mod rule_profile;
+pub mod score_weights;
 pub mod site;
 pub mod site_page;
+pub mod site_page_link;
 pub mod site_run;
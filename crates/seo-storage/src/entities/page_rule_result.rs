@@ -3,6 +3,9 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::enums::{db_rule_status::DbRuleStatus, plugin_rule_enums::DbSeverity};
+use crate::utils::result_context::ResultContext;
+
 #[cfg(test)]
 use fake::Dummy;
 
@@ -21,6 +24,15 @@ pub struct Model {
     pub rule_id: String,
     pub passed: bool,
     pub created_at: DateTimeUtc,
+    pub status: DbRuleStatus,
+    // What the check reported, e.g. "3 images missing srcset"
+    #[sea_orm(column_type = "Text", nullable)]
+    pub message: Option<String>,
+    // Severity the rule ran with, after overrides
+    pub severity: Option<DbSeverity>,
+    // JSON `ResultContext` for site rules that group pages by a value
+    #[sea_orm(column_type = "Text", nullable)]
+    pub context: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -33,6 +45,8 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     PluginRule,
+    #[sea_orm(has_many = "super::rule_finding::Entity")]
+    RuleFinding,
     #[sea_orm(
         belongs_to = "super::site::Entity",
         from = "Column::SiteId",
@@ -65,6 +79,12 @@ impl Related<super::plugin_rule::Entity> for Entity {
     }
 }
 
+impl Related<super::rule_finding::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RuleFinding.def()
+    }
+}
+
 impl Related<super::site::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Site.def()
@@ -84,3 +104,13 @@ impl Related<super::site_run::Entity> for Entity {
 }
 
 impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    pub fn context(&self) -> Result<Option<ResultContext>, DbErr> {
+        self.context
+            .as_deref()
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|e| DbErr::Json(e.to_string()))
+    }
+}
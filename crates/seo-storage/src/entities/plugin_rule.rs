@@ -3,7 +3,9 @@
 use fake::Dummy;
 
 use sea_orm::entity::prelude::*;
+use seo_plugins::utils::{config::Remediation, rule_param::RuleParamValue};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 use crate::enums::plugin_rule_enums::{DbRuleCategory, DbRuleType, DbSeverity};
 
@@ -27,6 +29,15 @@ pub struct Model {
     pub enabled: bool,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
+    pub severity_override: Option<DbSeverity>,
+    // Parameter overrides as a JSON object of name to value
+    #[sea_orm(column_type = "Text", nullable)]
+    pub params: Option<String>,
+    // Health score weight, replacing the weight of the rule's severity
+    pub weight: Option<i32>,
+    // How to fix a failure, as a JSON `Remediation`
+    #[sea_orm(column_type = "Text", nullable)]
+    pub remediation: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -42,3 +53,20 @@ impl Related<super::page_rule_result::Entity> for Entity {
 }
 
 impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    pub fn param_overrides(&self) -> Result<BTreeMap<String, RuleParamValue>, DbErr> {
+        match &self.params {
+            Some(params) => serde_json::from_str(params).map_err(|e| DbErr::Json(e.to_string())),
+            None => Ok(BTreeMap::new()),
+        }
+    }
+
+    pub fn remediation(&self) -> Result<Option<Remediation>, DbErr> {
+        self.remediation
+            .as_deref()
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|e| DbErr::Json(e.to_string()))
+    }
+}
@@ -2,6 +2,10 @@
 
 pub use super::page_rule_result::Entity as PageRuleResult;
 pub use super::plugin_rule::Entity as PluginRule;
+pub use super::rule_finding::Entity as RuleFinding;
+pub use super::rule_profile::Entity as RuleProfile;
+pub use super::score_weights::Entity as ScoreWeights;
 pub use super::site::Entity as Site;
 pub use super::site_page::Entity as SitePage;
+pub use super::site_page_link::Entity as SitePageLink;
 pub use super::site_run::Entity as SiteRun;
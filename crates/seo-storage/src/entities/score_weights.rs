@@ -0,0 +1,34 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::health_score::ScoreWeights;
+
+// The only row, saved by `SeoStorage::set_score_weights`
+pub const SCORE_WEIGHTS_ID: i32 = 1;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "score_weights")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub info: i32,
+    pub warning: i32,
+    pub error: i32,
+    pub critical: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for ScoreWeights {
+    fn from(model: Model) -> Self {
+        Self {
+            info: model.info,
+            warning: model.warning,
+            error: model.error,
+            critical: model.critical,
+        }
+    }
+}
@@ -15,6 +15,7 @@ pub struct Model {
     #[sea_orm(unique)]
     pub url: String,
     pub created_at: DateTimeUtc,
+    pub default_profile_id: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
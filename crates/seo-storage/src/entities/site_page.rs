@@ -1,16 +1,15 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.11
 
 use sea_orm::entity::prelude::*;
+use seo_plugins::utils::share_preview::SharePreview;
 use serde::{Deserialize, Serialize};
 
-use crate::enums::db_link_type::DbLinkType;
+use crate::enums::{db_link_source_type::DbLinkSourceType, db_link_type::DbLinkType};
 
 #[cfg(test)]
 use fake::Dummy;
 
-#[derive(
-    Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, specta :: Type,
-)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize, specta :: Type)]
 #[cfg_attr(test, derive(Dummy))]
 #[sea_orm(table_name = "site_page")]
 #[specta(rename = "SitePageModel")]
@@ -22,6 +21,34 @@ pub struct Model {
     pub url: String,
     pub db_link_type: DbLinkType,
     pub created_at: DateTimeUtc,
+    pub status_code: Option<i32>,
+    pub response_time_ms: Option<i32>,
+    pub content_length: Option<i32>,
+    pub redirected: Option<bool>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub title: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub description: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub canonical: Option<String>,
+    // Links followed from the root page to reach this page, `None` for pages only found
+    // in the sitemap
+    pub depth: Option<i32>,
+    pub discovery_source: Option<DbLinkSourceType>,
+    // Distinct internal pages linking to and linked from this page
+    pub inlinks: Option<i32>,
+    pub outlinks: Option<i32>,
+    // Internal PageRank, 100 for the strongest page of the run
+    pub link_score: Option<f64>,
+    // `SharePreview` as JSON
+    #[sea_orm(column_type = "Text", nullable)]
+    pub share_preview: Option<String>,
+    // Main content metrics, the ratio in percent
+    pub word_count: Option<i32>,
+    pub paragraph_count: Option<i32>,
+    pub text_html_ratio: Option<f32>,
+    pub reading_ease: Option<f32>,
+    pub grade_level: Option<f32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -65,3 +92,13 @@ impl Related<super::site_run::Entity> for Entity {
 }
 
 impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    pub fn share_preview(&self) -> Result<Option<SharePreview>, DbErr> {
+        self.share_preview
+            .as_deref()
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|e| DbErr::Json(e.to_string()))
+    }
+}
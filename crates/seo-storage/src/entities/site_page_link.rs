@@ -0,0 +1,63 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::enums::db_link_position::DbLinkPosition;
+
+#[cfg(test)]
+use fake::Dummy;
+
+#[derive(
+    Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, specta :: Type,
+)]
+#[cfg_attr(test, derive(Dummy))]
+#[sea_orm(table_name = "site_page_link")]
+#[specta(rename = "SitePageLinkModel")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub site_run_id: i32,
+    pub source_page_id: i32,
+    pub target_page_id: i32,
+    #[sea_orm(column_type = "Text")]
+    pub anchor_text: String,
+    // Space separated, lowercased `rel` values
+    pub rel: Option<String>,
+    pub position: DbLinkPosition,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::site_run::Entity",
+        from = "Column::SiteRunId",
+        to = "super::site_run::Column::Id",
+        on_update = "NoAction",
+        on_delete = "NoAction"
+    )]
+    SiteRun,
+    #[sea_orm(
+        belongs_to = "super::site_page::Entity",
+        from = "Column::SourcePageId",
+        to = "super::site_page::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    SourcePage,
+    #[sea_orm(
+        belongs_to = "super::site_page::Entity",
+        from = "Column::TargetPageId",
+        to = "super::site_page::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    TargetPage,
+}
+
+impl Related<super::site_run::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SiteRun.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,9 +1,11 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.11
 
+use std::collections::HashMap;
+
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use crate::enums::site_run_status::SiteRunStatus;
+use crate::enums::{plugin_rule_enums::DbRuleCategory, site_run_status::SiteRunStatus};
 
 #[derive(
     Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, specta :: Type,
@@ -16,12 +18,20 @@ pub struct Model {
     pub site_id: i32,
     pub created_at: DateTimeUtc,
     pub status: SiteRunStatus,
+    pub rule_profile_id: Option<i32>,
+    // 0-100 health score, set once the crawl results are stored
+    pub score: Option<i32>,
+    // Scores per category as a JSON object of category to score
+    #[sea_orm(column_type = "Text", nullable)]
+    pub category_scores: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(has_many = "super::page_rule_result::Entity")]
     PageRuleResult,
+    #[sea_orm(has_many = "super::rule_finding::Entity")]
+    RuleFinding,
     #[sea_orm(
         belongs_to = "super::site::Entity",
         from = "Column::SiteId",
@@ -40,6 +50,12 @@ impl Related<super::page_rule_result::Entity> for Entity {
     }
 }
 
+impl Related<super::rule_finding::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RuleFinding.def()
+    }
+}
+
 impl Related<super::site::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Site.def()
@@ -53,3 +69,12 @@ impl Related<super::site_page::Entity> for Entity {
 }
 
 impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    pub fn category_scores(&self) -> Result<HashMap<DbRuleCategory, i32>, DbErr> {
+        match &self.category_scores {
+            Some(scores) => serde_json::from_str(scores).map_err(|e| DbErr::Json(e.to_string())),
+            None => Ok(HashMap::new()),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use sea_orm::entity::prelude::*;
+use seo_plugins::utils::link_parser::LinkPosition;
+
+#[cfg(test)]
+use fake::Dummy;
+
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    serde::Serialize,
+    serde::Deserialize,
+    specta::Type,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[cfg_attr(test, derive(Dummy))]
+pub enum DbLinkPosition {
+    #[sea_orm(string_value = "Header")]
+    Header,
+    #[sea_orm(string_value = "Nav")]
+    Nav,
+    #[sea_orm(string_value = "Main")]
+    Main,
+    #[sea_orm(string_value = "Aside")]
+    Aside,
+    #[sea_orm(string_value = "Footer")]
+    Footer,
+    #[sea_orm(string_value = "Body")]
+    Body,
+}
+
+impl From<LinkPosition> for DbLinkPosition {
+    fn from(position: LinkPosition) -> Self {
+        match position {
+            LinkPosition::Header => DbLinkPosition::Header,
+            LinkPosition::Nav => DbLinkPosition::Nav,
+            LinkPosition::Main => DbLinkPosition::Main,
+            LinkPosition::Aside => DbLinkPosition::Aside,
+            LinkPosition::Footer => DbLinkPosition::Footer,
+            LinkPosition::Body => DbLinkPosition::Body,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use sea_orm::entity::prelude::*;
+use seo_plugins::site_analyzer::LinkSourceType;
+
+#[cfg(test)]
+use fake::Dummy;
+
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    serde::Serialize,
+    serde::Deserialize,
+    specta::Type,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[cfg_attr(test, derive(Dummy))]
+pub enum DbLinkSourceType {
+    #[sea_orm(string_value = "Sitemap")]
+    Sitemap,
+    #[sea_orm(string_value = "Root")]
+    Root,
+    #[sea_orm(string_value = "Link")]
+    Link,
+}
+
+impl From<LinkSourceType> for DbLinkSourceType {
+    fn from(link_source_type: LinkSourceType) -> Self {
+        match link_source_type {
+            LinkSourceType::Sitemap => DbLinkSourceType::Sitemap,
+            LinkSourceType::Root => DbLinkSourceType::Root,
+            LinkSourceType::Link => DbLinkSourceType::Link,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use sea_orm::entity::prelude::*;
+use seo_plugins::utils::config::RuleStatus;
+
+#[cfg(test)]
+use fake::Dummy;
+
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    serde::Serialize,
+    serde::Deserialize,
+    specta::Type,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[cfg_attr(test, derive(Dummy))]
+pub enum DbRuleStatus {
+    #[sea_orm(string_value = "Passed")]
+    Passed,
+    #[sea_orm(string_value = "Failed")]
+    Failed,
+    #[sea_orm(string_value = "NotApplicable")]
+    NotApplicable,
+    #[sea_orm(string_value = "Errored")]
+    Errored,
+}
+
+impl From<RuleStatus> for DbRuleStatus {
+    fn from(status: RuleStatus) -> Self {
+        match status {
+            RuleStatus::Passed => DbRuleStatus::Passed,
+            RuleStatus::Failed => DbRuleStatus::Failed,
+            RuleStatus::NotApplicable => DbRuleStatus::NotApplicable,
+            RuleStatus::Errored => DbRuleStatus::Errored,
+        }
+    }
+}
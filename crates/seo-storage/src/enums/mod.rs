@@ -1,3 +1,6 @@
+pub mod db_link_position;
+pub mod db_link_source_type;
 pub mod db_link_type;
+pub mod db_rule_status;
 pub mod plugin_rule_enums;
 pub mod site_run_status;
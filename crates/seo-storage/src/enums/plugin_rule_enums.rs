@@ -39,6 +39,17 @@ impl From<Severity> for DbSeverity {
     }
 }
 
+impl From<DbSeverity> for Severity {
+    fn from(severity: DbSeverity) -> Self {
+        match severity {
+            DbSeverity::Info => Severity::Info,
+            DbSeverity::Warning => Severity::Warning,
+            DbSeverity::Error => Severity::Error,
+            DbSeverity::Critical => Severity::Critical,
+        }
+    }
+}
+
 #[derive(
     Clone,
     Debug,
@@ -1,18 +1,34 @@
-use entities::prelude::{PageRuleResult, PluginRule, SitePage, SiteRun};
-use entities::{page_rule_result, plugin_rule, site, site_page, site_run};
+use std::collections::HashMap;
+
+use entities::prelude::{PageRuleResult, PluginRule, RuleFinding, SitePage, SitePageLink, SiteRun};
+use entities::{
+    page_rule_result, plugin_rule, rule_finding, rule_profile, score_weights, site, site_page,
+    site_page_link, site_run,
+};
+use enums::db_link_position::DbLinkPosition;
+use enums::db_link_source_type::DbLinkSourceType;
 use enums::db_link_type::DbLinkType;
+use enums::db_rule_status::DbRuleStatus;
+use enums::plugin_rule_enums::DbSeverity;
 use enums::site_run_status::SiteRunStatus;
 use migration::{Migrator, MigratorTrait, OnConflict};
 use sea_orm::ConnectOptions;
 use sea_orm::*;
 use sea_orm::{Database, DbErr};
 use seo_plugins::site_analyzer::{CrawlResult, PageLink};
-use seo_plugins::utils::config::{RuleResult, SiteCheckContext};
-use seo_plugins::utils::link_parser::LinkType;
+use seo_plugins::utils::config::{
+    Finding, RuleConfig, RuleDisplay, RuleResult, RuleStatus, SiteCheckContext,
+};
+use seo_plugins::utils::link_parser::{Link, LinkType};
+use seo_plugins::utils::profile::{ProfileFormat, RuleProfile};
 use seo_plugins::utils::registry::PluginRegistry;
+use seo_plugins::utils::rule_param::RuleParamValue;
+use seo_plugins::utils::share_preview::SharePreview;
 use serde::{Deserialize, Serialize};
 use utils::category_counts::{CategoryResultDisplay, CategoryResultHistory};
 use utils::category_detail::CategoryDetailResponse;
+use utils::health_score::{HealthScore, ScoreWeights};
+use utils::result_context::ResultContext;
 pub mod entities;
 pub mod enums;
 pub mod utils;
@@ -62,6 +78,7 @@ impl SeoStorage {
         }
 
         self.seed_plugin_rule_table().await?;
+        self.seed_rule_profiles().await?;
         Ok(())
     }
     pub async fn migrate_reset(&self) -> Result<(), DbErr> {
@@ -71,9 +88,18 @@ impl SeoStorage {
 
     pub async fn seed_plugin_rule_table(&self) -> Result<(), DbErr> {
         let registry = PluginRegistry::default_with_config();
-        let rules = registry.get_available_rules();
+        self.seed_plugin_rules(registry.get_available_rules()).await
+    }
 
+    // Upserts rule metadata, keeping each rule's enabled flag and overrides.
+    // Also used for rules loaded at runtime, e.g. custom rule files.
+    pub async fn seed_plugin_rules(&self, rules: Vec<RuleDisplay>) -> Result<(), DbErr> {
         for rule in rules {
+            let remediation = rule
+                .remediation
+                .map(|remediation| serde_json::to_string(&remediation))
+                .transpose()
+                .map_err(|e| DbErr::Json(e.to_string()))?;
             let rule = plugin_rule::ActiveModel {
                 id: ActiveValue::Set(rule.id),
                 name: ActiveValue::Set(rule.name),
@@ -84,6 +110,7 @@ impl SeoStorage {
                 rule_type: ActiveValue::Set(rule.rule_type.into()),
                 passed_message: ActiveValue::Set(rule.passed_message),
                 failed_message: ActiveValue::Set(rule.failed_message),
+                remediation: ActiveValue::Set(remediation),
                 enabled: ActiveValue::Set(true),
                 ..Default::default()
             };
@@ -97,6 +124,7 @@ impl SeoStorage {
                     plugin_rule::Column::RuleType,
                     plugin_rule::Column::PassedMessage,
                     plugin_rule::Column::FailedMessage,
+                    plugin_rule::Column::Remediation,
                     // plugin_rule::Column::Enabled,
                 ])
                 .to_owned();
@@ -109,10 +137,307 @@ impl SeoStorage {
         }
         Ok(())
     }
+
+    // Adds the preset profiles, leaving existing profiles with the same name untouched
+    pub async fn seed_rule_profiles(&self) -> Result<(), DbErr> {
+        let registry = PluginRegistry::default();
+        let rules = registry.get_available_rules();
+
+        for profile in RuleProfile::presets(&rules) {
+            let existing = rule_profile::Entity::find()
+                .filter(rule_profile::Column::Name.eq(&profile.name))
+                .one(&self.db)
+                .await?;
+            if existing.is_none() {
+                self.save_rule_profile(&profile).await?;
+            }
+        }
+        Ok(())
+    }
     /* #endregion */
 
     // Database interaction
 
+    /* #region PluginRule */
+    pub async fn get_plugin_rules(&self) -> Result<Vec<plugin_rule::Model>, DbErr> {
+        PluginRule::find()
+            .order_by_asc(plugin_rule::Column::Id)
+            .all(&self.db)
+            .await
+    }
+
+    // Build the rule config the registry runs with from the persisted rule settings
+    pub async fn get_rule_config(&self) -> Result<RuleConfig, DbErr> {
+        let rules = self.get_plugin_rules().await?;
+        let mut config = RuleConfig::new();
+        for rule in rules {
+            if rule.enabled {
+                config.enable_rule(&rule.id);
+            } else {
+                config.disable_rule(&rule.id);
+            }
+            for (name, value) in rule.param_overrides()? {
+                config.set_param(&rule.id, &name, value);
+            }
+            if let Some(severity) = rule.severity_override {
+                config.set_severity(&rule.id, severity.into());
+            }
+        }
+        Ok(config)
+    }
+
+    // `None` resets the parameter to the rule's default. Values are checked against
+    // `registry`, which should hold every plugin the app runs, custom and WASM ones too.
+    pub async fn set_rule_param(
+        &self,
+        registry: &PluginRegistry,
+        rule_id: &str,
+        name: &str,
+        value: Option<RuleParamValue>,
+    ) -> Result<plugin_rule::Model, DbErr> {
+        let rule =
+            PluginRule::find_by_id(rule_id)
+                .one(&self.db)
+                .await?
+                .ok_or(DbErr::RecordNotFound(format!(
+                    "PluginRule {rule_id} not found"
+                )))?;
+        let mut params = rule.param_overrides()?;
+        match value {
+            Some(value) => {
+                registry
+                    .validate_param(rule_id, name, &value)
+                    .map_err(|e| DbErr::Custom(e.to_string()))?;
+                params.insert(name.to_string(), value);
+            }
+            None => {
+                params.remove(name);
+            }
+        }
+        let params = serde_json::to_string(&params).map_err(|e| DbErr::Json(e.to_string()))?;
+
+        let rule = plugin_rule::ActiveModel {
+            id: ActiveValue::Set(rule_id.to_string()),
+            params: ActiveValue::Set(Some(params)),
+            updated_at: ActiveValue::Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+        rule.update(&self.db).await
+    }
+
+    pub async fn set_rule_enabled(
+        &self,
+        rule_id: &str,
+        enabled: bool,
+    ) -> Result<plugin_rule::Model, DbErr> {
+        let rule = plugin_rule::ActiveModel {
+            id: ActiveValue::Set(rule_id.to_string()),
+            enabled: ActiveValue::Set(enabled),
+            updated_at: ActiveValue::Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+        rule.update(&self.db).await
+    }
+
+    // `None` resets the rule to its default severity
+    pub async fn set_rule_severity(
+        &self,
+        rule_id: &str,
+        severity: Option<DbSeverity>,
+    ) -> Result<plugin_rule::Model, DbErr> {
+        let rule = plugin_rule::ActiveModel {
+            id: ActiveValue::Set(rule_id.to_string()),
+            severity_override: ActiveValue::Set(severity),
+            updated_at: ActiveValue::Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+        rule.update(&self.db).await
+    }
+
+    // `None` resets the rule to the weight of its severity
+    pub async fn set_rule_weight(
+        &self,
+        rule_id: &str,
+        weight: Option<i32>,
+    ) -> Result<plugin_rule::Model, DbErr> {
+        let rule = plugin_rule::ActiveModel {
+            id: ActiveValue::Set(rule_id.to_string()),
+            weight: ActiveValue::Set(weight),
+            updated_at: ActiveValue::Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+        rule.update(&self.db).await
+    }
+
+    // Severity weights for health scores, the defaults until they are saved
+    pub async fn get_score_weights(&self) -> Result<ScoreWeights, DbErr> {
+        let saved = score_weights::Entity::find_by_id(score_weights::SCORE_WEIGHTS_ID)
+            .one(&self.db)
+            .await?;
+        Ok(saved.map(ScoreWeights::from).unwrap_or_default())
+    }
+
+    // Used for the health scores of site runs stored from now on
+    pub async fn set_score_weights(
+        &self,
+        score_weights: ScoreWeights,
+    ) -> Result<ScoreWeights, DbErr> {
+        let model = score_weights::ActiveModel {
+            id: ActiveValue::Set(score_weights::SCORE_WEIGHTS_ID),
+            info: ActiveValue::Set(score_weights.info),
+            warning: ActiveValue::Set(score_weights.warning),
+            error: ActiveValue::Set(score_weights.error),
+            critical: ActiveValue::Set(score_weights.critical),
+        };
+        score_weights::Entity::insert(model)
+            .on_conflict(
+                OnConflict::column(score_weights::Column::Id)
+                    .update_columns([
+                        score_weights::Column::Info,
+                        score_weights::Column::Warning,
+                        score_weights::Column::Error,
+                        score_weights::Column::Critical,
+                    ])
+                    .to_owned(),
+            )
+            .exec(&self.db)
+            .await?;
+        Ok(score_weights)
+    }
+    /* #endregion */
+
+    /* #region RuleProfile */
+    pub async fn get_rule_profiles(&self) -> Result<Vec<rule_profile::Model>, DbErr> {
+        rule_profile::Entity::find()
+            .order_by_asc(rule_profile::Column::Name)
+            .all(&self.db)
+            .await
+    }
+
+    pub async fn get_rule_profile_by_id(&self, id: i32) -> Result<rule_profile::Model, DbErr> {
+        rule_profile::Entity::find_by_id(id)
+            .one(&self.db)
+            .await?
+            .ok_or(DbErr::RecordNotFound(format!("RuleProfile {id} not found")))
+    }
+
+    // Profiles are keyed by name, saving an existing name replaces its rules
+    pub async fn save_rule_profile(
+        &self,
+        profile: &RuleProfile,
+    ) -> Result<rule_profile::Model, DbErr> {
+        let rules =
+            serde_json::to_string(&profile.rules).map_err(|e| DbErr::Json(e.to_string()))?;
+        let model = rule_profile::ActiveModel {
+            name: ActiveValue::Set(profile.name.clone()),
+            description: ActiveValue::Set(profile.description.clone()),
+            rules: ActiveValue::Set(rules),
+            enable_unlisted: ActiveValue::Set(profile.enable_unlisted),
+            updated_at: ActiveValue::Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+        let on_conflict = OnConflict::column(rule_profile::Column::Name)
+            .update_columns([
+                rule_profile::Column::Description,
+                rule_profile::Column::Rules,
+                rule_profile::Column::EnableUnlisted,
+                rule_profile::Column::UpdatedAt,
+            ])
+            .to_owned();
+        rule_profile::Entity::insert(model)
+            .on_conflict(on_conflict)
+            .exec(&self.db)
+            .await?;
+
+        rule_profile::Entity::find()
+            .filter(rule_profile::Column::Name.eq(&profile.name))
+            .one(&self.db)
+            .await?
+            .ok_or(DbErr::RecordNotInserted)
+    }
+
+    // Snapshot of the current rule settings under a new name
+    pub async fn save_current_rules_as_profile(
+        &self,
+        name: &str,
+        description: &str,
+    ) -> Result<rule_profile::Model, DbErr> {
+        let config = self.get_rule_config().await?;
+        self.save_rule_profile(&RuleProfile::new(name, description, &config))
+            .await
+    }
+
+    pub async fn delete_rule_profile(&self, id: i32) -> Result<(), DbErr> {
+        // No foreign keys on site and site_run, so clear the references by hand
+        Site::update_many()
+            .col_expr(
+                site::Column::DefaultProfileId,
+                sea_query::Expr::value(Option::<i32>::None),
+            )
+            .filter(site::Column::DefaultProfileId.eq(id))
+            .exec(&self.db)
+            .await?;
+        SiteRun::update_many()
+            .col_expr(
+                site_run::Column::RuleProfileId,
+                sea_query::Expr::value(Option::<i32>::None),
+            )
+            .filter(site_run::Column::RuleProfileId.eq(id))
+            .exec(&self.db)
+            .await?;
+        rule_profile::Entity::delete_by_id(id)
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn import_rule_profile(
+        &self,
+        contents: &str,
+        format: ProfileFormat,
+    ) -> Result<rule_profile::Model, DbErr> {
+        let profile =
+            RuleProfile::import(contents, format).map_err(|e| DbErr::Custom(e.to_string()))?;
+        PluginRegistry::default()
+            .validate_config(&profile.to_config())
+            .map_err(|e| DbErr::Custom(e.to_string()))?;
+        self.save_rule_profile(&profile).await
+    }
+
+    pub async fn export_rule_profile(
+        &self,
+        id: i32,
+        format: ProfileFormat,
+    ) -> Result<String, DbErr> {
+        let profile = self.get_rule_profile_by_id(id).await?.to_profile()?;
+        profile
+            .export(format)
+            .map_err(|e| DbErr::Custom(e.to_string()))
+    }
+
+    // The config a crawl of `url` runs with: the site's default profile if it has one,
+    // otherwise the rule settings from plugin_rule
+    pub async fn get_rule_config_for_url(
+        &self,
+        url: &str,
+    ) -> Result<(Option<i32>, RuleConfig), DbErr> {
+        let profile_id = Site::find()
+            .filter(site::Column::Url.eq(url))
+            .one(&self.db)
+            .await?
+            .and_then(|site| site.default_profile_id);
+
+        if let Some(profile_id) = profile_id {
+            let profile = self
+                .get_rule_profile_by_id(profile_id)
+                .await?
+                .to_profile()?;
+            return Ok((Some(profile_id), profile.to_config()));
+        }
+        Ok((None, self.get_rule_config().await?))
+    }
+    /* #endregion */
+
     /* #region Site */
     pub async fn get_sites(
         &self,
@@ -156,6 +481,22 @@ impl SeoStorage {
 
         Ok(res.last_insert_id)
     }
+
+    pub async fn set_site_default_profile(
+        &self,
+        site_id: i32,
+        profile_id: Option<i32>,
+    ) -> Result<site::Model, DbErr> {
+        if let Some(profile_id) = profile_id {
+            self.get_rule_profile_by_id(profile_id).await?;
+        }
+        let site = site::ActiveModel {
+            id: ActiveValue::Set(site_id),
+            default_profile_id: ActiveValue::Set(profile_id),
+            ..Default::default()
+        };
+        site.update(&self.db).await
+    }
     /* #endregion */
 
     /* #region SiteRun */
@@ -167,11 +508,16 @@ impl SeoStorage {
         Ok(site_runs)
     }
 
-    pub async fn create_site_run(&self, url: &str) -> Result<i32, DbErr> {
+    pub async fn create_site_run(
+        &self,
+        url: &str,
+        rule_profile_id: Option<i32>,
+    ) -> Result<i32, DbErr> {
         let site = self.upsert_site(url).await?;
         let site_run = site_run::ActiveModel {
             site_id: ActiveValue::Set(site),
             status: ActiveValue::Set(SiteRunStatus::Pending),
+            rule_profile_id: ActiveValue::Set(rule_profile_id),
             ..Default::default()
         };
 
@@ -265,6 +611,194 @@ impl SeoStorage {
             .await?;
         Ok(site_page.unwrap())
     }
+
+    // Stores what the crawl learned about the page itself, apart from its rule results
+    pub async fn update_site_page_metadata(
+        &self,
+        site_page: site_page::Model,
+        page_link: &PageLink,
+    ) -> Result<site_page::Model, DbErr> {
+        let to_i32 = |value: u32| i32::try_from(value).unwrap_or(i32::MAX);
+        let mut site_page: site_page::ActiveModel = site_page.into();
+        site_page.depth = ActiveValue::Set(page_link.depth.map(to_i32));
+        site_page.discovery_source = ActiveValue::Set(Some(DbLinkSourceType::from(
+            page_link.discovered_by.clone(),
+        )));
+        if let Some(metadata) = &page_link.metadata {
+            site_page.status_code = ActiveValue::Set(metadata.status_code.map(i32::from));
+            site_page.response_time_ms = ActiveValue::Set(metadata.response_time_ms.map(to_i32));
+            site_page.content_length = ActiveValue::Set(metadata.content_length.map(to_i32));
+            site_page.redirected = ActiveValue::Set(Some(metadata.redirected));
+            site_page.title = ActiveValue::Set(metadata.title.clone());
+            site_page.description = ActiveValue::Set(metadata.description.clone());
+            site_page.canonical = ActiveValue::Set(metadata.canonical.clone());
+            site_page.share_preview = ActiveValue::Set(Some(
+                serde_json::to_string(&metadata.share_preview)
+                    .map_err(|e| DbErr::Json(e.to_string()))?,
+            ));
+            if let Some(content_metrics) = &metadata.content_metrics {
+                let readability = content_metrics.readability.as_ref();
+                site_page.word_count = ActiveValue::Set(Some(to_i32(content_metrics.word_count)));
+                site_page.paragraph_count =
+                    ActiveValue::Set(Some(to_i32(content_metrics.paragraph_count)));
+                site_page.text_html_ratio = ActiveValue::Set(Some(content_metrics.text_html_ratio));
+                site_page.reading_ease =
+                    ActiveValue::Set(readability.map(|readability| readability.reading_ease));
+                site_page.grade_level =
+                    ActiveValue::Set(readability.map(|readability| readability.grade_level));
+            }
+        }
+        if let Some(link_metrics) = &page_link.link_metrics {
+            site_page.inlinks = ActiveValue::Set(Some(to_i32(link_metrics.inlinks)));
+            site_page.outlinks = ActiveValue::Set(Some(to_i32(link_metrics.outlinks)));
+            site_page.link_score = ActiveValue::Set(Some(link_metrics.link_score));
+        }
+        site_page.update(&self.db).await
+    }
+
+    // The page inventory of a run, ordered by crawl depth then URL
+    pub async fn get_site_run_pages(
+        &self,
+        site_run_id: i32,
+    ) -> Result<Vec<site_page::Model>, DbErr> {
+        SitePage::find()
+            .filter(site_page::Column::SiteRunId.eq(site_run_id))
+            .order_by_asc(site_page::Column::Depth)
+            .order_by_asc(site_page::Column::Url)
+            .all(&self.db)
+            .await
+    }
+
+    // How a shared link to the page at `url` looks, `None` for pages without metadata
+    pub async fn get_page_share_preview(
+        &self,
+        site_run_id: i32,
+        url: &str,
+    ) -> Result<Option<SharePreview>, DbErr> {
+        let page = SitePage::find()
+            .filter(site_page::Column::SiteRunId.eq(site_run_id))
+            .filter(site_page::Column::Url.eq(url))
+            .one(&self.db)
+            .await?;
+        match page {
+            Some(page) => page.share_preview(),
+            None => Ok(None),
+        }
+    }
+    /* #endregion */
+
+    /* #region SitePageLink */
+
+    // Replaces the run's link graph with the links found on each crawled page. Links to
+    // URLs that aren't stored as pages of the run are dropped.
+    pub async fn replace_site_page_links(
+        &self,
+        site_run_id: i32,
+        outlinks: Vec<(String, Vec<Link>)>,
+    ) -> Result<(), DbErr> {
+        SitePageLink::delete_many()
+            .filter(site_page_link::Column::SiteRunId.eq(site_run_id))
+            .exec(&self.db)
+            .await?;
+
+        let page_ids: HashMap<String, i32> = self
+            .get_site_run_pages(site_run_id)
+            .await?
+            .into_iter()
+            .map(|page| (page.url, page.id))
+            .collect();
+        let page_ids = &page_ids;
+        let page_links: Vec<site_page_link::ActiveModel> = outlinks
+            .into_iter()
+            .filter_map(|(url, links)| page_ids.get(&url).map(|id| (*id, links)))
+            .flat_map(|(source_page_id, links)| {
+                links.into_iter().filter_map(move |link| {
+                    let target_page_id = *page_ids.get(&link.href)?;
+                    Some(site_page_link::ActiveModel {
+                        site_run_id: ActiveValue::Set(site_run_id),
+                        source_page_id: ActiveValue::Set(source_page_id),
+                        target_page_id: ActiveValue::Set(target_page_id),
+                        anchor_text: ActiveValue::Set(link.anchor_text),
+                        rel: ActiveValue::Set((!link.rel.is_empty()).then(|| link.rel.join(" "))),
+                        position: ActiveValue::Set(DbLinkPosition::from(link.position)),
+                        ..Default::default()
+                    })
+                })
+            })
+            .collect();
+
+        // Stay well under SQLite's limit on bound variables per statement
+        for chunk in page_links.chunks(500) {
+            SitePageLink::insert_many(chunk.to_vec())
+                .exec(&self.db)
+                .await?;
+        }
+        Ok(())
+    }
+
+    // Pages of the run linking to `url`
+    pub async fn get_page_inlinks(
+        &self,
+        site_run_id: i32,
+        url: &str,
+    ) -> Result<Vec<LinkedPage>, DbErr> {
+        self.get_linked_pages(
+            site_run_id,
+            url,
+            site_page_link::Column::TargetPageId,
+            site_page_link::Relation::SourcePage,
+        )
+        .await
+    }
+
+    // Pages of the run `url` links to
+    pub async fn get_page_outlinks(
+        &self,
+        site_run_id: i32,
+        url: &str,
+    ) -> Result<Vec<LinkedPage>, DbErr> {
+        self.get_linked_pages(
+            site_run_id,
+            url,
+            site_page_link::Column::SourcePageId,
+            site_page_link::Relation::TargetPage,
+        )
+        .await
+    }
+
+    // Links where the page at `url` is on the `page_column` end, joined to the page on
+    // the other end
+    async fn get_linked_pages(
+        &self,
+        site_run_id: i32,
+        url: &str,
+        page_column: site_page_link::Column,
+        other_page: site_page_link::Relation,
+    ) -> Result<Vec<LinkedPage>, DbErr> {
+        let Some(page) = SitePage::find()
+            .filter(site_page::Column::SiteRunId.eq(site_run_id))
+            .filter(site_page::Column::Url.eq(url))
+            .one(&self.db)
+            .await?
+        else {
+            return Ok(vec![]);
+        };
+
+        SitePageLink::find()
+            .filter(page_column.eq(page.id))
+            .join(JoinType::InnerJoin, other_page.def())
+            .select_only()
+            .column_as(site_page::Column::Url, "url")
+            .column(site_page_link::Column::AnchorText)
+            .column(site_page_link::Column::Rel)
+            .column(site_page_link::Column::Position)
+            .order_by_asc(site_page::Column::Url)
+            .order_by_asc(site_page_link::Column::Id)
+            .into_model::<LinkedPage>()
+            .all(&self.db)
+            .await
+    }
+
     /* #endregion */
 
     /* #region PageRuleResult */
@@ -274,101 +808,139 @@ impl SeoStorage {
         site_run_id: i32,
         page_results: PageLink,
     ) -> Result<(), DbErr> {
-        let url = page_results.url;
-        let link_type = page_results.link_type;
-        let site_page = self.upsert_site_page(site_run_id, &url, link_type).await?;
+        let site_page = self
+            .upsert_site_page(
+                site_run_id,
+                &page_results.url,
+                page_results.link_type.clone(),
+            )
+            .await?;
+        let site_page = self
+            .update_site_page_metadata(site_page, &page_results)
+            .await?;
 
         let mut rule_results = vec![];
 
         let page_results = page_results.result;
 
         if let Some(page_results) = page_results {
+            let mut findings = HashMap::new();
             for rule_result in page_results.results {
                 rule_results.push(self.format_rule_result(
                     site_page.id,
                     site_run_id,
                     site_page.site_id,
-                    &rule_result.rule_id,
-                    rule_result.passed,
-                ));
+                    &rule_result,
+                    None,
+                )?);
+                findings.insert(rule_result.rule_id, rule_result.findings);
             }
 
-            let on_conflict = OnConflict::columns([
-                page_rule_result::Column::SitePageId,
-                page_rule_result::Column::RuleId,
-            ])
-            .update_column(page_rule_result::Column::Passed)
-            .to_owned();
-
             if !rule_results.is_empty() {
                 let res = PageRuleResult::insert_many(rule_results)
-                    .on_conflict(on_conflict)
+                    .on_conflict(Self::rule_result_on_conflict())
                     .exec(&self.db)
                     .await?;
+                self.replace_rule_findings(site_run_id, site_page.id, findings)
+                    .await?;
             }
         }
         Ok(())
     }
 
+    // Site rules only report the pages they flag. Every other internal page of the run
+    // gets a passed result, so site rules are scored by their share of flagged pages
+    // like page rules are. A site rule that errored or doesn't apply flags no page and
+    // gives every page its outcome, so it's counted like a broken page rule. Store the
+    // page results first, so the run's pages exist.
     pub async fn insert_many_site_rule_results(
         &self,
         site_run_id: i32,
         site_rule_results: Vec<RuleResult>,
     ) -> Result<(), DbErr> {
         for rule_result in site_rule_results {
-            let rule_result_clone = rule_result.clone();
-            let on_conflict = OnConflict::columns([
-                page_rule_result::Column::SitePageId,
-                page_rule_result::Column::RuleId,
-            ])
-            .update_column(page_rule_result::Column::Passed)
-            .to_owned();
-
-            match rule_result.context {
+            // Pages the result is about, with the value each page was grouped by
+            let pages: Vec<(String, Option<ResultContext>)> = match &rule_result.context {
                 SiteCheckContext::Urls(urls) => {
-                    for url in urls {
-                        let site_page = self
-                            .upsert_site_page(site_run_id, &url, LinkType::Internal)
-                            .await?;
-                        let site_page_id = site_page.id;
-                        let site_rule_result = self.format_rule_result(
-                            site_page_id,
-                            site_run_id,
-                            site_page.site_id,
-                            &rule_result.rule_id,
-                            rule_result.passed,
-                        );
-                        let _ = PageRuleResult::insert(site_rule_result)
-                            .on_conflict(on_conflict.clone())
-                            .exec(&self.db)
-                            .await
-                            .unwrap();
-                    }
-                }
-                SiteCheckContext::Values(values) => {
-                    for (_key, urls) in values {
-                        for url in urls {
-                            let site_page = self
-                                .upsert_site_page(site_run_id, &url, LinkType::Internal)
-                                .await?;
-                            let site_page_id = site_page.id;
-                            let site_rule_result = self.format_rule_result(
-                                site_page_id,
-                                site_run_id,
-                                site_page.site_id,
-                                &rule_result_clone.rule_id,
-                                rule_result_clone.passed,
-                            );
-
-                            let _ = PageRuleResult::insert(site_rule_result)
-                                .on_conflict(on_conflict.clone())
-                                .exec(&self.db)
-                                .await?;
-                        }
-                    }
+                    urls.iter().map(|url| (url.clone(), None)).collect()
                 }
-                _ => {}
+                // One row per page, holding every group the page is in
+                SiteCheckContext::Values(values) => ResultContext::by_url(values)
+                    .into_iter()
+                    .map(|(url, context)| (url, Some(context)))
+                    .collect(),
+                SiteCheckContext::Empty => vec![],
+            };
+
+            let mut flagged_page_ids = vec![];
+            for (url, context) in pages {
+                let site_page = self
+                    .upsert_site_page(site_run_id, &url, LinkType::Internal)
+                    .await?;
+                flagged_page_ids.push(site_page.id);
+                let site_rule_result = self.format_rule_result(
+                    site_page.id,
+                    site_run_id,
+                    site_page.site_id,
+                    &rule_result,
+                    context,
+                )?;
+                PageRuleResult::insert(site_rule_result)
+                    .on_conflict(Self::rule_result_on_conflict())
+                    .exec(&self.db)
+                    .await?;
             }
+
+            self.insert_unflagged_site_rule_results(site_run_id, &rule_result, flagged_page_ids)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn insert_unflagged_site_rule_results(
+        &self,
+        site_run_id: i32,
+        rule_result: &RuleResult,
+        flagged_page_ids: Vec<i32>,
+    ) -> Result<(), DbErr> {
+        let (status, message) = match rule_result.status {
+            RuleStatus::Passed => (DbRuleStatus::Passed, Some(rule_result.message.clone())),
+            RuleStatus::Failed => (
+                DbRuleStatus::Passed,
+                PluginRule::find_by_id(&rule_result.rule_id)
+                    .one(&self.db)
+                    .await?
+                    .map(|rule| rule.passed_message),
+            ),
+            status => (status.into(), Some(rule_result.message.clone())),
+        };
+        let unflagged_pages = SitePage::find()
+            .filter(site_page::Column::SiteRunId.eq(site_run_id))
+            .filter(site_page::Column::DbLinkType.eq(DbLinkType::Internal))
+            .filter(site_page::Column::Id.is_not_in(flagged_page_ids))
+            .all(&self.db)
+            .await?;
+
+        let results: Vec<page_rule_result::ActiveModel> = unflagged_pages
+            .into_iter()
+            .map(|site_page| page_rule_result::ActiveModel {
+                site_page_id: ActiveValue::Set(site_page.id),
+                site_run_id: ActiveValue::Set(site_run_id),
+                site_id: ActiveValue::Set(site_page.site_id),
+                rule_id: ActiveValue::Set(rule_result.rule_id.clone()),
+                passed: ActiveValue::Set(status == DbRuleStatus::Passed),
+                status: ActiveValue::Set(status.clone()),
+                message: ActiveValue::Set(message.clone()),
+                severity: ActiveValue::Set(Some(rule_result.severity.clone().into())),
+                context: ActiveValue::Set(None),
+                ..Default::default()
+            })
+            .collect();
+        if !results.is_empty() {
+            PageRuleResult::insert_many(results)
+                .on_conflict(Self::rule_result_on_conflict())
+                .exec(&self.db)
+                .await?;
         }
         Ok(())
     }
@@ -380,10 +952,83 @@ impl SeoStorage {
     ) -> Result<(), DbErr> {
         let site_rule_results = crawl_result.site_result;
         let page_results = crawl_result.page_results;
+        let mut outlinks = vec![];
+        for mut page_result in page_results {
+            outlinks.push((
+                page_result.url.clone(),
+                std::mem::take(&mut page_result.outlinks),
+            ));
+            self.insert_many_page_rule_results(site_run_id, page_result)
+                .await?;
+        }
         self.insert_many_site_rule_results(site_run_id, site_rule_results)
             .await?;
-        for page_result in page_results {
-            self.insert_many_page_rule_results(site_run_id, page_result)
+        self.replace_site_page_links(site_run_id, outlinks).await?;
+        self.update_site_run_score(site_run_id).await?;
+        Ok(())
+    }
+
+    // Computes the site run's health score from its stored results and saves it
+    pub async fn update_site_run_score(&self, site_run_id: i32) -> Result<HealthScore, DbErr> {
+        let rule_results = self.get_rule_results(&site_run_id).await?;
+        let score_weights = self.get_score_weights().await?;
+        let health_score = utils::health_score::get_health_score(&rule_results, &score_weights);
+        let category_scores = serde_json::to_string(&health_score.categories)
+            .map_err(|e| DbErr::Json(e.to_string()))?;
+
+        let site_run = site_run::ActiveModel {
+            id: ActiveValue::Set(site_run_id),
+            score: ActiveValue::Set(health_score.score),
+            category_scores: ActiveValue::Set(Some(category_scores)),
+            ..Default::default()
+        };
+        site_run.update(&self.db).await?;
+        Ok(health_score)
+    }
+
+    async fn replace_rule_findings(
+        &self,
+        site_run_id: i32,
+        site_page_id: i32,
+        findings: HashMap<String, Vec<Finding>>,
+    ) -> Result<(), DbErr> {
+        let page_rule_results = PageRuleResult::find()
+            .filter(page_rule_result::Column::SitePageId.eq(site_page_id))
+            .filter(page_rule_result::Column::RuleId.is_in(findings.keys().cloned()))
+            .all(&self.db)
+            .await?;
+
+        // Results are upserted, so drop findings left over from a previous check
+        RuleFinding::delete_many()
+            .filter(
+                rule_finding::Column::PageRuleResultId
+                    .is_in(page_rule_results.iter().map(|result| result.id)),
+            )
+            .exec(&self.db)
+            .await?;
+
+        let mut rule_findings = vec![];
+        for page_rule_result in page_rule_results {
+            let Some(rule_result_findings) = findings.get(&page_rule_result.rule_id) else {
+                continue;
+            };
+            for finding in rule_result_findings {
+                rule_findings.push(rule_finding::ActiveModel {
+                    page_rule_result_id: ActiveValue::Set(page_rule_result.id),
+                    site_run_id: ActiveValue::Set(site_run_id),
+                    selector: ActiveValue::Set(finding.selector.clone()),
+                    html: ActiveValue::Set(finding.html.clone()),
+                    source_line: ActiveValue::Set(finding.line.map(|line| line as i32)),
+                    source_column: ActiveValue::Set(finding.column.map(|column| column as i32)),
+                    value: ActiveValue::Set(finding.value.clone()),
+                    ..Default::default()
+                });
+            }
+        }
+
+        if !rule_findings.is_empty() {
+            RuleFinding::insert_many(rule_findings)
+                .exec(&self.db)
                 .await?;
         }
         Ok(())
@@ -394,31 +1039,62 @@ impl SeoStorage {
         site_page_id: i32,
         site_run_id: i32,
         site_id: i32,
-        rule_id: &str,
-        passed: bool,
-    ) -> page_rule_result::ActiveModel {
-        page_rule_result::ActiveModel {
+        rule_result: &RuleResult,
+        context: Option<ResultContext>,
+    ) -> Result<page_rule_result::ActiveModel, DbErr> {
+        let context = context
+            .map(|context| serde_json::to_string(&context))
+            .transpose()
+            .map_err(|e| DbErr::Json(e.to_string()))?;
+        Ok(page_rule_result::ActiveModel {
             site_page_id: ActiveValue::Set(site_page_id),
             site_run_id: ActiveValue::Set(site_run_id),
             site_id: ActiveValue::Set(site_id),
-            rule_id: ActiveValue::Set(rule_id.to_string()),
-            passed: ActiveValue::Set(passed),
+            rule_id: ActiveValue::Set(rule_result.rule_id.clone()),
+            passed: ActiveValue::Set(rule_result.passed),
+            status: ActiveValue::Set(rule_result.status.into()),
+            message: ActiveValue::Set(Some(rule_result.message.clone())),
+            severity: ActiveValue::Set(Some(rule_result.severity.clone().into())),
+            context: ActiveValue::Set(context),
             ..Default::default()
-        }
+        })
+    }
+
+    // A page is checked once per rule and run, rechecking it replaces the result
+    fn rule_result_on_conflict() -> OnConflict {
+        OnConflict::columns([
+            page_rule_result::Column::SitePageId,
+            page_rule_result::Column::RuleId,
+        ])
+        .update_columns([
+            page_rule_result::Column::Passed,
+            page_rule_result::Column::Status,
+            page_rule_result::Column::Message,
+            page_rule_result::Column::Severity,
+            page_rule_result::Column::Context,
+        ])
+        .to_owned()
     }
 
     /* #endregion */
 
     /* #region Results Display */
-    pub async fn get_category_result(
+    async fn get_rule_results(
         &self,
         site_run_id: &i32,
-    ) -> Result<CategoryResultDisplay, DbErr> {
-        let res: Vec<(plugin_rule::Model, Vec<page_rule_result::Model>)> = PluginRule::find()
+    ) -> Result<Vec<(plugin_rule::Model, Vec<page_rule_result::Model>)>, DbErr> {
+        PluginRule::find()
             .find_with_related(page_rule_result::Entity)
             .filter(page_rule_result::Column::SiteRunId.eq(site_run_id.to_owned()))
             .all(&self.db)
-            .await?;
+            .await
+    }
+
+    pub async fn get_category_result(
+        &self,
+        site_run_id: &i32,
+    ) -> Result<CategoryResultDisplay, DbErr> {
+        let res = self.get_rule_results(site_run_id).await?;
 
         let category_counts = utils::category_counts::get_category_counts(res);
 
@@ -439,14 +1115,28 @@ impl SeoStorage {
             .all(&self.db)
             .await?;
         let site_runs: Vec<site_run::Model> = site_runs.into_iter().rev().collect();
+        let score_weights = self.get_score_weights().await?;
         let mut category_result_displays = vec![];
         // TODO: Update query to get all site run categories
         // The get_site_category_history function processes each site run sequentially, making a separate database query for each run. This could be inefficient for sites with many runs.
         for site_run in site_runs {
             let category_result_display = self.get_category_result(&site_run.id).await?;
+            // Runs stored before scoring existed are scored on the fly
+            let health_score = match site_run.category_scores {
+                Some(_) => HealthScore {
+                    score: site_run.score,
+                    categories: site_run.category_scores()?,
+                },
+                None => utils::health_score::get_health_score(
+                    &self.get_rule_results(&site_run.id).await?,
+                    &score_weights,
+                ),
+            };
             category_result_displays.push(CategoryResultHistory {
                 created_at: site_run.created_at,
                 data: category_result_display.data,
+                score: health_score.score,
+                scores: health_score.categories,
             });
         }
         Ok(category_result_displays)
@@ -462,8 +1152,14 @@ impl SeoStorage {
             .find_also_related(site_page::Entity)
             .find_also_related(plugin_rule::Entity);
 
+        // Not applicable and errored results are neither passes nor failures
         if let Some(passed) = passed {
-            res = res.filter(page_rule_result::Column::Passed.eq(passed));
+            let status = if passed {
+                DbRuleStatus::Passed
+            } else {
+                DbRuleStatus::Failed
+            };
+            res = res.filter(page_rule_result::Column::Status.eq(status));
         }
 
         let res = res.all(&self.db).await?;
@@ -472,6 +1168,17 @@ impl SeoStorage {
 
         Ok(category_detail)
     }
+
+    pub async fn get_rule_findings(
+        &self,
+        page_rule_result_id: i32,
+    ) -> Result<Vec<rule_finding::Model>, DbErr> {
+        RuleFinding::find()
+            .filter(rule_finding::Column::PageRuleResultId.eq(page_rule_result_id))
+            .order_by_asc(rule_finding::Column::Id)
+            .all(&self.db)
+            .await
+    }
     /* #endregion */
 }
 
@@ -481,6 +1188,15 @@ pub struct SitePageLinkCount {
     pub count: i32,
 }
 
+// A link between two pages, seen from one end, with the URL of the other end
+#[derive(Debug, Clone, FromQueryResult, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct LinkedPage {
+    pub url: String,
+    pub anchor_text: String,
+    pub rel: Option<String>,
+    pub position: DbLinkPosition,
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -488,11 +1204,22 @@ mod tests {
 
     use migration::SchemaManager;
     use seo_plugins::{
-        site_analyzer::PageResult,
-        utils::config::{RuleCategory, RuleResult, Severity, SiteCheckContext},
+        plugins::custom_rule::CustomRulePlugin,
+        site_analyzer::{LinkSourceType, PageResult},
+        utils::{
+            config::{
+                Effort, Finding, RuleCategory, RuleResult, RuleStatus, Severity, SiteCheckContext,
+            },
+            content::{ContentMetrics, Readability},
+            link_graph::PageLinkMetrics,
+            link_parser::{LinkPosition, parse_link},
+            page::PageMetadata,
+            page_plugin::SeoPlugin,
+        },
     };
 
     use crate::enums::plugin_rule_enums::DbRuleCategory;
+    use crate::utils::result_context::ContextValue;
 
     use super::*;
 
@@ -504,6 +1231,175 @@ mod tests {
 
         let _ = seo_storage.migrate_up().await;
         assert!(schema_manager.has_table("site").await.unwrap());
+        assert!(schema_manager.has_table("rule_finding").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn it_should_build_rule_config_from_plugin_rules() {
+        let seo_storage = SeoStorage::new_migrated_with_default().await;
+
+        let config = seo_storage.get_rule_config().await.unwrap();
+        assert!(config.is_rule_enabled("title.has_title"));
+
+        let rule = seo_storage
+            .set_rule_enabled("title.has_title", false)
+            .await
+            .unwrap();
+        assert!(!rule.enabled);
+        let rule = seo_storage
+            .set_rule_severity("title.title_length", Some(DbSeverity::Critical))
+            .await
+            .unwrap();
+        assert_eq!(rule.severity_override, Some(DbSeverity::Critical));
+
+        let config = seo_storage.get_rule_config().await.unwrap();
+        assert!(!config.is_rule_enabled("title.has_title"));
+        assert!(config.is_rule_enabled("title.title_length"));
+        assert!(matches!(
+            config.get_severity("title.title_length", Severity::Warning),
+            Severity::Critical
+        ));
+
+        let registry = PluginRegistry::default();
+        seo_storage
+            .set_rule_param(
+                &registry,
+                "title.title_length",
+                "max_length",
+                Some(RuleParamValue::Number(70.0)),
+            )
+            .await
+            .unwrap();
+        assert!(
+            seo_storage
+                .set_rule_param(
+                    &registry,
+                    "title.title_length",
+                    "max_length",
+                    Some(RuleParamValue::Text("long".to_string())),
+                )
+                .await
+                .is_err()
+        );
+        // Params are checked against the registry the caller runs
+        assert!(
+            seo_storage
+                .set_rule_param(
+                    &PluginRegistry::new(),
+                    "title.title_length",
+                    "max_length",
+                    Some(RuleParamValue::Number(70.0)),
+                )
+                .await
+                .is_err()
+        );
+        let config = seo_storage.get_rule_config().await.unwrap();
+        assert_eq!(
+            config.get_param("title.title_length", "max_length"),
+            Some(&RuleParamValue::Number(70.0))
+        );
+
+        // Re-seeding keeps the user's settings
+        seo_storage.seed_plugin_rule_table().await.unwrap();
+        let config = seo_storage.get_rule_config().await.unwrap();
+        assert!(!config.is_rule_enabled("title.has_title"));
+
+        seo_storage
+            .set_rule_severity("title.title_length", None)
+            .await
+            .unwrap();
+        let config = seo_storage.get_rule_config().await.unwrap();
+        assert!(matches!(
+            config.get_severity("title.title_length", Severity::Warning),
+            Severity::Warning
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_should_seed_custom_rules() {
+        let seo_storage = SeoStorage::new_migrated_with_default().await;
+        let definitions = CustomRulePlugin::parse_toml(
+            r#"
+            [[rules]]
+            id = "custom.product_price"
+            name = "Product pages show a price"
+            selector = "[itemprop=price]"
+            passed_message = "Price is shown"
+            failed_message = "Price is missing"
+            assert = { type = "exists" }
+            "#,
+        )
+        .unwrap();
+        let plugin = CustomRulePlugin::new(definitions).unwrap();
+
+        seo_storage
+            .seed_plugin_rules(plugin.rule_displays())
+            .await
+            .unwrap();
+        let config = seo_storage.get_rule_config().await.unwrap();
+        assert!(config.is_rule_enabled("custom.product_price"));
+        assert!(config.is_rule_enabled("title.has_title"));
+    }
+
+    #[tokio::test]
+    async fn it_should_run_with_the_site_default_profile() {
+        let seo_storage = SeoStorage::new_migrated_with_default().await;
+        let url = "https://example.com/";
+
+        let profiles = seo_storage.get_rule_profiles().await.unwrap();
+        let full_audit = profiles.iter().find(|p| p.name == "Full audit").unwrap();
+        // Rules the stored preset doesn't know about, e.g. custom rules, still run
+        let config = full_audit.to_profile().unwrap().to_config();
+        assert!(config.is_rule_enabled("custom.product_price"));
+        assert!(config.is_rule_enabled("title.has_title"));
+
+        let imported = seo_storage
+            .import_rule_profile(
+                r#"
+                name = "Client X"
+
+                [rules."title.has_title"]
+                enabled = true
+                severity = "Critical"
+                "#,
+                ProfileFormat::Toml,
+            )
+            .await
+            .unwrap();
+
+        // Without a default the persisted rule settings apply
+        let (profile_id, config) = seo_storage.get_rule_config_for_url(url).await.unwrap();
+        assert_eq!(profile_id, None);
+        assert!(config.is_rule_enabled("title.title_length"));
+
+        let site_id = seo_storage.upsert_site(url).await.unwrap();
+        let site = seo_storage
+            .set_site_default_profile(site_id, Some(imported.id))
+            .await
+            .unwrap();
+        assert_eq!(site.default_profile_id, Some(imported.id));
+
+        let (profile_id, config) = seo_storage.get_rule_config_for_url(url).await.unwrap();
+        assert_eq!(profile_id, Some(imported.id));
+        assert!(config.is_rule_enabled("title.has_title"));
+        assert!(!config.is_rule_enabled("title.title_length"));
+
+        let site_run_id = seo_storage.create_site_run(url, profile_id).await.unwrap();
+        let site_run = seo_storage.get_site_run_by_id(site_run_id).await.unwrap();
+        assert_eq!(site_run.rule_profile_id, Some(imported.id));
+
+        let exported = seo_storage
+            .export_rule_profile(imported.id, ProfileFormat::Json)
+            .await
+            .unwrap();
+        let profile = RuleProfile::import(&exported, ProfileFormat::Json).unwrap();
+        assert_eq!(profile, imported.to_profile().unwrap());
+
+        seo_storage.delete_rule_profile(imported.id).await.unwrap();
+        let site = seo_storage.get_site_by_id(site_id).await.unwrap();
+        assert_eq!(site.default_profile_id, None);
+        let site_run = seo_storage.get_site_run_by_id(site_run_id).await.unwrap();
+        assert_eq!(site_run.rule_profile_id, None);
     }
 
     #[tokio::test]
@@ -535,7 +1431,7 @@ mod tests {
             .unwrap();
         let test_url = "https://forest-fitness-website-1dfad0.gitlab.io/";
         let cur_site = seo_storage.upsert_site(test_url).await.unwrap();
-        let site_run_id = seo_storage.create_site_run(test_url).await.unwrap();
+        let site_run_id = seo_storage.create_site_run(test_url, None).await.unwrap();
         assert_eq!(site_run_id, 1);
 
         let site_runs = SiteRun::find().all(&seo_storage.get_db()).await.unwrap();
@@ -552,7 +1448,7 @@ mod tests {
         let _ = seo_storage.migrate_up().await;
 
         let site_run_id = seo_storage
-            .create_site_run("https://forest-fitness-website-1dfad0.gitlab.io/")
+            .create_site_run("https://forest-fitness-website-1dfad0.gitlab.io/", None)
             .await
             .unwrap();
         let site_run = seo_storage
@@ -568,12 +1464,233 @@ mod tests {
         assert_eq!(found_site_run.unwrap().status, SiteRunStatus::Running);
     }
 
+    #[tokio::test]
+    async fn it_should_store_page_metadata() {
+        let seo_storage = SeoStorage::new_migrated_with_default().await;
+        let site_run_id = seo_storage
+            .create_site_run("https://example.com/", None)
+            .await
+            .unwrap();
+
+        let page = |url: &str, depth: Option<u32>, metadata: Option<PageMetadata>| PageLink {
+            url: url.to_string(),
+            link_type: LinkType::Internal,
+            found_in: HashSet::new(),
+            depth,
+            discovered_by: if depth.is_some() {
+                LinkSourceType::Link
+            } else {
+                LinkSourceType::Sitemap
+            },
+            metadata,
+            outlinks: vec![],
+            link_metrics: None,
+            result: None,
+        };
+        let metadata = PageMetadata {
+            status_code: Some(200),
+            response_time_ms: Some(120),
+            content_length: Some(2048),
+            redirected: false,
+            title: Some("About".to_string()),
+            description: Some("About us".to_string()),
+            canonical: Some("https://example.com/about".to_string()),
+            share_preview: SharePreview {
+                url: "https://example.com/about".to_string(),
+                title: Some("About".to_string()),
+                ..Default::default()
+            },
+            content_metrics: Some(ContentMetrics {
+                word_count: 320,
+                sentence_count: 20,
+                paragraph_count: 6,
+                text_html_ratio: 18.6,
+                readability: Some(Readability {
+                    language: "en".to_string(),
+                    formula: "Flesch".to_string(),
+                    reading_ease: 61.4,
+                    grade_level: 8.2,
+                }),
+            }),
+        };
+        for page in [
+            page("https://example.com/orphan", None, None),
+            PageLink {
+                link_metrics: Some(PageLinkMetrics {
+                    inlinks: 3,
+                    outlinks: 2,
+                    link_score: 40.5,
+                }),
+                ..page("https://example.com/about", Some(1), Some(metadata))
+            },
+            page(
+                "https://example.com/",
+                Some(0),
+                Some(PageMetadata::default()),
+            ),
+        ] {
+            seo_storage
+                .insert_many_page_rule_results(site_run_id, page)
+                .await
+                .unwrap();
+        }
+
+        let pages = seo_storage.get_site_run_pages(site_run_id).await.unwrap();
+        let urls: Vec<&str> = pages.iter().map(|page| page.url.as_str()).collect();
+        // SQLite sorts null depths first
+        assert_eq!(
+            urls,
+            [
+                "https://example.com/orphan",
+                "https://example.com/",
+                "https://example.com/about"
+            ]
+        );
+        assert_eq!(pages[0].status_code, None);
+        assert_eq!(pages[0].discovery_source, Some(DbLinkSourceType::Sitemap));
+        let about = &pages[2];
+        assert_eq!(about.depth, Some(1));
+        assert_eq!(about.discovery_source, Some(DbLinkSourceType::Link));
+        assert_eq!(about.status_code, Some(200));
+        assert_eq!(about.response_time_ms, Some(120));
+        assert_eq!(about.content_length, Some(2048));
+        assert_eq!(about.redirected, Some(false));
+        assert_eq!(about.title.as_deref(), Some("About"));
+        assert_eq!(
+            about.canonical.as_deref(),
+            Some("https://example.com/about")
+        );
+        assert_eq!(
+            (about.inlinks, about.outlinks, about.link_score),
+            (Some(3), Some(2), Some(40.5))
+        );
+        assert_eq!(
+            (
+                about.word_count,
+                about.paragraph_count,
+                about.text_html_ratio
+            ),
+            (Some(320), Some(6), Some(18.6))
+        );
+        assert_eq!(
+            (about.reading_ease, about.grade_level),
+            (Some(61.4), Some(8.2))
+        );
+        assert_eq!(pages[1].word_count, None);
+        let share_preview = seo_storage
+            .get_page_share_preview(site_run_id, "https://example.com/about")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(share_preview.title.as_deref(), Some("About"));
+        assert_eq!(
+            seo_storage
+                .get_page_share_preview(site_run_id, "https://example.com/orphan")
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_store_the_link_graph() {
+        let seo_storage = SeoStorage::new_migrated_with_default().await;
+        let site_run_id = seo_storage
+            .create_site_run("https://example.com/", None)
+            .await
+            .unwrap();
+        let home = "https://example.com/";
+        let about = "https://example.com/about";
+        let blog = "https://example.com/blog";
+        for url in [home, about, blog] {
+            seo_storage
+                .upsert_site_page(site_run_id, url, LinkType::Internal)
+                .await
+                .unwrap();
+        }
+
+        let link = |href: &str, anchor_text: &str, rel: &[&str], position: LinkPosition| Link {
+            anchor_text: anchor_text.to_string(),
+            rel: rel.iter().map(|rel| String::from(*rel)).collect(),
+            position,
+            ..parse_link(href, home).unwrap()
+        };
+        let outlinks = vec![
+            (
+                home.to_string(),
+                vec![
+                    link(about, "About", &[], LinkPosition::Nav),
+                    link(blog, "Blog", &["nofollow", "ugc"], LinkPosition::Body),
+                ],
+            ),
+            (
+                about.to_string(),
+                vec![
+                    link(home, "Home", &[], LinkPosition::Nav),
+                    // Not a page of the run
+                    link("https://other.com/", "Other", &[], LinkPosition::Footer),
+                ],
+            ),
+        ];
+        // Replacing twice keeps a single copy of the graph
+        for _ in 0..2 {
+            seo_storage
+                .replace_site_page_links(site_run_id, outlinks.clone())
+                .await
+                .unwrap();
+        }
+
+        let home_outlinks = seo_storage
+            .get_page_outlinks(site_run_id, home)
+            .await
+            .unwrap();
+        assert_eq!(
+            home_outlinks,
+            vec![
+                LinkedPage {
+                    url: about.to_string(),
+                    anchor_text: "About".to_string(),
+                    rel: None,
+                    position: DbLinkPosition::Nav,
+                },
+                LinkedPage {
+                    url: blog.to_string(),
+                    anchor_text: "Blog".to_string(),
+                    rel: Some("nofollow ugc".to_string()),
+                    position: DbLinkPosition::Body,
+                },
+            ]
+        );
+        let home_inlinks = seo_storage
+            .get_page_inlinks(site_run_id, home)
+            .await
+            .unwrap();
+        assert_eq!(home_inlinks.len(), 1);
+        assert_eq!(home_inlinks[0].url, about);
+        assert_eq!(home_inlinks[0].anchor_text, "Home");
+        assert_eq!(
+            seo_storage
+                .get_page_outlinks(site_run_id, about)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(
+            seo_storage
+                .get_page_inlinks(site_run_id, "https://example.com/missing")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     #[tokio::test]
     async fn it_should_upsert_site_pages() {
         let seo_storage = SeoStorage::new_migrated_with_default().await;
 
         let site_run_id = seo_storage
-            .create_site_run("https://forest-fitness-website-1dfad0.gitlab.io/")
+            .create_site_run("https://forest-fitness-website-1dfad0.gitlab.io/", None)
             .await
             .unwrap();
         let site_page = seo_storage
@@ -602,7 +1719,7 @@ mod tests {
     async fn it_should_insert_many_page_rule_results() {
         let seo_storage = SeoStorage::new_migrated_with_default().await;
         let site_run_id = seo_storage
-            .create_site_run("https://forest-fitness-website-1dfad0.gitlab.io/")
+            .create_site_run("https://forest-fitness-website-1dfad0.gitlab.io/", None)
             .await
             .unwrap();
 
@@ -610,6 +1727,11 @@ mod tests {
             url: "https://forest-fitness-website-1dfad0.gitlab.io/".to_string(),
             link_type: LinkType::Internal,
             found_in: HashSet::new(),
+            depth: None,
+            discovered_by: LinkSourceType::Link,
+            metadata: None,
+            outlinks: vec![],
+            link_metrics: None,
             result: Some(PageResult {
                 error: false,
                 results: vec![RuleResult {
@@ -617,10 +1739,12 @@ mod tests {
                     name: "test".to_string(),
                     plugin_name: "test".to_string(),
                     passed: true,
+                    status: RuleStatus::Passed,
                     message: "test".to_string(),
                     severity: Severity::Info,
                     category: RuleCategory::SEO,
                     context: SiteCheckContext::Empty,
+                    findings: vec![],
                 }],
             }),
         };
@@ -667,6 +1791,11 @@ mod tests {
             url: "https://forest-fitness-website-1dfad0.gitlab.io/".to_string(),
             link_type: LinkType::Internal,
             found_in: HashSet::new(),
+            depth: None,
+            discovered_by: LinkSourceType::Link,
+            metadata: None,
+            outlinks: vec![],
+            link_metrics: None,
             result: Some(PageResult {
                 error: false,
                 results: vec![RuleResult {
@@ -674,10 +1803,12 @@ mod tests {
                     name: "test".to_string(),
                     plugin_name: "test".to_string(),
                     passed: false,
+                    status: RuleStatus::Failed,
                     message: "test".to_string(),
                     severity: Severity::Info,
                     category: RuleCategory::SEO,
                     context: SiteCheckContext::Empty,
+                    findings: vec![],
                 }],
             }),
         };
@@ -703,4 +1834,429 @@ mod tests {
         assert_eq!(category_detail.data.len(), 1);
         assert_eq!(category_detail.data[&DbRuleCategory::SEO].len(), 2);
     }
+
+    #[tokio::test]
+    async fn it_should_replace_rule_findings() {
+        let seo_storage = SeoStorage::new_migrated_with_default().await;
+        let site_run_id = seo_storage
+            .create_site_run("https://forest-fitness-website-1dfad0.gitlab.io/", None)
+            .await
+            .unwrap();
+
+        let page_results = |findings: Vec<Finding>| PageLink {
+            url: "https://forest-fitness-website-1dfad0.gitlab.io/".to_string(),
+            link_type: LinkType::Internal,
+            found_in: HashSet::new(),
+            depth: None,
+            discovered_by: LinkSourceType::Link,
+            metadata: None,
+            outlinks: vec![],
+            link_metrics: None,
+            result: Some(PageResult {
+                error: false,
+                results: vec![RuleResult {
+                    rule_id: "axe.image_alt".to_string(),
+                    name: "test".to_string(),
+                    plugin_name: "test".to_string(),
+                    passed: false,
+                    status: RuleStatus::Failed,
+                    message: "test".to_string(),
+                    severity: Severity::Critical,
+                    category: RuleCategory::Accessibility,
+                    context: SiteCheckContext::Empty,
+                    findings,
+                }],
+            }),
+        };
+        let finding = |src: &str, line: u32| Finding {
+            selector: "html > body > img".to_string(),
+            html: format!(r#"<img src="{}">"#, src),
+            line: Some(line),
+            column: Some(5),
+            value: Some(src.to_string()),
+        };
+
+        seo_storage
+            .insert_many_page_rule_results(
+                site_run_id,
+                page_results(vec![finding("/a.jpg", 3), finding("/b.jpg", 4)]),
+            )
+            .await
+            .unwrap();
+        seo_storage
+            .insert_many_page_rule_results(site_run_id, page_results(vec![finding("/c.jpg", 7)]))
+            .await
+            .unwrap();
+
+        let category_detail = seo_storage
+            .get_category_result_detail(&site_run_id, Some(false))
+            .await
+            .unwrap();
+        let page_rule_result_id =
+            category_detail.data[&DbRuleCategory::Accessibility][0].page_rule_result_id;
+
+        let findings = seo_storage
+            .get_rule_findings(page_rule_result_id)
+            .await
+            .unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].value, Some("/c.jpg".to_string()));
+        assert_eq!(findings[0].source_line, Some(7));
+        assert_eq!(findings[0].source_column, Some(5));
+    }
+
+    #[tokio::test]
+    async fn it_should_exclude_unchecked_results_from_counts() {
+        let seo_storage = SeoStorage::new_migrated_with_default().await;
+        let site_run_id = seo_storage
+            .create_site_run("https://forest-fitness-website-1dfad0.gitlab.io/", None)
+            .await
+            .unwrap();
+
+        let rule_result = |rule_id: &str, status: RuleStatus| RuleResult {
+            rule_id: rule_id.to_string(),
+            name: "test".to_string(),
+            plugin_name: "test".to_string(),
+            passed: status == RuleStatus::Passed,
+            status,
+            message: "test".to_string(),
+            severity: Severity::Warning,
+            category: RuleCategory::SEO,
+            context: SiteCheckContext::Empty,
+            findings: vec![],
+        };
+        let test_page_results = PageLink {
+            url: "https://forest-fitness-website-1dfad0.gitlab.io/".to_string(),
+            link_type: LinkType::Internal,
+            found_in: HashSet::new(),
+            depth: None,
+            discovered_by: LinkSourceType::Link,
+            metadata: None,
+            outlinks: vec![],
+            link_metrics: None,
+            result: Some(PageResult {
+                error: false,
+                results: vec![
+                    rule_result("title.has_title", RuleStatus::Passed),
+                    rule_result("title.title_length", RuleStatus::Failed),
+                    rule_result("seo_basic.has_html_doctype", RuleStatus::NotApplicable),
+                    rule_result("seo_basic.has_canonical_url", RuleStatus::Errored),
+                ],
+            }),
+        };
+
+        seo_storage
+            .insert_many_page_rule_results(site_run_id, test_page_results)
+            .await
+            .unwrap();
+
+        let category_result = seo_storage.get_category_result(&site_run_id).await.unwrap();
+        assert_eq!(category_result.total, 4);
+        assert_eq!(category_result.passed, 1);
+        assert_eq!(category_result.failed, 1);
+        assert_eq!(category_result.not_applicable, 1);
+        assert_eq!(category_result.errored, 1);
+
+        let category_detail = seo_storage
+            .get_category_result_detail(&site_run_id, Some(false))
+            .await
+            .unwrap();
+        assert_eq!(category_detail.data[&DbRuleCategory::SEO].len(), 1);
+        assert_eq!(
+            category_detail.data[&DbRuleCategory::SEO][0].rule_id,
+            "title.title_length"
+        );
+        // Seeded from the rule definition
+        let remediation = category_detail.data[&DbRuleCategory::SEO][0]
+            .remediation
+            .clone()
+            .unwrap();
+        assert_eq!(remediation.effort, Effort::Low);
+        assert!(!remediation.references.is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_should_score_site_runs() {
+        let seo_storage = SeoStorage::new_migrated_with_default().await;
+        let url = "https://forest-fitness-website-1dfad0.gitlab.io/";
+        let site_run_id = seo_storage.create_site_run(url, None).await.unwrap();
+        seo_storage
+            .set_rule_weight("title.has_title", Some(3))
+            .await
+            .unwrap();
+        seo_storage
+            .set_rule_weight("title.title_length", Some(1))
+            .await
+            .unwrap();
+
+        let rule_result = |rule_id: &str, status: RuleStatus| RuleResult {
+            rule_id: rule_id.to_string(),
+            name: "test".to_string(),
+            plugin_name: "test".to_string(),
+            passed: status == RuleStatus::Passed,
+            status,
+            message: "test".to_string(),
+            severity: Severity::Warning,
+            category: RuleCategory::SEO,
+            context: SiteCheckContext::Empty,
+            findings: vec![],
+        };
+        for (path, title_length) in [("", RuleStatus::Passed), ("about", RuleStatus::Failed)] {
+            let page_results = PageLink {
+                url: format!("{url}{path}"),
+                link_type: LinkType::Internal,
+                found_in: HashSet::new(),
+                depth: None,
+                discovered_by: LinkSourceType::Link,
+                metadata: None,
+                outlinks: vec![],
+                link_metrics: None,
+                result: Some(PageResult {
+                    error: false,
+                    results: vec![
+                        rule_result("title.has_title", RuleStatus::Passed),
+                        rule_result("title.title_length", title_length),
+                    ],
+                }),
+            };
+            seo_storage
+                .insert_many_page_rule_results(site_run_id, page_results)
+                .await
+                .unwrap();
+        }
+
+        // (3 * 1 + 1 * 0.5) / 4
+        let health_score = seo_storage
+            .update_site_run_score(site_run_id)
+            .await
+            .unwrap();
+        assert_eq!(health_score.score, Some(88));
+        assert_eq!(health_score.categories[&DbRuleCategory::SEO], 88);
+
+        let site_run = seo_storage.get_site_run_by_id(site_run_id).await.unwrap();
+        assert_eq!(site_run.score, Some(88));
+        let history = seo_storage
+            .get_site_category_history(&site_run.site_id)
+            .await
+            .unwrap();
+        assert_eq!(history[0].score, Some(88));
+        assert_eq!(history[0].scores[&DbRuleCategory::SEO], 88);
+
+        // Site rules pass on the pages they don't flag, even when nothing is flagged
+        seo_storage
+            .insert_many_site_rule_results(
+                site_run_id,
+                vec![
+                    RuleResult {
+                        context: SiteCheckContext::Urls(vec![format!("{url}about")]),
+                        ..rule_result("orphaned_page.check", RuleStatus::Failed)
+                    },
+                    rule_result("uniqueness.title", RuleStatus::Passed),
+                ],
+            )
+            .await
+            .unwrap();
+        // (3 * 1 + 1 * 0.5 + 3 * 0.5 + 3 * 1) / 10
+        let health_score = seo_storage
+            .update_site_run_score(site_run_id)
+            .await
+            .unwrap();
+        assert_eq!(health_score.score, Some(80));
+
+        let score_weights = ScoreWeights {
+            warning: 0,
+            ..Default::default()
+        };
+        seo_storage
+            .set_score_weights(score_weights.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            seo_storage.get_score_weights().await.unwrap(),
+            score_weights
+        );
+        let health_score = seo_storage
+            .update_site_run_score(site_run_id)
+            .await
+            .unwrap();
+        assert_eq!(health_score.score, Some(88));
+    }
+
+    #[tokio::test]
+    async fn it_should_count_errored_site_rules() {
+        let seo_storage = SeoStorage::new_migrated_with_default().await;
+        let url = "https://forest-fitness-website-1dfad0.gitlab.io/";
+        let site_run_id = seo_storage.create_site_run(url, None).await.unwrap();
+
+        let rule_result = |rule_id: &str, status: RuleStatus| RuleResult {
+            rule_id: rule_id.to_string(),
+            name: "test".to_string(),
+            plugin_name: "test".to_string(),
+            passed: status == RuleStatus::Passed,
+            status,
+            message: "test".to_string(),
+            severity: Severity::Warning,
+            category: RuleCategory::SEO,
+            context: SiteCheckContext::Empty,
+            findings: vec![],
+        };
+        for path in ["", "about"] {
+            let page_results = PageLink {
+                url: format!("{url}{path}"),
+                link_type: LinkType::Internal,
+                found_in: HashSet::new(),
+                depth: None,
+                discovered_by: LinkSourceType::Link,
+                metadata: None,
+                outlinks: vec![],
+                link_metrics: None,
+                result: Some(PageResult {
+                    error: false,
+                    results: vec![rule_result("title.has_title", RuleStatus::Passed)],
+                }),
+            };
+            seo_storage
+                .insert_many_page_rule_results(site_run_id, page_results)
+                .await
+                .unwrap();
+        }
+
+        // Errored and not applicable site rules have no context, every page gets their outcome
+        seo_storage
+            .insert_many_site_rule_results(
+                site_run_id,
+                vec![
+                    RuleResult {
+                        message: "Link graph unavailable".to_string(),
+                        ..rule_result("orphaned_page.check", RuleStatus::Errored)
+                    },
+                    rule_result("uniqueness.title", RuleStatus::NotApplicable),
+                ],
+            )
+            .await
+            .unwrap();
+
+        let category_result = seo_storage.get_category_result(&site_run_id).await.unwrap();
+        assert_eq!(category_result.total, 6);
+        assert_eq!(category_result.passed, 2);
+        assert_eq!(category_result.errored, 2);
+        assert_eq!(category_result.not_applicable, 2);
+
+        let errored = PageRuleResult::find()
+            .filter(page_rule_result::Column::SiteRunId.eq(site_run_id))
+            .filter(page_rule_result::Column::RuleId.eq("orphaned_page.check"))
+            .all(&seo_storage.db)
+            .await
+            .unwrap();
+        assert_eq!(errored.len(), 2);
+        assert!(errored.iter().all(|result| !result.passed
+            && result.status == DbRuleStatus::Errored
+            && result.message.as_deref() == Some("Link graph unavailable")));
+    }
+
+    #[tokio::test]
+    async fn it_should_keep_rule_messages_and_context() {
+        let seo_storage = SeoStorage::new_migrated_with_default().await;
+        let url = "https://forest-fitness-website-1dfad0.gitlab.io/";
+        let site_run_id = seo_storage.create_site_run(url, None).await.unwrap();
+        let (home, about, contact) = (
+            url.to_string(),
+            format!("{url}about"),
+            format!("{url}contact"),
+        );
+
+        let rule_result = |rule_id: &str, message: &str, context: SiteCheckContext| RuleResult {
+            rule_id: rule_id.to_string(),
+            name: "test".to_string(),
+            plugin_name: "test".to_string(),
+            passed: false,
+            status: RuleStatus::Failed,
+            message: message.to_string(),
+            severity: Severity::Critical,
+            category: RuleCategory::SEO,
+            context,
+            findings: vec![],
+        };
+        seo_storage
+            .insert_many_page_rule_results(
+                site_run_id,
+                PageLink {
+                    url: home.clone(),
+                    link_type: LinkType::Internal,
+                    found_in: HashSet::new(),
+                    depth: None,
+                    discovered_by: LinkSourceType::Link,
+                    metadata: None,
+                    outlinks: vec![],
+                    link_metrics: None,
+                    result: Some(PageResult {
+                        error: false,
+                        results: vec![rule_result(
+                            "images.responsive",
+                            "3 images missing srcset",
+                            SiteCheckContext::Empty,
+                        )],
+                    }),
+                },
+            )
+            .await
+            .unwrap();
+        seo_storage
+            .insert_many_site_rule_results(
+                site_run_id,
+                vec![rule_result(
+                    "uniqueness.meta_description",
+                    "2 pages share a meta description",
+                    SiteCheckContext::Values(HashMap::from([
+                        ("Gym classes".to_string(), vec![home.clone(), about.clone()]),
+                        (
+                            "About the gym".to_string(),
+                            vec![about.clone(), contact.clone()],
+                        ),
+                    ])),
+                )],
+            )
+            .await
+            .unwrap();
+
+        let results: Vec<utils::category_detail::FlatRuleResult> = seo_storage
+            .get_category_result_detail(&site_run_id, Some(false))
+            .await
+            .unwrap()
+            .data
+            .into_values()
+            .flatten()
+            .collect();
+        let images = results
+            .iter()
+            .find(|result| result.rule_id == "images.responsive")
+            .unwrap();
+        assert_eq!(images.message, "3 images missing srcset");
+        assert_eq!(images.severity, DbSeverity::Critical);
+        assert_eq!(images.context, None);
+
+        let shared = results
+            .iter()
+            .find(|result| {
+                result.rule_id == "uniqueness.meta_description" && result.page_url == about
+            })
+            .unwrap();
+        assert_eq!(shared.message, "2 pages share a meta description");
+        // The page is in both groups, neither replaces the other
+        assert_eq!(
+            shared.context,
+            Some(ResultContext {
+                values: vec![
+                    ContextValue {
+                        value: "About the gym".to_string(),
+                        related_urls: vec![contact],
+                    },
+                    ContextValue {
+                        value: "Gym classes".to_string(),
+                        related_urls: vec![home],
+                    },
+                ],
+            })
+        );
+    }
 }
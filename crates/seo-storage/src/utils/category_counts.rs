@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     entities::{page_rule_result, plugin_rule},
-    enums::plugin_rule_enums::DbRuleCategory,
+    enums::{db_rule_status::DbRuleStatus, plugin_rule_enums::DbRuleCategory},
 };
 
 pub fn get_category_counts(
@@ -14,24 +14,16 @@ pub fn get_category_counts(
     let mut category_counts: HashMap<DbRuleCategory, CategoryResult> = HashMap::new();
 
     for (rule, results) in data {
-        let category = rule.category;
-        let count = results.len();
-        let passed = results.iter().filter(|r| r.passed).count() as i32;
-        let failed = count as i32 - passed;
-        let Some(existing) = category_counts.get_mut(&category) else {
-            category_counts.insert(
-                category,
-                CategoryResult {
-                    total: count as i32,
-                    passed,
-                    failed,
-                },
-            );
-            continue;
-        };
-        existing.total += count as i32;
-        existing.passed += results.iter().filter(|r| r.passed).count() as i32;
-        existing.failed += results.iter().filter(|r| !r.passed).count() as i32;
+        let existing = category_counts.entry(rule.category).or_default();
+        for result in results {
+            existing.total += 1;
+            match result.status {
+                DbRuleStatus::Passed => existing.passed += 1,
+                DbRuleStatus::Failed => existing.failed += 1,
+                DbRuleStatus::NotApplicable => existing.not_applicable += 1,
+                DbRuleStatus::Errored => existing.errored += 1,
+            }
+        }
     }
 
     category_counts
@@ -43,19 +35,25 @@ pub fn get_category_result_display(
     let total = data.values().map(|r| r.total).sum();
     let passed = data.values().map(|r| r.passed).sum();
     let failed = data.values().map(|r| r.failed).sum();
+    let not_applicable = data.values().map(|r| r.not_applicable).sum();
+    let errored = data.values().map(|r| r.errored).sum();
     CategoryResultDisplay {
         data,
         total,
         passed,
         failed,
+        not_applicable,
+        errored,
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, specta::Type, Clone)]
+#[derive(Debug, Serialize, Deserialize, specta::Type, Clone, Default)]
 pub struct CategoryResult {
     pub total: i32,
     pub passed: i32,
     pub failed: i32,
+    pub not_applicable: i32,
+    pub errored: i32,
 }
 
 #[derive(Debug, Serialize, Deserialize, specta::Type, Clone)]
@@ -64,12 +62,17 @@ pub struct CategoryResultDisplay {
     pub total: i32,
     pub passed: i32,
     pub failed: i32,
+    pub not_applicable: i32,
+    pub errored: i32,
 }
 
 #[derive(Debug, Serialize, Deserialize, specta::Type, Clone)]
 pub struct CategoryResultHistory {
     pub data: HashMap<DbRuleCategory, CategoryResult>,
     pub created_at: DateTime<Utc>,
+    // Weighted 0-100 health score of the run and of each checked category
+    pub score: Option<i32>,
+    pub scores: HashMap<DbRuleCategory, i32>,
 }
 
 #[cfg(test)]
@@ -94,7 +97,7 @@ mod tests {
                     let mut rule = Faker.fake::<plugin_rule::Model>();
                     rule.category = category;
                     let mut rule_result = Faker.fake::<page_rule_result::Model>();
-                    rule_result.passed = true;
+                    rule_result.status = DbRuleStatus::Passed;
                     let results = vec![rule_result];
                     data.push((rule, results));
                 }
@@ -104,7 +107,7 @@ mod tests {
                         rule.category = DbRuleCategory::Performance;
                         for _ in 0..10 {
                             let mut rule_result = Faker.fake::<page_rule_result::Model>();
-                            rule_result.passed = true;
+                            rule_result.status = DbRuleStatus::Passed;
                             let results = vec![rule_result];
                             data.push((rule.clone(), results));
                         }
@@ -116,7 +119,11 @@ mod tests {
                         rule.category = DbRuleCategory::BestPractices;
                         for i in 0..10 {
                             let mut rule_result = Faker.fake::<page_rule_result::Model>();
-                            rule_result.passed = i % 2 == 0;
+                            rule_result.status = match i % 4 {
+                                0 | 2 => DbRuleStatus::Passed,
+                                1 => DbRuleStatus::Failed,
+                                _ => DbRuleStatus::NotApplicable,
+                            };
                             let results = vec![rule_result];
                             data.push((rule.clone(), results));
                         }
@@ -144,6 +151,8 @@ mod tests {
 
         assert_eq!(result[&DbRuleCategory::BestPractices].total, 100);
         assert_eq!(result[&DbRuleCategory::BestPractices].passed, 50);
-        assert_eq!(result[&DbRuleCategory::BestPractices].failed, 50);
+        assert_eq!(result[&DbRuleCategory::BestPractices].failed, 30);
+        assert_eq!(result[&DbRuleCategory::BestPractices].not_applicable, 20);
+        assert_eq!(result[&DbRuleCategory::BestPractices].errored, 0);
     }
 }
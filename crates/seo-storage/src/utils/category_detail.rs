@@ -1,10 +1,15 @@
 use std::collections::HashMap;
 
+use seo_plugins::utils::config::Remediation;
 use serde::{Deserialize, Serialize};
 
+use super::result_context::ResultContext;
 use crate::{
     entities::{page_rule_result, plugin_rule, site_page},
-    enums::plugin_rule_enums::{DbRuleCategory, DbSeverity},
+    enums::{
+        db_rule_status::DbRuleStatus,
+        plugin_rule_enums::{DbRuleCategory, DbSeverity},
+    },
 };
 
 pub fn get_category_detail(
@@ -19,23 +24,33 @@ pub fn get_category_detail(
     for (page_rule_result, site_page, plugin_rule) in res {
         let site_page = site_page.unwrap();
         let plugin_rule = plugin_rule.unwrap();
+        let remediation = plugin_rule.remediation().map_err(|e| e.to_string())?;
         let category = plugin_rule.category;
         let category_clone = category.clone();
-        let message = if page_rule_result.passed {
-            plugin_rule.passed_message
-        } else {
-            plugin_rule.failed_message
-        };
+        let context = page_rule_result.context().map_err(|e| e.to_string())?;
+        // Results stored before messages were kept fall back to the rule's messages
+        let message = page_rule_result
+            .message
+            .unwrap_or_else(|| match page_rule_result.status {
+                DbRuleStatus::Passed => plugin_rule.passed_message,
+                DbRuleStatus::Failed => plugin_rule.failed_message,
+                DbRuleStatus::NotApplicable => "Rule does not apply to this page".to_string(),
+                DbRuleStatus::Errored => "Rule could not be checked".to_string(),
+            });
 
         let flat_rule_result = FlatRuleResult {
+            page_rule_result_id: page_rule_result.id,
             rule_id: page_rule_result.rule_id,
             name: plugin_rule.name,
             plugin_name: plugin_rule.plugin_name,
             passed: page_rule_result.passed,
+            status: page_rule_result.status,
             message,
-            severity: plugin_rule.severity,
+            severity: page_rule_result.severity.unwrap_or(plugin_rule.severity),
             category,
             page_url: site_page.url,
+            remediation,
+            context,
         };
         category_detail
             .entry(category_clone)
@@ -50,14 +65,18 @@ pub fn get_category_detail(
 
 #[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
 pub struct FlatRuleResult {
+    pub page_rule_result_id: i32,
     pub rule_id: String,
     pub name: String,
     pub plugin_name: String,
     pub passed: bool,
+    pub status: DbRuleStatus,
     pub message: String,
     pub severity: DbSeverity,
     pub category: DbRuleCategory,
     pub page_url: String,
+    pub remediation: Option<Remediation>,
+    pub context: Option<ResultContext>,
     // link_type: String,
     // found_in: String,
 }
@@ -77,9 +96,16 @@ mod tests {
     fn get_fake_plugin_rule(category: DbRuleCategory) -> plugin_rule::Model {
         let mut rule = Faker.fake::<plugin_rule::Model>();
         rule.category = category;
+        rule.remediation = None;
         rule
     }
 
+    fn get_fake_page_rule_result() -> page_rule_result::Model {
+        let mut result = Faker.fake::<page_rule_result::Model>();
+        result.context = None;
+        result
+    }
+
     fn get_test_data() -> Vec<(
         page_rule_result::Model,
         Option<site_page::Model>,
@@ -87,17 +113,17 @@ mod tests {
     )> {
         vec![
             (
-                Faker.fake::<page_rule_result::Model>(),
+                get_fake_page_rule_result(),
                 Some(Faker.fake::<site_page::Model>()),
                 Some(get_fake_plugin_rule(DbRuleCategory::Accessibility)),
             ),
             (
-                Faker.fake::<page_rule_result::Model>(),
+                get_fake_page_rule_result(),
                 Some(Faker.fake::<site_page::Model>()),
                 Some(get_fake_plugin_rule(DbRuleCategory::Performance)),
             ),
             (
-                Faker.fake::<page_rule_result::Model>(),
+                get_fake_page_rule_result(),
                 Some(Faker.fake::<site_page::Model>()),
                 Some(get_fake_plugin_rule(DbRuleCategory::Performance)),
             ),
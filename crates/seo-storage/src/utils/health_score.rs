@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    entities::{page_rule_result, plugin_rule},
+    enums::{
+        db_rule_status::DbRuleStatus,
+        plugin_rule_enums::{DbRuleCategory, DbSeverity},
+    },
+};
+
+// How much a rule counts towards the health score, by the severity it ran with.
+// A rule's own `weight` replaces these, and a weight of 0 leaves the rule out.
+#[derive(Debug, Serialize, Deserialize, specta::Type, Clone, PartialEq, Eq)]
+pub struct ScoreWeights {
+    pub info: i32,
+    pub warning: i32,
+    pub error: i32,
+    pub critical: i32,
+}
+
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        Self {
+            info: 1,
+            warning: 3,
+            error: 6,
+            critical: 10,
+        }
+    }
+}
+
+impl ScoreWeights {
+    // `severity` is the one stored with the results, results stored before it was
+    // recorded fall back to the rule's current severity
+    pub fn rule_weight(&self, rule: &plugin_rule::Model, severity: Option<&DbSeverity>) -> i32 {
+        let weight = rule.weight.unwrap_or_else(|| {
+            match severity
+                .or(rule.severity_override.as_ref())
+                .unwrap_or(&rule.severity)
+            {
+                DbSeverity::Info => self.info,
+                DbSeverity::Warning => self.warning,
+                DbSeverity::Error => self.error,
+                DbSeverity::Critical => self.critical,
+            }
+        });
+        weight.max(0)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, specta::Type, Clone, Default, PartialEq, Eq)]
+pub struct HealthScore {
+    // `None` when no rule was checked on any page
+    pub score: Option<i32>,
+    pub categories: HashMap<DbRuleCategory, i32>,
+}
+
+#[derive(Default)]
+struct WeightedSum {
+    weighted: f64,
+    weights: f64,
+}
+
+impl WeightedSum {
+    fn add(&mut self, weight: i32, value: f64) {
+        self.weighted += weight as f64 * value;
+        self.weights += weight as f64;
+    }
+
+    fn score(&self) -> Option<i32> {
+        (self.weights > 0.0).then(|| (self.weighted / self.weights * 100.0).round() as i32)
+    }
+}
+
+// Scores each rule by the share of checked pages that passed it, so a rule failing on
+// one page of a hundred costs far less than one failing everywhere. Category and overall
+// scores are the weighted average of their rules, on a 0-100 scale.
+// Not applicable and errored results are not checks and don't count.
+pub fn get_health_score(
+    data: &[(plugin_rule::Model, Vec<page_rule_result::Model>)],
+    weights: &ScoreWeights,
+) -> HealthScore {
+    let mut overall = WeightedSum::default();
+    let mut categories: HashMap<DbRuleCategory, WeightedSum> = HashMap::new();
+
+    for (rule, results) in data {
+        let passed = results
+            .iter()
+            .filter(|result| result.status == DbRuleStatus::Passed)
+            .count();
+        let failed = results
+            .iter()
+            .filter(|result| result.status == DbRuleStatus::Failed)
+            .count();
+        let severity = results.iter().find_map(|result| result.severity.as_ref());
+        let weight = weights.rule_weight(rule, severity);
+        if passed + failed == 0 || weight == 0 {
+            continue;
+        }
+        let pass_rate = passed as f64 / (passed + failed) as f64;
+        overall.add(weight, pass_rate);
+        categories
+            .entry(rule.category.clone())
+            .or_default()
+            .add(weight, pass_rate);
+    }
+
+    HealthScore {
+        score: overall.score(),
+        categories: categories
+            .into_iter()
+            .filter_map(|(category, sum)| sum.score().map(|score| (category, score)))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::{Fake, Faker};
+
+    use super::*;
+
+    fn rule(
+        category: DbRuleCategory,
+        severity: DbSeverity,
+        statuses: &[DbRuleStatus],
+    ) -> (plugin_rule::Model, Vec<page_rule_result::Model>) {
+        let mut rule = Faker.fake::<plugin_rule::Model>();
+        rule.category = category;
+        rule.severity = severity.clone();
+        rule.severity_override = None;
+        rule.weight = None;
+        let results = statuses
+            .iter()
+            .map(|status| {
+                let mut result = Faker.fake::<page_rule_result::Model>();
+                result.status = status.clone();
+                result.severity = Some(severity.clone());
+                result
+            })
+            .collect();
+        (rule, results)
+    }
+
+    #[test]
+    fn test_get_health_score() {
+        use DbRuleStatus::*;
+
+        let mut data = vec![
+            // Fails on 1 of 4 pages, the unchecked pages don't count
+            rule(
+                DbRuleCategory::SEO,
+                DbSeverity::Critical,
+                &[Passed, Passed, Passed, Failed, NotApplicable, Errored],
+            ),
+            rule(DbRuleCategory::SEO, DbSeverity::Info, &[Failed, Failed]),
+            rule(DbRuleCategory::Performance, DbSeverity::Warning, &[Passed]),
+            rule(
+                DbRuleCategory::Accessibility,
+                DbSeverity::Error,
+                &[NotApplicable],
+            ),
+        ];
+        let weights = ScoreWeights::default();
+
+        let score = get_health_score(&data, &weights);
+        // (10 * 0.75 + 1 * 0) / 11
+        assert_eq!(score.categories[&DbRuleCategory::SEO], 68);
+        assert_eq!(score.categories[&DbRuleCategory::Performance], 100);
+        assert!(
+            !score
+                .categories
+                .contains_key(&DbRuleCategory::Accessibility)
+        );
+        // (10 * 0.75 + 1 * 0 + 3 * 1) / 14
+        assert_eq!(score.score, Some(75));
+
+        // A rule weight replaces the severity weight
+        data[1].0.weight = Some(10);
+        let score = get_health_score(&data, &weights);
+        assert_eq!(score.categories[&DbRuleCategory::SEO], 38);
+
+        data[1].0.weight = Some(0);
+        let score = get_health_score(&data, &weights);
+        assert_eq!(score.categories[&DbRuleCategory::SEO], 75);
+
+        // Results are weighted by the severity they ran with, not the rule's current one
+        data[1].0.weight = None;
+        data[1].0.severity_override = Some(DbSeverity::Critical);
+        let score = get_health_score(&data, &weights);
+        assert_eq!(score.categories[&DbRuleCategory::SEO], 68);
+
+        assert_eq!(get_health_score(&[], &weights), HealthScore::default());
+    }
+}
@@ -1,3 +1,5 @@
 pub mod category_counts;
 pub mod category_detail;
+pub mod health_score;
+pub mod result_context;
 pub mod sites_with_site_runs;
@@ -0,0 +1,44 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+// What a site rule result on one page was about. A page can be in several groups of
+// one rule, e.g. two vague anchor texts it uses, so each group is kept.
+#[derive(Debug, Serialize, Deserialize, specta::Type, Clone, PartialEq, Eq)]
+pub struct ResultContext {
+    pub values: Vec<ContextValue>,
+}
+
+// One group the page is in, e.g. the meta description it shares with `related_urls`
+#[derive(Debug, Serialize, Deserialize, specta::Type, Clone, PartialEq, Eq)]
+pub struct ContextValue {
+    pub value: String,
+    // The other pages the value was found on
+    pub related_urls: Vec<String>,
+}
+
+impl ResultContext {
+    // The context of each page in `groups`, with every value the page was grouped by
+    pub fn by_url(groups: &HashMap<String, Vec<String>>) -> BTreeMap<String, Self> {
+        let mut contexts: BTreeMap<String, Self> = BTreeMap::new();
+        let mut groups: Vec<_> = groups.iter().collect();
+        groups.sort_by_key(|(value, _)| *value);
+        for (value, urls) in groups {
+            for url in urls {
+                contexts
+                    .entry(url.clone())
+                    .or_insert_with(|| Self { values: vec![] })
+                    .values
+                    .push(ContextValue {
+                        value: value.clone(),
+                        related_urls: urls
+                            .iter()
+                            .filter(|related| *related != url)
+                            .cloned()
+                            .collect(),
+                    });
+            }
+        }
+        contexts
+    }
+}